@@ -1,12 +1,12 @@
 use crate::dom::document;
 use euclid::default::Point2D;
-use glissade::{Animated, Inertial};
+use glissade::{Animated, DomTime, Inertial};
 use web_sys::{Element, HtmlElement};
 
 pub struct Circle {
     node: Element,
     delay: f64,
-    position: Option<Inertial<Point2D<f32>, f64>>,
+    position: Option<Inertial<Point2D<f32>, DomTime>>,
 }
 
 impl Circle {
@@ -22,7 +22,7 @@ impl Circle {
         }
     }
 
-    pub fn set_position(&mut self, time: f64, position: Point2D<f32>) {
+    pub fn set_position(&mut self, time: DomTime, position: Point2D<f32>) {
         let delay: f64 = self.delay;
 
         self.position = self
@@ -32,7 +32,7 @@ impl Circle {
             .or_else(|| Some(position.into()));
     }
 
-    pub fn update(&self, time: f64) {
+    pub fn update(&self, time: DomTime) {
         if let Some(position) = self.position.as_ref() {
             let position = position.get(time);
             self.node
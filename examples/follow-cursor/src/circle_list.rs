@@ -1,6 +1,7 @@
 use crate::circle::Circle;
 use crate::dom::body;
 use euclid::default::Point2D;
+use glissade::DomTime;
 
 pub struct CircleList {
     circles: Vec<Circle>,
@@ -15,13 +16,13 @@ impl CircleList {
         }
     }
 
-    pub fn set_mouse_position(&mut self, time: f64, position: Point2D<f32>) {
+    pub fn set_mouse_position(&mut self, time: DomTime, position: Point2D<f32>) {
         self.circles
             .iter_mut()
             .for_each(|circle| circle.set_position(time, position));
     }
 
-    pub fn update(&self, time: f64) {
+    pub fn update(&self, time: DomTime) {
         self.circles.iter().for_each(|circle| circle.update(time));
     }
 }
@@ -10,10 +10,6 @@ pub fn body() -> HtmlElement {
     document().body().unwrap()
 }
 
-pub fn now() -> f64 {
-    web_sys::window().unwrap().performance().unwrap().now()
-}
-
 pub struct MousePositionListener {
     on_mouse_move: Closure<dyn FnMut(MouseEvent)>,
 }
@@ -1,12 +1,12 @@
-mod animation_loop;
 mod circle;
 mod circle_list;
 mod dom;
 
-use crate::animation_loop::AnimationLoop;
 use crate::circle_list::CircleList;
-use crate::dom::{now, MousePositionListener};
+use crate::dom::MousePositionListener;
 use euclid::default::Point2D;
+use glissade::driver::web::AnimationLoop;
+use glissade::{DomTime, Time};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -18,7 +18,7 @@ const STEP_DELAY: f64 = 150.0;
 const DEBOUNCE: f64 = 100.0;
 
 struct App {
-    last_update: f64,
+    last_update: DomTime,
     mouse_position: Option<Point2D<f32>>,
     circle_list: CircleList,
 }
@@ -26,7 +26,7 @@ struct App {
 impl App {
     fn new() -> Self {
         App {
-            last_update: 0.0,
+            last_update: DomTime::default(),
             mouse_position: None,
             circle_list: CircleList::new(ITEMS_COUNT, STEP_DELAY),
         }
@@ -36,9 +36,9 @@ impl App {
         self.mouse_position = Some(position);
     }
 
-    fn update(&mut self, time: f64) {
+    fn update(&mut self, time: DomTime) {
         if let Some(mouse_position) = self.mouse_position {
-            if time - self.last_update > DEBOUNCE {
+            if time.since(self.last_update) > DEBOUNCE {
                 self.last_update = time;
             }
             self.circle_list.set_mouse_position(time, mouse_position);
@@ -53,7 +53,7 @@ fn main() {
     let app = Rc::new(RefCell::new(App::new()));
     let animation_loop = AnimationLoop::new({
         let app = app.clone();
-        move || app.borrow_mut().update(now())
+        move |time| app.borrow_mut().update(time)
     });
     let mouse_position_listener = MousePositionListener::new({
         let app = app.clone();
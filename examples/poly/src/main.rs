@@ -1,14 +1,14 @@
-use crate::animation_loop::AnimationLoop;
 use crate::path_view::PathView;
-use crate::paths::{lissajous, ngon, star};
+use glam::Vec2;
+use glissade::driver::web::AnimationLoop;
+use glissade::generators::{lissajous, ngon, star};
+use glissade::keyframes;
 use std::mem::forget;
 use wasm_bindgen::JsValue;
 use web_sys::{window, Element};
-use web_time::Instant;
+use web_time::{Duration, Instant};
 
-mod animation_loop;
 mod path_view;
-mod paths;
 
 pub fn run_animations(container: &Element) -> Result<AnimationLoop, JsValue> {
     let node = {
@@ -20,28 +20,24 @@ pub fn run_animations(container: &Element) -> Result<AnimationLoop, JsValue> {
     let now = Instant::now();
 
     let items: Vec<PathView> = vec![
-        PathView::new("Triangle", now, &ngon(100.0, 3).collect::<Vec<_>>(), &node)?,
-        PathView::new("Square", now, &ngon(100.0, 4).collect::<Vec<_>>(), &node)?,
-        PathView::new("Pentagon", now, &ngon(100.0, 5).collect::<Vec<_>>(), &node)?,
-        PathView::new("Hexagon", now, &ngon(100.0, 6).collect::<Vec<_>>(), &node)?,
-        PathView::new("Octagon", now, &ngon(100.0, 8).collect::<Vec<_>>(), &node)?,
-        PathView::new("Circle", now, &ngon(100.0, 200).collect::<Vec<_>>(), &node)?,
-        PathView::new("Star", now, &star(100.0, 5).collect::<Vec<_>>(), &node)?,
-        PathView::new(
-            "Lissajous 1:3",
+        PathView::new("Triangle", now, &ngon(100.0, 3), &node)?,
+        PathView::new("Square", now, &ngon(100.0, 4), &node)?,
+        PathView::new("Pentagon", now, &ngon(100.0, 5), &node)?,
+        PathView::new("Hexagon", now, &ngon(100.0, 6), &node)?,
+        PathView::new("Octagon", now, &ngon(100.0, 8), &node)?,
+        PathView::with_motion(
+            "Circle",
             now,
-            &lissajous(100.0, 500, 1, 3).collect::<Vec<_>>(),
-            &node,
-        )?,
-        PathView::new(
-            "Lissajous 3:4",
-            now,
-            &lissajous(100.0, 500, 3, 4).collect::<Vec<_>>(),
+            &ngon(100.0, 64),
+            keyframes::orbit(Vec2::new(0.0, 0.0), 100.0, Duration::from_secs(2)),
             &node,
         )?,
+        PathView::new("Star", now, &star(100.0, 50.0, 5), &node)?,
+        PathView::new("Lissajous 1:3", now, &lissajous(100.0, 500, 1, 3), &node)?,
+        PathView::new("Lissajous 3:4", now, &lissajous(100.0, 500, 3, 4), &node)?,
     ];
 
-    Ok(AnimationLoop::new(move || {
+    Ok(AnimationLoop::new(move |_| {
         let now = Instant::now();
         for item in &items {
             item.update(now).unwrap();
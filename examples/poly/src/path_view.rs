@@ -1,5 +1,5 @@
 use glam::Vec2;
-use glissade::{keyframes, Animated, Distance, Easing, Keyframes, Mix};
+use glissade::{keyframes, Animated, Easing, Keyframes, Mix};
 use wasm_bindgen::JsValue;
 use web_sys::Element;
 use web_time::{Duration, Instant};
@@ -10,12 +10,6 @@ struct PathItem {
     position: Vec2,
 }
 
-impl Distance for PathItem {
-    fn distance(self, other: Self) -> f32 {
-        self.position.distance(other.position)
-    }
-}
-
 impl PathItem {
     fn new(opacity: f32, position: Vec2) -> Self {
         Self { opacity, position }
@@ -30,11 +24,25 @@ pub struct PathView {
 const SVG_NS: Option<&str> = Some("http://www.w3.org/2000/svg");
 
 impl PathView {
+    /// Show the path, and move the point along it.
     pub fn new(
         title: &str,
         now: Instant,
         points: &[Vec2],
         parent: &Element,
+    ) -> Result<Self, JsValue> {
+        let motion = keyframes::poly(points.to_vec(), Duration::from_secs(2), Easing::CubicInOut);
+        Self::with_motion(title, now, points, motion, parent)
+    }
+
+    /// Show the outline, and move the point with the given keyframes after blinking at their start.
+    /// Finite motions are repeated together with the blinking.
+    pub fn with_motion(
+        title: &str,
+        now: Instant,
+        outline: &[Vec2],
+        motion: impl Keyframes<Vec2, Instant> + 'static,
+        parent: &Element,
     ) -> Result<Self, JsValue> {
         let document = parent.owner_document().unwrap();
 
@@ -70,7 +78,7 @@ impl PathView {
             let node = document.create_element_ns(SVG_NS, "polygon")?;
             node.set_attribute(
                 "points",
-                &points
+                &outline
                     .iter()
                     .map(|p| format!("{:.2},{:.2}", p.x, p.y))
                     .collect::<Vec<_>>()
@@ -86,7 +94,7 @@ impl PathView {
             node
         };
 
-        let origin = points.first().copied().unwrap();
+        let origin = motion.start_value();
 
         // Create a keyframes animation
         let animation = keyframes::from(PathItem::new(1.0, origin))
@@ -95,16 +103,13 @@ impl PathView {
             .go_to(PathItem::new(1.0, origin), Duration::from_millis(400))
             .repeat_n(2.0)
             // move along the path
-            .poly_to(
-                points
-                    .iter()
-                    .map(|p| PathItem::new(1.0, *p))
-                    .collect::<Vec<_>>(),
-                Duration::from_secs(2),
-                Easing::CubicInOut,
-            )
-            .repeat()
-            .run(now);
+            .then(motion.map(|position| PathItem::new(1.0, position)));
+        let animation = if animation.is_finite() {
+            animation.repeat().boxed()
+        } else {
+            animation.boxed()
+        }
+        .run(now);
 
         Ok(Self {
             path: Box::new(animation),
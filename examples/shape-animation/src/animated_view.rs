@@ -1,5 +1,5 @@
-use crate::use_inertial::use_inertial;
 use cgmath::Vector2;
+use glissade::yew::use_inertial;
 use glissade::Mix;
 use palette::{LinSrgb, Srgb};
 use std::f32::consts::PI;
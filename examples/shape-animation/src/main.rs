@@ -1,10 +1,8 @@
 mod animated_view;
-mod animation_loop;
 mod button;
 mod enum_input;
 mod mdl;
 mod radio;
-mod use_inertial;
 
 use crate::animated_view::{AnimatedView, AnimatedViewProps};
 use crate::button::Button;
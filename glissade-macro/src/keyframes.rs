@@ -0,0 +1,226 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, Lit, Token};
+
+/// Keyframes track: `time [linear | ease EASING] => value`, separated by commas.
+pub struct Track {
+    keyframes: Vec<Keyframe>,
+}
+
+struct Keyframe {
+    time: Lit,
+    transition: Option<Transition>,
+    value: Expr,
+}
+
+enum Transition {
+    Linear,
+    Ease(Expr),
+}
+
+impl Parse for Track {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyframes = Punctuated::<Keyframe, Token![,]>::parse_terminated(input)?;
+        Ok(Self {
+            keyframes: keyframes.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for Keyframe {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let time: Lit = input.parse()?;
+        let transition = if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            if ident == "linear" {
+                Some(Transition::Linear)
+            } else if ident == "ease" {
+                Some(Transition::Ease(input.parse()?))
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expected `linear` or `ease` transition",
+                ));
+            }
+        } else {
+            None
+        };
+        input.parse::<Token![=>]>()?;
+        let value = input.parse()?;
+        Ok(Self {
+            time,
+            transition,
+            value,
+        })
+    }
+}
+
+/// An absolute keyframe time.
+enum Time {
+    /// A time with the `s` or `ms` suffix, it's converted to `core::time::Duration`.
+    Nanos(u64),
+    /// A plain number, it's used as is, so it works with any numeric time type.
+    Number(f64),
+}
+
+impl Time {
+    fn parse(lit: &Lit) -> syn::Result<Self> {
+        let (digits, suffix) = match lit {
+            Lit::Int(lit) => (lit.base10_digits(), lit.suffix()),
+            Lit::Float(lit) => (lit.base10_digits(), lit.suffix()),
+            _ => return Err(syn::Error::new(lit.span(), "expected a keyframe time")),
+        };
+        let exponent = match suffix {
+            "s" => 9,
+            "ms" => 6,
+            _ => {
+                return digits
+                    .parse()
+                    .map(Time::Number)
+                    .map_err(|_| syn::Error::new(lit.span(), "invalid keyframe time"));
+            }
+        };
+        to_nanos(digits, exponent).map(Time::Nanos).ok_or_else(|| {
+            syn::Error::new(
+                lit.span(),
+                "keyframe time must be a whole number of nanoseconds",
+            )
+        })
+    }
+}
+
+/// Convert decimal digits like `2.5` or `1e-3` multiplied by `10^exponent` to an integer exactly.
+fn to_nanos(digits: &str, exponent: i32) -> Option<u64> {
+    let (mantissa, power) = match digits.split_once(['e', 'E']) {
+        Some((mantissa, power)) => (mantissa, power.parse::<i32>().ok()?),
+        None => (digits, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let value: u128 = format!("{}{}", integer, fraction).parse().ok()?;
+    let power = power + exponent - fraction.len() as i32;
+    let value = if power >= 0 {
+        value.checked_mul(10u128.checked_pow(power as u32)?)?
+    } else {
+        let divisor = 10u128.checked_pow(power.unsigned_abs())?;
+        if !value.is_multiple_of(divisor) {
+            return None;
+        }
+        value / divisor
+    };
+    value.try_into().ok()
+}
+
+/// Get the duration between two absolute times.
+fn duration(from: (&Time, &Lit), to: (&Time, &Lit)) -> syn::Result<TokenStream> {
+    match (from, to) {
+        ((Time::Nanos(from), _), (Time::Nanos(to), lit)) => {
+            if to < from {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "keyframe times must not decrease",
+                ));
+            }
+            let nanos = to - from;
+            Ok(quote! { ::core::time::Duration::from_nanos(#nanos) })
+        }
+        ((Time::Number(from), from_lit), (Time::Number(to), to_lit)) => {
+            if matches!(from_lit, Lit::Float(_)) != matches!(to_lit, Lit::Float(_)) {
+                return Err(syn::Error::new(
+                    to_lit.span(),
+                    "keyframe times can't mix integer and float numbers",
+                ));
+            }
+            if from_lit.suffix() != to_lit.suffix() {
+                return Err(syn::Error::new(
+                    to_lit.span(),
+                    "keyframe times must have the same type suffix",
+                ));
+            }
+            if to < from {
+                return Err(syn::Error::new(
+                    to_lit.span(),
+                    "keyframe times must not decrease",
+                ));
+            }
+            Ok(quote! { (#to_lit - #from_lit) })
+        }
+        (_, (_, lit)) => Err(syn::Error::new(
+            lit.span(),
+            "keyframe times can't mix `s`/`ms` durations and plain numbers",
+        )),
+    }
+}
+
+/// A single capitalized identifier is a variant of `Easing`, anything else is an expression.
+fn easing(easing: &Expr) -> TokenStream {
+    if let Expr::Path(path) = easing {
+        if let Some(ident) = path.path.get_ident() {
+            if ident.to_string().starts_with(char::is_uppercase) {
+                return quote! { glissade::Easing::#ident };
+            }
+        }
+    }
+    easing.to_token_stream()
+}
+
+impl Track {
+    pub fn expand(&self) -> syn::Result<TokenStream> {
+        let Some(first) = self.keyframes.first() else {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "keyframes! needs at least one keyframe",
+            ));
+        };
+        if first.transition.is_some() {
+            return Err(syn::Error::new(
+                first.time.span(),
+                "the first keyframe can't have a transition",
+            ));
+        }
+
+        let times = self
+            .keyframes
+            .iter()
+            .map(|keyframe| Time::parse(&keyframe.time))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let value = &first.value;
+        let mut result = quote! { glissade::keyframes::from(#value) };
+
+        let delay = match &times[0] {
+            Time::Nanos(0) => None,
+            Time::Nanos(nanos) => Some(quote! { ::core::time::Duration::from_nanos(#nanos) }),
+            Time::Number(number) if *number == 0.0 => None,
+            Time::Number(_) => Some(first.time.to_token_stream()),
+        };
+        if let Some(delay) = delay {
+            result = quote! { #result.stay(#delay) };
+        }
+
+        for (i, keyframe) in self.keyframes.iter().enumerate().skip(1) {
+            let duration = duration(
+                (&times[i - 1], &self.keyframes[i - 1].time),
+                (&times[i], &keyframe.time),
+            )?;
+            let value = &keyframe.value;
+            result = match &keyframe.transition {
+                None | Some(Transition::Linear) => {
+                    quote! { #result.go_to(#value, #duration) }
+                }
+                Some(Transition::Ease(expr)) => {
+                    let easing = easing(expr);
+                    quote! { #result.ease_to(#value, #duration, #easing) }
+                }
+            };
+        }
+
+        Ok(quote! {
+            {
+                use glissade::Keyframes as _;
+                #result
+            }
+        })
+    }
+}
@@ -1,40 +1,59 @@
+mod keyframes;
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Fields, GenericParam};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, DeriveInput, Field, Fields, Generics, Index, LitStr,
+    Member, Token, Type, WherePredicate,
+};
 
 #[derive(Debug)]
 enum Error {
-    CantDeriveForEnum,
-    CantDeriveForUnion,
+    CantDeriveForEnum(&'static str),
+    CantDeriveForUnion(&'static str),
+    InvalidAttributes(&'static str, &'static str),
 }
 
-impl From<Error> for TokenStream {
-    fn from(error: Error) -> TokenStream {
-        match error {
-            Error::CantDeriveForEnum => {
-                quote! {
-                    compile_error!("Mix cannot be derived for enums");
-                }
-            }
-            Error::CantDeriveForUnion => {
-                quote! {
-                    compile_error!("Mix cannot be derived for unions");
-                }
+impl From<Error> for proc_macro2::TokenStream {
+    fn from(error: Error) -> proc_macro2::TokenStream {
+        let message = match error {
+            Error::CantDeriveForEnum(name) => format!("{} cannot be derived for enums", name),
+            Error::CantDeriveForUnion(name) => format!("{} cannot be derived for unions", name),
+            Error::InvalidAttributes(name, attribute) => {
+                format!("{} derive requires {}", name, attribute)
             }
+        };
+        quote! {
+            compile_error!(#message);
         }
-        .into()
     }
 }
 
-/// Derive the `Mix` trait for a struct.
+impl From<Error> for TokenStream {
+    fn from(error: Error) -> TokenStream {
+        proc_macro2::TokenStream::from(error).into()
+    }
+}
+
+/// Derive the `Mix` trait for a struct or an enum.
 /// It interpolates each field of the struct with the `Mix` trait.
-#[proc_macro_derive(Mix)]
+/// Enum values of the same variant are interpolated field by field,
+/// values of different variants switch from one to the other at `t = 0.5`.
+///
+/// Each type parameter is required to implement `Mix`, it can be changed with attributes:
+/// * `#[mix(bound = "T: Mix + Clone")]` - Use the given bounds instead.
+/// * `#[mix(no_bounds)]` - Don't add bounds, e.g. if parameters only appear in `PhantomData`.
+#[proc_macro_derive(Mix, attributes(mix))]
 pub fn mix_macro(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    mix_impl(&input).into()
+}
 
-    let name = input.ident;
+fn mix_impl(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let name = &input.ident;
 
-    let fields = match input.data {
+    let body = match input.data {
         syn::Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
                 let fields_mix = fields
@@ -49,7 +68,7 @@ pub fn mix_macro(input: TokenStream) -> TokenStream {
                     .collect::<Vec<_>>();
 
                 quote! {
-                    {
+                    Self {
                       #(#fields_mix),*
                     }
                 }
@@ -65,63 +84,443 @@ pub fn mix_macro(input: TokenStream) -> TokenStream {
                     .collect::<Vec<_>>();
 
                 quote! {
-                    (
+                    Self(
                         #(#fields_mix),*
                     )
                 }
             }
-            Fields::Unit => TokenStream::default().into(),
+            Fields::Unit => quote! { Self },
         },
-        syn::Data::Enum(_) => {
-            return Error::CantDeriveForEnum.into();
+        syn::Data::Enum(ref data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let members = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| match field.ident {
+                        Some(ref ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(Index::from(i)),
+                    })
+                    .collect::<Vec<_>>();
+                let from = (0..members.len())
+                    .map(|i| format_ident!("__from{}", i))
+                    .collect::<Vec<_>>();
+                let to = (0..members.len())
+                    .map(|i| format_ident!("__to{}", i))
+                    .collect::<Vec<_>>();
+                quote! {
+                    (
+                        Self::#variant_name { #(#members: #from),* },
+                        Self::#variant_name { #(#members: #to),* },
+                    ) => Self::#variant_name { #(#members: #from.mix(#to, t)),* }
+                }
+            });
+
+            quote! {
+                #[allow(unreachable_patterns)]
+                match (self, other) {
+                    #(#arms,)*
+                    (from, to) => {
+                        if t > 0.5 {
+                            to
+                        } else {
+                            from
+                        }
+                    }
+                }
+            }
         }
         syn::Data::Union(_) => {
-            return Error::CantDeriveForUnion.into();
+            return Error::CantDeriveForUnion("Mix").into();
         }
     };
 
-    let generic_params = input.generics.params;
-    let generic_names = if generic_params.is_empty() {
-        quote! {}
-    } else {
-        let names = generic_params
+    let generics = match bounded_generics(input, quote! { glissade::Mix }) {
+        Ok(generics) => generics,
+        Err(error) => return error.to_compile_error(),
+    };
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics glissade::Mix for #name #type_generics #where_clause {
+            fn mix(self, other: Self, t: f32) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+/// Derive the `Distance` trait for a struct.
+/// The distance is euclidean, it's the square root of the sum of squared field distances.
+/// Type parameter bounds are set with the same `#[mix(...)]` attributes as for the `Mix` derive.
+#[proc_macro_derive(Distance, attributes(mix))]
+pub fn distance_macro(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    distance_impl(&input).into()
+}
+
+fn distance_impl(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+
+    let members: Vec<Member> = match input.data {
+        syn::Data::Struct(ref data) => data
+            .fields
             .iter()
-            .map(|param| match param {
-                GenericParam::Type(t) => {
-                    let name = t.ident.clone();
-                    quote! { #name }
-                }
-                GenericParam::Lifetime(l) => {
-                    let lifetime = l.lifetime.clone();
-                    quote! { #lifetime }
-                }
-                GenericParam::Const(c) => {
-                    let name = c.ident.clone();
-                    quote! { #name }
-                }
+            .enumerate()
+            .map(|(i, field)| match field.ident {
+                Some(ref ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(i)),
             })
-            .collect::<Vec<_>>();
-        quote! {
-            <#(#names),*>
+            .collect(),
+        syn::Data::Enum(_) => {
+            return Error::CantDeriveForEnum("Distance").into();
+        }
+        syn::Data::Union(_) => {
+            return Error::CantDeriveForUnion("Distance").into();
+        }
+    };
+    let count = members.len();
+
+    let generics = match bounded_generics(input, quote! { glissade::Distance }) {
+        Ok(generics) => generics,
+        Err(error) => return error.to_compile_error(),
+    };
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    // Field distances are combined as an array, so it works without `std` in the user's crate.
+    quote! {
+        impl #impl_generics glissade::Distance for #name #type_generics #where_clause {
+            fn distance(self, other: Self) -> f32 {
+                let distances: [f32; #count] = [
+                    #(glissade::Distance::distance(self.#members, other.#members)),*
+                ];
+                glissade::Distance::distance(distances, [0.0; #count])
+            }
         }
+    }
+}
+
+/// Derive everything a value needs for path-based animation with `poly_to`:
+/// `Mix`, `Distance`, and `Stationary` (so a plain value is an always finished animation).
+/// The type must also implement `Clone`.
+/// Type parameter bounds are set with the same `#[mix(...)]` attributes as for the `Mix` derive.
+#[proc_macro_derive(Animate, attributes(mix))]
+pub fn animate_macro(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let mix = mix_impl(&input);
+    let distance = distance_impl(&input);
+    let stationary = match stationary_impl(&input) {
+        Ok(stationary) => stationary,
+        Err(error) => return error.to_compile_error().into(),
     };
 
-    let generic_params = if generic_params.is_empty() {
-        quote! {}
-    } else {
+    (quote! {
+        #mix
+        #distance
+        #stationary
+    })
+    .into()
+}
+
+/// Derive the `Stationary` trait, so a value of the type is an always finished animation,
+/// e.g. an enum or a label joined with animated values. The type must also implement `Clone`.
+/// Type parameters are required to implement `Clone`, bounds are set with the same `#[mix(...)]` attributes as for the `Mix` derive.
+#[proc_macro_derive(Stationary, attributes(mix))]
+pub fn stationary_macro(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match stationary_impl(&input) {
+        Ok(stationary) => stationary.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn stationary_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let mut generics = bounded_generics(input, quote! { Clone })?;
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { #name #type_generics: Clone });
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics glissade::Stationary for #name #type_generics #where_clause {}
+    })
+}
+
+/// Derive the `PropertyAccess` trait for a struct.
+/// Each field is a property named after the field (or its index for tuple structs),
+/// and the rest of a dotted path like `"color.alpha"` is passed to the field.
+/// Fields marked with `#[property(skip)]` are not accessible.
+#[proc_macro_derive(PropertyAccess, attributes(property))]
+pub fn property_access_macro(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+
+    let fields: Vec<(String, Member, Type)> = match input.data {
+        syn::Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => fields
+                .named
+                .iter()
+                .filter(|field| !is_skipped(field))
+                .map(|field| {
+                    let ident = field.ident.as_ref().unwrap();
+                    (
+                        ident.to_string(),
+                        Member::Named(ident.clone()),
+                        field.ty.clone(),
+                    )
+                })
+                .collect(),
+            Fields::Unnamed(ref fields) => fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| !is_skipped(field))
+                .map(|(i, field)| {
+                    (
+                        i.to_string(),
+                        Member::Unnamed(Index::from(i)),
+                        field.ty.clone(),
+                    )
+                })
+                .collect(),
+            Fields::Unit => Vec::new(),
+        },
+        syn::Data::Enum(_) => {
+            return Error::CantDeriveForEnum("PropertyAccess").into();
+        }
+        syn::Data::Union(_) => {
+            return Error::CantDeriveForUnion("PropertyAccess").into();
+        }
+    };
+
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    let type_generics = quote! { #type_generics };
+
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote! { __V });
+    let where_clause = generics.make_where_clause();
+    for (_, _, ty) in &fields {
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: glissade::PropertyAccess<__V> });
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let get_arms = fields.iter().map(|(property, member, ty)| {
+        quote! {
+            #property => <#ty as glissade::PropertyAccess<__V>>::get_property(&self.#member, rest)
+        }
+    });
+    let set_arms = fields.iter().map(|(property, member, ty)| {
         quote! {
-            <#generic_params>
+            #property => <#ty as glissade::PropertyAccess<__V>>::set_property(&mut self.#member, rest, value)
         }
+    });
+
+    (quote! {
+        impl #impl_generics glissade::PropertyAccess<__V> for #name #type_generics #where_clause {
+            #[allow(unused_variables)]
+            fn get_property(&self, path: &str) -> Option<__V> {
+                let (property, rest) = path.split_once('.').unwrap_or((path, ""));
+                match property {
+                    #(#get_arms,)*
+                    _ => None,
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn set_property(&mut self, path: &str, value: __V) -> bool {
+                let (property, rest) = path.split_once('.').unwrap_or((path, ""));
+                match property {
+                    #(#set_arms,)*
+                    _ => false,
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// Derive the `Animated` trait for a struct with animated fields, e.g. a view model.
+/// The value is a plain struct with the same field names (or the same number of fields for tuple structs),
+/// each field of it is the value of the corresponding animated field.
+/// The animation is finished when all fields are finished.
+///
+/// * `#[animated(value = ViewValue)]` - The value type, it's required.
+/// * `#[animated(time = Instant)]` - The time type, it's required. It can be a generic parameter of the struct.
+#[proc_macro_derive(Animated, attributes(animated))]
+pub fn animated_macro(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let mut value: Option<Type> = None;
+    let mut time: Option<Type> = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("animated"))
+    {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("value") {
+                value = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("time") {
+                time = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `value` or `time`"))
+            }
+        });
+        if let Err(error) = result {
+            return error.to_compile_error().into();
+        }
+    }
+    let (Some(value), Some(time)) = (value, time) else {
+        return Error::InvalidAttributes("Animated", "#[animated(value = ..., time = ...)]").into();
     };
 
-    let where_clause = input.generics.where_clause;
+    // The value is constructed by its path, generic arguments are inferred.
+    let mut value_path = match &value {
+        Type::Path(path) => path.path.clone(),
+        _ => return Error::InvalidAttributes("Animated", "a struct path as `value`").into(),
+    };
+    if let Some(segment) = value_path.segments.last_mut() {
+        segment.arguments = syn::PathArguments::None;
+    }
+
+    let (constructor, members) = match input.data {
+        syn::Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                let members: Vec<Member> = fields
+                    .named
+                    .iter()
+                    .map(|field| Member::Named(field.ident.clone().unwrap()))
+                    .collect();
+                let values = members.iter().map(|member| {
+                    quote! { #member: glissade::Animated::get(&self.#member, time) }
+                });
+                (quote! { #value_path { #(#values),* } }, members)
+            }
+            Fields::Unnamed(ref fields) => {
+                let members: Vec<Member> = (0..fields.unnamed.len())
+                    .map(|i| Member::Unnamed(Index::from(i)))
+                    .collect();
+                let values = members.iter().map(|member| {
+                    quote! { glissade::Animated::get(&self.#member, time) }
+                });
+                (quote! { #value_path ( #(#values),* ) }, members)
+            }
+            Fields::Unit => (quote! { #value_path }, Vec::new()),
+        },
+        syn::Data::Enum(_) => {
+            return Error::CantDeriveForEnum("Animated").into();
+        }
+        syn::Data::Union(_) => {
+            return Error::CantDeriveForUnion("Animated").into();
+        }
+    };
+
+    let finished = members.iter().map(|member| {
+        quote! { glissade::Animated::is_finished(&self.#member, time) }
+    });
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
 
     (quote! {
-        impl #generic_params glissade::Mix for #name #generic_names #where_clause {
-            fn mix(self, other: Self, t: f32) -> Self {
-                Self #fields
+        impl #impl_generics glissade::Animated<#value, #time> for #name #type_generics #where_clause {
+            fn get(&self, time: #time) -> #value {
+                #constructor
+            }
+
+            fn is_finished(&self, time: #time) -> bool {
+                true #(&& #finished)*
             }
         }
     })
     .into()
 }
+
+/// Bounds of the `Mix` derive implementation.
+enum MixBounds {
+    /// `T: Mix` (or the derived trait) for each type parameter.
+    Inferred,
+    /// `#[mix(bound = "...")]`
+    Custom(Punctuated<WherePredicate, Token![,]>),
+    /// `#[mix(no_bounds)]`
+    None,
+}
+
+/// Generics of a derived implementation, the bounds depend on the `#[mix(...)]` attributes.
+fn bounded_generics(input: &DeriveInput, bound: proc_macro2::TokenStream) -> syn::Result<Generics> {
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    match mix_bounds(&input.attrs)? {
+        MixBounds::Inferred => {
+            for param in input.generics.type_params() {
+                let param = &param.ident;
+                where_clause
+                    .predicates
+                    .push(parse_quote! { #param: #bound });
+            }
+        }
+        MixBounds::Custom(predicates) => where_clause.predicates.extend(predicates),
+        MixBounds::None => {}
+    }
+    Ok(generics)
+}
+
+/// Parse the `#[mix(...)]` attributes.
+fn mix_bounds(attrs: &[Attribute]) -> syn::Result<MixBounds> {
+    let mut bounds = MixBounds::Inferred;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("mix")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                let value: LitStr = meta.value()?.parse()?;
+                bounds = MixBounds::Custom(value.parse_with(Punctuated::parse_terminated)?);
+                Ok(())
+            } else if meta.path.is_ident("no_bounds") {
+                bounds = MixBounds::None;
+                Ok(())
+            } else {
+                Err(meta.error("expected `bound` or `no_bounds`"))
+            }
+        })?;
+    }
+    Ok(bounds)
+}
+
+/// Check if the field has the `#[property(skip)]` attribute.
+fn is_skipped(field: &Field) -> bool {
+    field.attrs.iter().any(|attr| {
+        let mut skip = false;
+        if attr.path().is_ident("property") {
+            let _ = attr.parse_nested_meta(|meta| {
+                skip |= meta.path.is_ident("skip");
+                Ok(())
+            });
+        }
+        skip
+    })
+}
+
+/// Build keyframes from a compact track of absolute times, e.g.
+/// `keyframes! { 0s => start, 1s ease QuadraticOut => mid, 2.5s => end }`.
+///
+/// Each keyframe is `time [linear | ease EASING] => value`, the transition is linear by default.
+/// Times with `s` and `ms` suffixes are `core::time::Duration`, plain numbers are used as is,
+/// so they must be all integers or all floats with the same suffix.
+/// A capitalized `EASING` is a variant of `glissade::Easing`, anything else is an expression.
+/// It expands to `keyframes::from(...)` followed by `go_to` and `ease_to` calls
+/// with durations between neighbour keyframes, the first time above zero adds a `stay`.
+#[proc_macro]
+pub fn keyframes(input: TokenStream) -> TokenStream {
+    let track = parse_macro_input!(input as keyframes::Track);
+    track
+        .expand()
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
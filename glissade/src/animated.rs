@@ -1,5 +1,8 @@
-use crate::Time;
-use std::fmt::Debug;
+#[cfg(feature = "futures")]
+use crate::AnimatedStream;
+use crate::{Finished, Time, Timer};
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 /// An animated value that changes over time.
 /// It's a common trait for `Animation` and `Inertial`.
@@ -10,6 +13,12 @@ pub trait Animated<T, X: Time> {
     /// Check if the animation is finished at a specific time.
     fn is_finished(&self, time: X) -> bool;
 
+    /// Get values at several times, e.g. to render a motion trail.
+    /// Animations based on keyframes walk their segments, when times are sorted.
+    fn get_many(&self, times: &[X]) -> Vec<T> {
+        times.iter().map(|time| self.get(*time)).collect()
+    }
+
     /// Map the animated value to another type.
     fn map<R, F: Fn(T) -> R>(self, map: F) -> AnimatedMap<T, X, Self, R, F>
     where
@@ -36,6 +45,69 @@ pub trait Animated<T, X: Time> {
     {
         AnimatedFlatten::new(self)
     }
+
+    /// Wrap the animation, so it can be replaced by its last value once it's finished,
+    /// see `HoldLast::update`.
+    fn hold_last(self) -> HoldLast<T, X, Self>
+    where
+        Self: Sized,
+    {
+        HoldLast::new(self)
+    }
+
+    /// Get a future that resolves when the animation is finished, e.g. to continue an async UI flow.
+    /// The animation is checked with the timer time after each of its ticks.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "tokio")]
+    /// # async fn example() {
+    /// use glissade::driver::tokio::TokioTimer;
+    /// use glissade::{keyframes, Animated, Keyframes};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let timer = TokioTimer::new(Duration::from_millis(16));
+    /// let animation = keyframes::line(0.0, 1.0, Duration::from_millis(300)).run(Instant::now());
+    /// animation.finished(&timer).await;
+    /// # }
+    /// ```
+    fn finished<C: Timer<X>>(&self, timer: C) -> Finished<'_, T, X, Self, C>
+    where
+        Self: Sized,
+    {
+        Finished::new(self, timer)
+    }
+
+    /// Get a stream of values sampled on every timer tick, e.g. `TokioTimer::new(interval)`
+    /// samples them every `interval`. The first value is sampled immediately,
+    /// and the stream ends after the value of the finished animation.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[cfg(all(feature = "futures", feature = "tokio"))]
+    /// # async fn example() {
+    /// use futures::StreamExt;
+    /// use glissade::driver::tokio::TokioTimer;
+    /// use glissade::{keyframes, Animated, Keyframes};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let timer = TokioTimer::new(Duration::from_millis(16));
+    /// let animation = keyframes::line(0.0, 1.0, Duration::from_millis(300)).run(Instant::now());
+    /// let mut values = animation.stream(timer);
+    /// while let Some(opacity) = values.next().await {
+    ///     println!("{}", opacity);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "futures")]
+    fn stream<C: Timer<X>>(self, timer: C) -> AnimatedStream<T, X, Self, C>
+    where
+        Self: Sized,
+    {
+        AnimatedStream::new(self, timer)
+    }
 }
 
 impl<X: Time> Animated<(), X> for () {
@@ -134,7 +206,7 @@ impl<T: Clone + Copy + Default, X: Time, I: Animated<T, X>, const S: usize> Anim
 pub struct AnimatedMap<T, X: Time, A: Animated<T, X>, R, F: Fn(T) -> R> {
     animated: A,
     map: F,
-    phantom: std::marker::PhantomData<(T, X)>,
+    phantom: core::marker::PhantomData<(T, X)>,
 }
 
 impl<T, X: Time, A: Animated<T, X>, R, F: Fn(T) -> R> AnimatedMap<T, X, A, R, F> {
@@ -154,6 +226,11 @@ impl<T, X: Time, A: Animated<T, X>, R, F: Fn(T) -> R> Animated<R, X>
         (self.map)(self.animated.get(time))
     }
 
+    fn get_many(&self, times: &[X]) -> Vec<R> {
+        let values = self.animated.get_many(times);
+        values.into_iter().map(&self.map).collect()
+    }
+
     fn is_finished(&self, time: X) -> bool {
         self.animated.is_finished(time)
     }
@@ -176,7 +253,7 @@ impl<T, X: Time, A: Animated<T, X>, R, F: Fn(T) -> R> Debug for AnimatedMap<T, X
 where
     A: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("AnimatedMap")
             .field("animated", &self.animated)
             .field("map", &"Fn(T) -> R")
@@ -187,7 +264,7 @@ where
 pub struct AnimatedJoin<T1, T2, X: Time, A1: Animated<T1, X>, A2: Animated<T2, X>> {
     animated1: A1,
     animated2: A2,
-    phantom: std::marker::PhantomData<(T1, T2, X)>,
+    phantom: core::marker::PhantomData<(T1, T2, X)>,
 }
 
 impl<T1, T2, X: Time, A1: Animated<T1, X>, A2: Animated<T2, X>> AnimatedJoin<T1, T2, X, A1, A2> {
@@ -232,7 +309,7 @@ impl<T1, T2, X: Time, A1: Animated<T1, X> + Copy, A2: Animated<T2, X> + Copy> Co
 impl<T1, T2, X: Time, A1: Animated<T1, X> + Debug, A2: Animated<T2, X> + Debug> Debug
     for AnimatedJoin<T1, T2, X, A1, A2>
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("AnimatedJoin")
             .field("animated1", &self.animated1)
             .field("animated2", &self.animated2)
@@ -242,7 +319,7 @@ impl<T1, T2, X: Time, A1: Animated<T1, X> + Debug, A2: Animated<T2, X> + Debug>
 
 pub struct AnimatedFlatten<T, X: Time, A: Animated<T, X>, AG: Animated<A, X>> {
     animated: AG,
-    phantom: std::marker::PhantomData<(T, X, A)>,
+    phantom: core::marker::PhantomData<(T, X, A)>,
 }
 
 impl<T, X: Time, A: Animated<T, X>, AG: Animated<A, X>> AnimatedFlatten<T, X, A, AG> {
@@ -287,13 +364,104 @@ impl<T, X: Time, A: Animated<T, X>, AG: Animated<A, X> + Debug> Debug
 where
     A: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("AnimatedFlatten")
             .field("animated", &self.animated)
             .finish()
     }
 }
 
+/// An animation that is replaced by its last value once it's finished,
+/// so long-lived state doesn't keep the keyframes and doesn't evaluate them anymore.
+/// The replacement happens in `update`, usually called once per frame.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Animated, Keyframes};
+///
+/// let mut value = keyframes::line::<f32, f32>(0.0, 1.0, 1.0).run(0.0).hold_last();
+/// value.update(0.5);
+/// assert!(!value.is_held());
+/// value.update(2.0);
+/// assert!(value.is_held());
+/// assert_eq!(value.get(3.0), 1.0);
+/// ```
+pub struct HoldLast<T, X: Time, A: Animated<T, X>> {
+    state: HoldState<T, A>,
+    phantom: core::marker::PhantomData<X>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HoldState<T, A> {
+    Running(A),
+    Held(T),
+}
+
+impl<T, X: Time, A: Animated<T, X>> HoldLast<T, X, A> {
+    pub fn new(animated: A) -> Self {
+        Self {
+            state: HoldState::Running(animated),
+            phantom: Default::default(),
+        }
+    }
+
+    /// Replace the animation by its value if it's finished at the time.
+    pub fn update(&mut self, time: X) {
+        if let HoldState::Running(animated) = &self.state {
+            if animated.is_finished(time) {
+                self.state = HoldState::Held(animated.get(time));
+            }
+        }
+    }
+
+    /// Check if the animation was replaced by its last value.
+    pub fn is_held(&self) -> bool {
+        matches!(self.state, HoldState::Held(_))
+    }
+}
+
+impl<T: Clone, X: Time, A: Animated<T, X>> Animated<T, X> for HoldLast<T, X, A> {
+    fn get(&self, time: X) -> T {
+        match &self.state {
+            HoldState::Running(animated) => animated.get(time),
+            HoldState::Held(value) => value.clone(),
+        }
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        match &self.state {
+            HoldState::Running(animated) => animated.is_finished(time),
+            HoldState::Held(_) => true,
+        }
+    }
+}
+
+impl<T: Clone, X: Time, A: Animated<T, X> + Clone> Clone for HoldLast<T, X, A> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: Copy, X: Time, A: Animated<T, X> + Copy> Copy for HoldLast<T, X, A> {}
+
+impl<T: PartialEq, X: Time, A: Animated<T, X> + PartialEq> PartialEq for HoldLast<T, X, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: Debug, X: Time, A: Animated<T, X> + Debug> Debug for HoldLast<T, X, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HoldLast")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -304,6 +472,20 @@ mod test {
     #[derive(Clone, Copy, Debug, PartialEq, Mix)]
     struct TestItem(f32);
 
+    #[test]
+    fn hold_last() {
+        let mut animated = keyframes::line::<f32, f32>(0.0, 1.0, 1.0)
+            .run(0.0)
+            .hold_last();
+        animated.update(0.5);
+        assert!(!animated.is_held());
+        assert_eq!(animated.get(0.5), 0.5);
+        animated.update(1.0);
+        assert!(animated.is_held());
+        assert_eq!(animated.get(0.0), 1.0);
+        assert!(animated.is_finished(0.0));
+    }
+
     #[test]
     fn animated_map() {
         let animated = keyframes::from(TestItem(0.0))
@@ -0,0 +1,133 @@
+use super::keyframes_group::Barrier;
+use crate::{Animated, Keyframes, Time, TimeDiff};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Animations of the same value type running together, e.g. inertial values and keyframe animations
+/// of a single view, with a single `is_finished` for the whole group. The value is a vector of item values.
+/// Unlike `GroupKeyframes`, items are type-erased and keep their own start times,
+/// see `staggered` to start keyframes one after another.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Animated, AnimationGroup, Inertial, Keyframes};
+///
+/// let mut group = AnimationGroup::all([]);
+/// group.push(keyframes::line::<f32, f32>(0.0, 1.0, 1.0).run(0.0));
+/// group.push(Inertial::new(0.0).go_to(2.0, 0.0, 2.0));
+/// assert_eq!(group.get(2.0), vec![1.0, 2.0]);
+/// assert!(!group.is_finished(1.5));
+/// ```
+pub struct AnimationGroup<T, X: Time> {
+    items: Vec<Box<dyn Animated<T, X>>>,
+    barrier: Barrier,
+}
+
+impl<T, X: Time> AnimationGroup<T, X> {
+    /// The group is finished when all items are finished.
+    pub fn all(items: impl IntoIterator<Item = Box<dyn Animated<T, X>>>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            barrier: Barrier::All,
+        }
+    }
+
+    /// The group is finished when the first item is finished.
+    /// Item values are not snapped, since animated values don't report their end,
+    /// use `keyframes::race` when the other items should jump to their end values.
+    pub fn race(items: impl IntoIterator<Item = Box<dyn Animated<T, X>>>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            barrier: Barrier::Race,
+        }
+    }
+
+    /// Run keyframes side by side, the item `i` starts at `start_time` plus `i` delays,
+    /// the group is finished when all items are finished, see `keyframes::staggered`.
+    pub fn staggered(
+        items: impl IntoIterator<Item = Box<dyn Keyframes<T, X>>>,
+        start_time: X,
+        delay: X::Duration,
+    ) -> Self
+    where
+        T: 'static,
+        X: 'static,
+    {
+        Self::all(items.into_iter().enumerate().map(|(i, keyframes)| {
+            Box::new(keyframes.run(start_time.advance(delay.scale(i as f32))))
+                as Box<dyn Animated<T, X>>
+        }))
+    }
+
+    /// Add an item to the group.
+    pub fn push(&mut self, item: impl Animated<T, X> + 'static) {
+        self.items.push(Box::new(item));
+    }
+
+    /// Get the number of items in the group.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check if the group has no items, it's always finished.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T, X: Time> Animated<Vec<T>, X> for AnimationGroup<T, X> {
+    fn get(&self, time: X) -> Vec<T> {
+        self.items.iter().map(|item| item.get(time)).collect()
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        match self.barrier {
+            Barrier::All => self.items.iter().all(|item| item.is_finished(time)),
+            Barrier::Race => {
+                self.items.is_empty() || self.items.iter().any(|item| item.is_finished(time))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+
+    #[test]
+    fn all_and_race() {
+        let mut all = AnimationGroup::all([]);
+        assert!(all.is_empty());
+        assert!(all.is_finished(0.0));
+        all.push(keyframes::line::<f32, f32>(0.0, 1.0, 1.0).run(0.0));
+        all.push(keyframes::line(0.0, 1.0, 1.0).run(0.5));
+        assert_eq!(all.len(), 2);
+        assert_eq!(all.get(1.0), vec![1.0, 0.5]);
+        assert!(!all.is_finished(1.25));
+        assert!(all.is_finished(1.5));
+
+        let mut race = AnimationGroup::race([]);
+        race.push(keyframes::line::<f32, f32>(0.0, 1.0, 1.0).run(0.0));
+        race.push(keyframes::line(0.0, 1.0, 1.0).repeat().run(0.0));
+        assert!(!race.is_finished(0.5));
+        assert!(race.is_finished(1.0));
+        assert_eq!(race.get(1.5), vec![1.0, 0.5]);
+    }
+
+    #[test]
+    fn staggered() {
+        let group = AnimationGroup::staggered(
+            (0..3).map(|_| keyframes::line::<f32, f32>(0.0, 1.0, 1.0).boxed()),
+            1.0,
+            0.5,
+        );
+        assert_eq!(group.len(), 3);
+        assert_eq!(group.get(1.0), vec![0.0, 0.0, 0.0]);
+        assert_eq!(group.get(1.75), vec![0.75, 0.25, 0.0]);
+        assert!(!group.is_finished(2.5));
+        assert_eq!(group.get(3.0), vec![1.0, 1.0, 1.0]);
+        assert!(group.is_finished(3.0));
+    }
+}
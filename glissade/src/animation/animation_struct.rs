@@ -1,30 +1,75 @@
+use super::keyframes_reverse::ReverseKeyframes;
+use super::keyframes_sequential::SequentialKeyframes;
 use crate::animated::Animated;
-use crate::{Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use crate::math::round_f64;
+use crate::stationary::Frozen;
+use crate::{Keyframes, Time, TimeDiff};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// The value of an `Animation` before its start time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum BeforeStart {
+    /// The start value, as if time was clamped to the start time.
+    #[default]
+    StartValue,
+    /// The end value, e.g. for an exit animation that shouldn't show its start state in advance.
+    /// Infinite animations return the start value.
+    EndValue,
+}
 
 /// Running keyframes animation started at a specific time.
 pub struct Animation<I, X: Time, T: Keyframes<I, X>> {
     keyframes: T,
     start_time: X,
+    /// Keyframes offset at `start_time`, it's changed by `seek`.
+    offset: X::Duration,
+    /// Playback speed, the keyframes offset advances `rate` times faster than the time.
+    rate: f32,
+    before_start: BeforeStart,
     phantom: PhantomData<I>,
 }
 
 impl<I, X: Time, T: Keyframes<I, X> + Debug> Debug for Animation<I, X, T>
 where
     X: Debug,
+    X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Animation")
             .field("keyframes", &self.keyframes)
             .field("start_time", &self.start_time)
+            .field("offset", &self.offset)
+            .field("rate", &self.rate)
+            .field("before_start", &self.before_start)
             .finish()
     }
 }
 
 impl<I, X: Time, T: Keyframes<I, X> + PartialEq> PartialEq for Animation<I, X, T> {
     fn eq(&self, other: &Self) -> bool {
-        self.keyframes == other.keyframes && self.start_time == other.start_time
+        self.keyframes == other.keyframes
+            && self.start_time == other.start_time
+            && self.offset == other.offset
+            && self.rate == other.rate
+            && self.before_start == other.before_start
+    }
+}
+
+impl<I, X: Time + Eq, T: Keyframes<I, X> + Eq> Eq for Animation<I, X, T> where X::Duration: Eq {}
+
+impl<I, X: Time + Hash, T: Keyframes<I, X> + Hash> Hash for Animation<I, X, T>
+where
+    X::Duration: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.keyframes.hash(state);
+        self.start_time.hash(state);
+        self.offset.hash(state);
+        self.rate.to_bits().hash(state);
+        self.before_start.hash(state);
     }
 }
 
@@ -37,38 +82,301 @@ impl<I, X: Time, T: Keyframes<I, X>> Animation<I, X, T> {
         Self {
             keyframes,
             start_time,
+            offset: Default::default(),
+            rate: 1.0,
+            before_start: Default::default(),
             phantom: Default::default(),
         }
     }
 
+    /// Set the value returned before the start time, it's the start value by default.
+    pub fn before_start(self, before_start: BeforeStart) -> Self {
+        Self {
+            before_start,
+            ..self
+        }
+    }
+
+    /// Set the playback speed, `1.0` by default, `0.5` is two times slower.
+    /// It panics if the rate is negative, NaN or infinite.
+    pub fn with_rate(self, rate: f32) -> Self {
+        Self {
+            rate: valid_rate(rate),
+            ..self
+        }
+    }
+
+    /// Get the playback speed.
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    /// Change the playback speed starting from `time`, e.g. for slow motion.
+    /// The value stays continuous, only its rate changes. Zero rate holds the current value.
+    /// It panics if the rate is negative, NaN or infinite.
+    pub fn set_rate(&mut self, rate: f32, time: X) {
+        let rate = valid_rate(rate);
+        if time > self.start_time {
+            self.seek(time, self.offset_at(time));
+        }
+        self.rate = rate;
+    }
+
     /// Get the start time of the animation.
+    /// After `seek` it's the time when the animation was at the seek offset.
     pub fn start_time(&self) -> X {
         self.start_time
     }
 
+    /// Move the animation to a new start time, keeping its keyframes and current offset.
+    /// E.g. to delay an animation that hasn't started yet.
+    pub fn set_start_time(&mut self, start_time: X) {
+        self.start_time = start_time;
+    }
+
+    /// Jump to the keyframes `offset` at `time`, the animation continues from there.
+    /// It's used to scrub a timeline, or to resume after a pause:
+    /// `animation.seek(now, animation.offset_at(pause_time))`.
+    pub fn seek(&mut self, time: X, offset: X::Duration) {
+        self.start_time = time;
+        self.offset = offset;
+    }
+
+    /// Run the animation again from the beginning at `time`, keeping its rate.
+    pub fn restart(&mut self, time: X) {
+        self.seek(time, Default::default());
+    }
+
+    /// Replace the keyframes and run them from `time`, keeping the rate and the before start policy.
+    /// The new keyframes are built from the value shown at `time`, so they can start from it without a jump.
+    ///
+    /// ```
+    /// use glissade::{keyframes, Animated, Keyframes};
+    ///
+    /// let animation = keyframes::line::<f32, f32>(0.0, 10.0, 10.0).run(0.0);
+    /// let animation = animation.restart_with(4.0, |value| keyframes::from(value).go_to(0.0, 2.0));
+    /// assert_eq!(animation.get(4.0), 4.0);
+    /// assert_eq!(animation.get(5.0), 2.0);
+    /// ```
+    pub fn restart_with<K, F>(self, time: X, keyframes: F) -> Animation<I, X, K>
+    where
+        K: Keyframes<I, X>,
+        F: FnOnce(I) -> K,
+    {
+        Animation {
+            keyframes: keyframes(self.get(time)),
+            start_time: time,
+            offset: Default::default(),
+            rate: self.rate,
+            before_start: self.before_start,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Schedule the next keyframes to start exactly at the end time of the animation.
+    /// They are built from the end value, so they can continue without a jump.
+    /// The rate applies to the whole sequence. It panics if the animation is infinite.
+    ///
+    /// ```
+    /// use glissade::{keyframes, Animated, Keyframes};
+    ///
+    /// let animation = keyframes::line::<f32, f32>(0.0, 10.0, 10.0).run(5.0);
+    /// let animation = animation.followed_by(|value| keyframes::from(value).go_to(0.0, 5.0));
+    /// assert_eq!(animation.get(15.0), 10.0);
+    /// assert_eq!(animation.get(17.0), 6.0);
+    /// assert_eq!(animation.end_time(), 20.0);
+    /// ```
+    pub fn followed_by<K, F>(self, keyframes: F) -> Animation<I, X, SequentialKeyframes<I, X, T, K>>
+    where
+        K: Keyframes<I, X>,
+        F: FnOnce(I) -> K,
+    {
+        assert!(
+            self.keyframes.is_finite(),
+            "Infinite animation can't be followed by other keyframes"
+        );
+        let next = keyframes(self.keyframes.end_value());
+        Animation {
+            keyframes: SequentialKeyframes::new(self.keyframes, next),
+            start_time: self.start_time,
+            offset: self.offset,
+            rate: self.rate,
+            before_start: self.before_start,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Get an animation that plays backwards from the value shown at `time`, without a jump,
+    /// e.g. to revert a hover effect that hasn't finished yet. The rate is kept.
+    /// It panics if the animation is infinite.
+    pub fn reverse_at(self, time: X) -> Animation<I, X, ReverseKeyframes<I, X, T>> {
+        let duration = self.keyframes.duration();
+        let offset = self.offset_at(time);
+        let offset = if offset < duration {
+            duration - offset
+        } else {
+            Default::default()
+        };
+        Animation {
+            keyframes: ReverseKeyframes::new(self.keyframes),
+            start_time: time,
+            offset,
+            rate: self.rate,
+            before_start: self.before_start,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Get the keyframes offset at the time.
+    /// Times before the start time give the offset at the start time.
+    pub fn offset_at(&self, time: X) -> X::Duration {
+        let elapsed = time.saturating_since(self.start_time);
+        if self.rate == 1.0 {
+            self.offset + elapsed
+        } else {
+            self.offset + elapsed.scale(self.rate)
+        }
+    }
+
+    /// Get the normalized progress at the time, from 0 to 1.
+    /// Infinite animations return the progress of the current cycle if the keyframes repeat,
+    /// see `Keyframes::period`, and 0 otherwise. Zero-duration animations are always complete.
+    pub fn progress(&self, time: X) -> f32 {
+        let offset = self.offset_at(time);
+        let (offset, duration) = match self.keyframes.try_duration() {
+            Some(duration) => (offset, duration),
+            None => match self.keyframes.period() {
+                Some(period) => (offset.wrap(period), period),
+                None => return 0.0,
+            },
+        };
+        if duration == Default::default() {
+            1.0
+        } else {
+            (offset.as_f64() / duration.as_f64()).min(1.0) as f32
+        }
+    }
+
+    /// Capture the value at the time, e.g. to replace an interrupted animation by a constant.
+    pub fn freeze_at(&self, time: X) -> Frozen<I> {
+        Frozen(self.get(time))
+    }
+
+    /// Get the index of the repeat cycle at the time, starting from 0,
+    /// e.g. to spawn a particle on every pulse of a looped animation.
+    /// A new cycle starts exactly at a multiple of the cycle duration, see `Keyframes::period`.
+    /// It's always 0 if the keyframes don't repeat.
+    pub fn iteration(&self, time: X) -> u64 {
+        self.cycle(time).0
+    }
+
+    /// Get the offset inside the current repeat cycle, see `iteration`.
+    /// It's the keyframes offset if they don't repeat.
+    pub fn cycle_offset(&self, time: X) -> X::Duration {
+        self.cycle(time).1
+    }
+
+    fn cycle(&self, time: X) -> (u64, X::Duration) {
+        let mut offset = self.offset_at(time);
+        let Some(period) = self.keyframes.period() else {
+            return (0, offset);
+        };
+        let duration = self.keyframes.try_duration();
+        if let Some(duration) = duration {
+            if offset > duration {
+                offset = duration;
+            }
+        }
+        let rest = offset.wrap(period);
+        let iteration = round_f64((offset - rest).as_f64() / period.as_f64()) as u64;
+        if iteration > 0 && rest == Default::default() && Some(offset) == duration {
+            // The end of the last cycle of finite keyframes
+            (iteration - 1, period)
+        } else {
+            (iteration, rest)
+        }
+    }
+
+    /// Get the time left from the start time to the end of the keyframes,
+    /// `None` if the animation is infinite or never ends at zero rate.
+    fn remaining_duration(&self) -> Option<X::Duration> {
+        let duration = self.keyframes.try_duration()?;
+        if self.offset >= duration {
+            Some(Default::default())
+        } else if self.rate == 1.0 {
+            Some(duration - self.offset)
+        } else {
+            (duration - self.offset).checked_scale(1.0 / self.rate)
+        }
+    }
+
+    /// Check if the animation has started at the time.
+    pub fn has_started(&self, time: X) -> bool {
+        time >= self.start_time
+    }
+
     /// Get the end time of the animation.
-    /// Infinite animations will panic.
+    /// Infinite animations will panic, see `try_end_time`.
     pub fn end_time(&self) -> X {
-        self.start_time.advance(self.keyframes.duration())
+        match self.remaining_duration() {
+            Some(duration) => self.start_time.advance(duration),
+            None if self.is_finite() => panic!("Animation::end_time: the animation never ends"),
+            None => self.start_time.advance(self.keyframes.duration()),
+        }
+    }
+
+    /// Same as `end_time`, but returns `None` if the animation is infinite,
+    /// or the end time can't be represented.
+    pub fn try_end_time(&self) -> Option<X> {
+        self.start_time.checked_advance(self.remaining_duration()?)
     }
 
-    /// Get the duration of the animation.
-    /// Infinite animations will panic.
+    /// Get the duration of the keyframes, it doesn't depend on the rate.
+    /// Use `end_time` to get when the animation finishes at its rate.
+    /// Infinite animations will panic, see `try_duration`.
     pub fn duration(&self) -> X::Duration {
         self.keyframes.duration()
     }
 
+    /// Same as `duration`, but returns `None` if the animation is infinite.
+    pub fn try_duration(&self) -> Option<X::Duration> {
+        self.keyframes.try_duration()
+    }
+
+    /// Get the time left until the animation is finished, zero if it's already finished,
+    /// `None` if the animation is infinite, or its end time can't be represented.
+    /// Before the start time it includes the wait until the start.
+    pub fn remaining(&self, time: X) -> Option<X::Duration> {
+        Some(self.try_end_time()?.saturating_since(time))
+    }
+
     /// Check if the animation is infinite.
     pub fn is_finite(&self) -> bool {
         self.keyframes.is_finite()
     }
 }
 
+/// Check the rate, `-0.0` is replaced by `0.0`, so equal animations have equal hashes.
+fn valid_rate(rate: f32) -> f32 {
+    assert!(
+        rate >= 0.0 && rate.is_finite(),
+        "Animation rate must be finite and non-negative"
+    );
+    if rate == 0.0 {
+        0.0
+    } else {
+        rate
+    }
+}
+
 impl<I, X: Time, T: Keyframes<I, X> + Clone> Clone for Animation<I, X, T> {
     fn clone(&self) -> Self {
         Self {
             keyframes: self.keyframes.clone(),
             start_time: self.start_time,
+            offset: self.offset,
+            rate: self.rate,
+            before_start: self.before_start,
             phantom: Default::default(),
         }
     }
@@ -76,13 +384,28 @@ impl<I, X: Time, T: Keyframes<I, X> + Clone> Clone for Animation<I, X, T> {
 
 impl<I, X: Time, T: Keyframes<I, X> + Copy> Copy for Animation<I, X, T> {}
 
+/// An animation scheduled in the future returns the value set by `before_start` until it starts,
+/// the start value by default. Sampling it before the start time never panics.
 impl<I, X: Time, T: Keyframes<I, X>> Animated<I, X> for Animation<I, X, T> {
     fn get(&self, time: X) -> I {
-        self.keyframes.get(time.since(self.start_time))
+        if self.before_start == BeforeStart::EndValue && !self.has_started(time) {
+            if let Some(value) = self.keyframes.try_end_value() {
+                return value;
+            }
+        }
+        self.keyframes.get(self.offset_at(time))
+    }
+
+    fn get_many(&self, times: &[X]) -> Vec<I> {
+        if self.before_start == BeforeStart::EndValue {
+            return times.iter().map(|time| self.get(*time)).collect();
+        }
+        let offsets: Vec<X::Duration> = times.iter().map(|time| self.offset_at(*time)).collect();
+        self.keyframes.get_many(&offsets)
     }
 
     fn is_finished(&self, time: X) -> bool {
-        self.keyframes.is_finished(time.since(self.start_time))
+        self.keyframes.is_finished(self.offset_at(time))
     }
 }
 
@@ -101,4 +424,225 @@ mod tests {
         let result = animation.get(start_time + Duration::from_millis(500));
         assert_eq!(result, 5.0);
     }
+
+    #[test]
+    fn get_before_start() {
+        let keyframes = LinearKeyframes::new(0.0, 10.0, Duration::from_secs(1));
+        let start_time = Instant::now() + Duration::from_secs(10);
+        let animation = Animation::start(keyframes, start_time);
+        assert_eq!(animation.get(Instant::now()), 0.0);
+        assert!(!animation.is_finished(Instant::now()));
+    }
+
+    #[test]
+    fn before_start_policy() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 1.0);
+        let animation = Animation::start(keyframes, 5.0);
+        assert!(!animation.has_started(4.0));
+        assert!(animation.has_started(5.0));
+        assert_eq!(animation.get(4.0), 0.0);
+
+        let animation = animation.before_start(BeforeStart::EndValue);
+        assert_eq!(animation.get(4.0), 10.0);
+        assert_eq!(animation.get(5.5), 5.0);
+        assert_eq!(animation.get_many(&[4.0, 5.5]), vec![10.0, 5.0]);
+    }
+
+    #[test]
+    fn get_many() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 1.0);
+        let animation = Animation::start(keyframes, 1.0);
+        assert_eq!(
+            animation.get_many(&[0.0, 1.5, 1.25, 3.0]),
+            vec![0.0, 5.0, 2.5, 10.0]
+        );
+        assert_eq!(
+            animation.map(|value| value * 2.0).get_many(&[1.5]),
+            vec![10.0]
+        );
+    }
+
+    #[test]
+    fn seek() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 1.0);
+        let mut animation = Animation::start(keyframes, 1.0);
+        assert_eq!(animation.offset_at(1.5), 0.5);
+
+        // Pause at 1.5 and resume at 3.0
+        animation.seek(3.0, animation.offset_at(1.5));
+        assert_eq!(animation.get(2.0), 5.0);
+        assert_eq!(animation.get(3.25), 7.5);
+        assert_eq!(animation.end_time(), 3.5);
+        assert!(animation.is_finished(3.5));
+
+        animation.seek(4.0, 0.0);
+        assert_eq!(animation.get(4.5), 5.0);
+        assert_eq!(animation.try_end_time(), Some(5.0));
+
+        animation.set_start_time(10.0);
+        assert_eq!(animation.get(4.5), 0.0);
+        assert_eq!(animation.get(10.5), 5.0);
+
+        animation.seek(0.0, 2.0);
+        assert_eq!(animation.end_time(), 0.0);
+        assert_eq!(animation.get(0.0), 10.0);
+    }
+
+    #[test]
+    fn progress() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 4.0);
+        let animation = Animation::start(keyframes, 1.0);
+        assert_eq!(animation.progress(0.0), 0.0);
+        assert_eq!(animation.progress(2.0), 0.25);
+        assert_eq!(animation.progress(9.0), 1.0);
+
+        let repeat = Animation::start(keyframes.repeat().scale(0.5), 1.0);
+        assert_eq!(repeat.progress(2.0), 0.5);
+        assert_eq!(repeat.progress(5.5), 0.25);
+
+        let endless = Animation::start(
+            crate::keyframes::from(0.0)
+                .stay(1.0)
+                .repeat()
+                .slice_from(0.5),
+            0.0,
+        );
+        assert_eq!(endless.progress(1.0), 0.0);
+
+        let empty = Animation::start(LinearKeyframes::<f32, f32>::new(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(empty.progress(0.0), 1.0);
+    }
+
+    #[test]
+    fn remaining() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 4.0);
+        let mut animation = Animation::start(keyframes, 1.0);
+        assert_eq!(animation.remaining(0.0), Some(5.0));
+        assert_eq!(animation.remaining(2.0), Some(3.0));
+        assert_eq!(animation.remaining(6.0), Some(0.0));
+
+        animation.seek(2.0, 3.0);
+        assert_eq!(animation.remaining(2.5), Some(0.5));
+
+        let repeat = Animation::start(keyframes.repeat(), 1.0);
+        assert_eq!(repeat.remaining(2.0), None);
+    }
+
+    #[test]
+    fn rate() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 10.0);
+        let mut animation = Animation::start(keyframes, 0.0).with_rate(2.0);
+        assert_eq!(animation.get(2.0), 4.0);
+        assert_eq!(animation.end_time(), 5.0);
+
+        animation.set_rate(0.5, 2.0);
+        assert_eq!(animation.get(2.0), 4.0);
+        assert_eq!(animation.get(4.0), 5.0);
+        assert_eq!(animation.remaining(4.0), Some(10.0));
+        assert_eq!(animation.end_time(), 14.0);
+
+        animation.set_rate(0.0, 4.0);
+        assert_eq!(animation.get(100.0), 5.0);
+        assert!(!animation.is_finished(100.0));
+        assert_eq!(animation.try_end_time(), None);
+        assert_eq!(animation.progress(100.0), 0.5);
+        assert_eq!(animation.duration(), 10.0, "The keyframes duration");
+    }
+
+    #[test]
+    fn negative_zero_rate() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 1.0, 1.0);
+        let animation = Animation::start(keyframes, 0.0).with_rate(-0.0);
+        assert!(animation.rate().is_sign_positive());
+        assert_eq!(animation, Animation::start(keyframes, 0.0).with_rate(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Animation rate must be finite and non-negative")]
+    fn negative_rate() {
+        Animation::start(LinearKeyframes::<f32, f32>::new(0.0, 1.0, 1.0), 0.0).with_rate(-1.0);
+    }
+
+    #[test]
+    fn reverse_at() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 10.0);
+        let animation = Animation::start(keyframes, 0.0).reverse_at(4.0);
+        assert_eq!(animation.get(4.0), 4.0);
+        assert_eq!(animation.get(6.0), 2.0);
+        assert_eq!(animation.end_time(), 8.0);
+
+        let animation = animation.reverse_at(6.0);
+        assert_eq!(animation.get(6.0), 2.0);
+        assert_eq!(animation.get(7.0), 3.0);
+        assert_eq!(animation.end_time(), 14.0);
+
+        let finished = Animation::start(keyframes, 0.0).reverse_at(20.0);
+        assert_eq!(finished.get(20.0), 10.0);
+        assert_eq!(finished.get(25.0), 5.0);
+    }
+
+    #[test]
+    fn restart() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 10.0);
+        let mut animation = Animation::start(keyframes, 0.0).with_rate(2.0);
+        animation.seek(1.0, 5.0);
+        animation.restart(3.0);
+        assert_eq!(animation.get(3.0), 0.0);
+        assert_eq!(animation.get(4.0), 2.0);
+
+        let animation = animation.restart_with(4.0, |value| LinearKeyframes::new(value, 0.0, 2.0));
+        assert_eq!(animation.get(4.0), 2.0);
+        assert_eq!(animation.get(4.5), 1.0);
+        assert_eq!(animation.end_time(), 5.0);
+    }
+
+    #[test]
+    fn iteration() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 2.0);
+        let repeat = Animation::start(keyframes.repeat(), 1.0);
+        assert_eq!(repeat.iteration(0.0), 0);
+        assert_eq!(repeat.iteration(2.5), 0);
+        assert_eq!(repeat.iteration(3.0), 1);
+        assert_eq!(repeat.iteration(8.5), 3);
+        assert_eq!(repeat.cycle_offset(8.5), 1.5);
+
+        let repeat_n = Animation::start(keyframes.repeat_n(3.0), 0.0);
+        assert_eq!(repeat_n.iteration(5.0), 2);
+        assert_eq!(repeat_n.iteration(6.0), 2);
+        assert_eq!(repeat_n.cycle_offset(6.0), 2.0);
+        assert_eq!(repeat_n.iteration(100.0), 2);
+
+        let once = Animation::start(keyframes, 0.0);
+        assert_eq!(once.iteration(5.0), 0);
+        assert_eq!(once.cycle_offset(1.0), 1.0);
+    }
+
+    #[test]
+    fn followed_by() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 10.0);
+        let mut animation = Animation::start(keyframes, 0.0);
+        animation.seek(2.0, 6.0);
+        let animation: Box<dyn Animated<f32, f32>> =
+            Box::new(animation.followed_by(|value| LinearKeyframes::new(value, 0.0, 5.0)));
+        assert_eq!(animation.get(6.0), 10.0);
+        assert_eq!(animation.get(7.0), 8.0);
+        assert!(animation.is_finished(11.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Infinite animation can't be followed")]
+    fn followed_by_infinite() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 10.0);
+        Animation::start(keyframes.repeat(), 0.0)
+            .followed_by(|value| LinearKeyframes::new(value, 0.0, 1.0));
+    }
+
+    #[test]
+    fn freeze_at() {
+        let keyframes = LinearKeyframes::<f32, f32>::new(0.0, 10.0, 10.0);
+        let frozen = Animation::start(keyframes, 0.0).freeze_at(4.0);
+        assert_eq!(frozen, Frozen(4.0));
+        assert_eq!(frozen.get(100.0), 4.0);
+        assert!(Animated::<f32, f32>::is_finished(&frozen, 0.0));
+    }
 }
@@ -0,0 +1,228 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::math::{ceil_f64, floor_f64};
+use crate::{Animated, Keyframes, Mix, Time, TimeDiff};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Keyframes sampled with a fixed interval, values between samples are interpolated.
+/// Getting a value is a single lookup and mix, no matter how complex the source animation is,
+/// so it's useful for templates with many running instances. Clones share the samples.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, BakedAnimation, Easing, Keyframes};
+///
+/// let template = keyframes::from::<f32, f32>(0.0)
+///     .ease_to(1.0, 1.0, Easing::CubicInOut)
+///     .ease_to(0.0, 1.0, Easing::QuarticOut);
+/// let baked = BakedAnimation::new(&template, 0.05);
+///
+/// assert_eq!(baked.duration(), 2.0);
+/// assert_eq!(baked.get(1.0), 1.0);
+/// let instances: Vec<_> = (0..100).map(|i| baked.clone().run(i as f32 * 0.01)).collect();
+/// ```
+pub struct BakedAnimation<T, X: Time> {
+    samples: Arc<[T]>,
+    /// The exact duration of the source, samples are evenly spread over it.
+    duration: X::Duration,
+}
+
+impl<T: Debug, X: Time> Debug for BakedAnimation<T, X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BakedAnimation")
+            .field("samples", &self.samples)
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl<T, X: Time> Clone for BakedAnimation<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            samples: self.samples.clone(),
+            duration: self.duration,
+        }
+    }
+}
+
+impl<T: PartialEq, X: Time> PartialEq for BakedAnimation<T, X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.samples == other.samples && self.duration == other.duration
+    }
+}
+
+impl<T, X: Time> BakedAnimation<T, X> {
+    /// Sample finite keyframes, the last sample is taken exactly at the end,
+    /// so the actual interval can be slightly shorter than requested.
+    /// It panics if the keyframes are infinite, or the interval isn't positive.
+    pub fn new(keyframes: &impl Keyframes<T, X>, interval: X::Duration) -> Self {
+        assert!(keyframes.is_finite(), "Infinite keyframes can't be baked");
+        assert!(
+            interval > Default::default(),
+            "Bake interval must be positive"
+        );
+
+        let duration = keyframes.duration();
+        let count = ceil_f64(duration.as_f64() / interval.as_f64()).max(1.0) as usize;
+        let samples: Vec<T> = (0..=count)
+            .map(|i| {
+                if i == count {
+                    keyframes.get(duration)
+                } else {
+                    keyframes.get(duration.scale(i as f32 / count as f32))
+                }
+            })
+            .collect();
+
+        Self {
+            samples: samples.into(),
+            duration,
+        }
+    }
+
+    /// Sample an animation from `start_time` with a fixed interval until it's finished.
+    /// It panics if the interval isn't positive, and never returns if the animation is infinite.
+    pub fn from_animated(
+        animated: &impl Animated<T, X>,
+        start_time: X,
+        interval: X::Duration,
+    ) -> Self {
+        assert!(
+            interval > Default::default(),
+            "Bake interval must be positive"
+        );
+
+        let mut samples = Vec::new();
+        let mut offset: X::Duration = Default::default();
+        loop {
+            let time = start_time.advance(offset);
+            samples.push(animated.get(time));
+            if animated.is_finished(time) {
+                break;
+            }
+            // Offsets aren't accumulated, so rounding errors don't add up.
+            offset = interval.scale(samples.len() as f32);
+        }
+
+        Self {
+            samples: samples.into(),
+            duration: offset,
+        }
+    }
+
+    /// Get the number of samples.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// It's always `false`, there is at least one sample.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Keyframes<T, X> for BakedAnimation<T, X> {
+    fn get(&self, offset: X::Duration) -> T {
+        let last = self.samples.len() - 1;
+        let position = offset.as_f64() / self.duration.as_f64() * last as f64;
+        if last == 0 || position.is_nan() || position >= last as f64 {
+            return self.samples[last].clone();
+        }
+
+        let position = position.max(0.0);
+        let index = floor_f64(position);
+        let i = index as usize;
+        self.samples[i].mix_ref(&self.samples[i + 1], (position - index) as f32)
+    }
+
+    fn duration(&self) -> X::Duration {
+        self.duration
+    }
+
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Baked, Some(self.duration()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Easing};
+
+    #[test]
+    fn bake_keyframes() {
+        let keyframes = keyframes::from::<f32, f32>(0.0)
+            .go_to(1.0, 1.0)
+            .go_to(0.0, 1.0);
+        let baked = BakedAnimation::new(&keyframes, 0.3);
+        assert_eq!(baked.len(), 8);
+        assert_eq!(baked.duration(), 2.0);
+
+        let baked = BakedAnimation::new(&keyframes, 0.25);
+        assert_eq!(baked.len(), 9);
+        assert_eq!(baked.duration(), 2.0);
+        assert_eq!(baked.get(-1.0), 0.0);
+        assert_eq!(baked.get(0.125), 0.125);
+        assert_eq!(baked.get(1.0), 1.0);
+        assert_eq!(baked.get(1.5), 0.5);
+        assert_eq!(baked.get(3.0), 0.0);
+    }
+
+    #[test]
+    fn bake_tick_time() {
+        let keyframes = keyframes::line::<f32, u32>(0.0, 10.0, 10);
+        let baked = BakedAnimation::new(&keyframes, 3);
+        assert_eq!(baked.len(), 5);
+        assert_eq!(baked.duration(), 10, "Keeps the exact duration");
+        assert_eq!(baked.get(5), 5.0);
+        assert_eq!(baked.get(10), 10.0);
+        assert!(baked.run(0).is_finished(10));
+    }
+
+    #[test]
+    fn bake_close_to_source() {
+        let keyframes = keyframes::ease::<f32, f32>(0.0, 1.0, 1.0, Easing::CubicInOut);
+        let baked = BakedAnimation::new(&keyframes, 0.01);
+
+        for i in 0..=100 {
+            let offset = i as f32 * 0.0123;
+            assert!((baked.get(offset) - keyframes.get(offset)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn bake_animated() {
+        let animation = keyframes::line::<f32, f32>(0.0, 1.0, 1.0).run(10.0);
+        let baked = BakedAnimation::from_animated(&animation, 10.0, 0.25);
+
+        assert_eq!(baked.len(), 5);
+        assert_eq!(baked.duration(), 1.0);
+        assert_eq!(baked.get(0.375), 0.375);
+        assert!(baked.is_finished(1.0));
+    }
+
+    #[test]
+    fn bake_animated_below_time_precision() {
+        let start = 1e8_f32;
+        let animation = keyframes::line::<f32, f32>(0.0, 1.0, 32.0).run(start);
+        let baked = BakedAnimation::from_animated(&animation, start, 1.0);
+        assert_eq!(baked.end_value(), 1.0);
+    }
+
+    #[test]
+    fn bake_zero_duration() {
+        let baked = BakedAnimation::new(&keyframes::stay::<f32, f32>(5.0, 0.0), 0.1);
+        assert_eq!(baked.get(0.0), 5.0);
+        assert_eq!(baked.get(1.0), 5.0);
+        assert_eq!(baked.duration(), 0.0);
+    }
+}
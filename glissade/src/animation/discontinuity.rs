@@ -0,0 +1,19 @@
+/// The kind of a discontinuity found by `Keyframes::validate_continuity`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiscontinuityKind {
+    /// The value jumps (C0).
+    Value,
+    /// The value is continuous, but its velocity jumps (C1).
+    Velocity,
+}
+
+/// A discontinuity at a join of keyframes segments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Discontinuity<D> {
+    /// Offset of the join.
+    pub offset: D,
+    pub kind: DiscontinuityKind,
+    /// The distance between values around the join, or between velocities
+    /// in value units per `TimeDiff::as_f32` unit.
+    pub jump: f32,
+}
@@ -0,0 +1,164 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::{Keyframes, Mix, Time, TimeDiff};
+use core::fmt::Debug;
+
+/// A part of the duration spent on pulling back, and on settling after the overshoot.
+const PHASE_SHARE: f32 = 0.25;
+
+/// Cartoon-like motion from one value to another: it pulls back, shoots past the end, and settles.
+/// See `keyframes::anticipate_overshoot`.
+pub struct AnticipateOvershootKeyframes<T: Mix + Clone, X: Time> {
+    start: T,
+    end: T,
+    duration: X::Duration,
+    anticipation: f32,
+    overshoot: f32,
+}
+
+impl<T: Mix + Clone, X: Time> AnticipateOvershootKeyframes<T, X> {
+    /// `anticipation` and `overshoot` are parts of the distance between `start` and `end`,
+    /// the motion goes that far beyond `start` and `end`. Zero skips the phase.
+    pub fn new(start: T, end: T, duration: X::Duration, anticipation: f32, overshoot: f32) -> Self {
+        assert!(
+            anticipation >= 0.0
+                && anticipation.is_finite()
+                && overshoot >= 0.0
+                && overshoot.is_finite(),
+            "Anticipation and overshoot must be finite and non-negative"
+        );
+        Self {
+            start,
+            end,
+            duration,
+            anticipation,
+            overshoot,
+        }
+    }
+
+    /// Get the mix factor between `start` and `end` at the linear progress `t` in `[0, 1]`.
+    /// Each phase is smoothstepped, so the motion stops at the extremes.
+    fn progress(&self, t: f32) -> f32 {
+        let anticipation_end = if self.anticipation > 0.0 {
+            PHASE_SHARE
+        } else {
+            0.0
+        };
+        let settle_start = if self.overshoot > 0.0 {
+            1.0 - PHASE_SHARE
+        } else {
+            1.0
+        };
+        let low = -self.anticipation;
+        let high = 1.0 + self.overshoot;
+
+        if t < anticipation_end {
+            low * smoothstep(t / anticipation_end)
+        } else if t < settle_start {
+            let s = smoothstep((t - anticipation_end) / (settle_start - anticipation_end));
+            low + (high - low) * s
+        } else {
+            high + (1.0 - high) * smoothstep((t - settle_start) / (1.0 - settle_start))
+        }
+    }
+}
+
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+impl<T: Mix + Clone, X: Time> Keyframes<T, X> for AnticipateOvershootKeyframes<T, X> {
+    fn get(&self, offset: X::Duration) -> T {
+        if offset <= Default::default() {
+            self.start.clone()
+        } else if offset >= self.duration {
+            self.end.clone()
+        } else {
+            let t = offset.as_f32() / self.duration.as_f32();
+            self.start.mix_ref(&self.end, self.progress(t))
+        }
+    }
+
+    fn duration(&self) -> X::Duration {
+        self.duration
+    }
+
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::AnticipateOvershoot, Some(self.duration))
+    }
+}
+
+impl<T: Mix + Clone + Debug, X: Time> Debug for AnticipateOvershootKeyframes<T, X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnticipateOvershootKeyframes")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("duration", &self.duration)
+            .field("anticipation", &self.anticipation)
+            .field("overshoot", &self.overshoot)
+            .finish()
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Clone for AnticipateOvershootKeyframes<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            duration: self.duration,
+            anticipation: self.anticipation,
+            overshoot: self.overshoot,
+        }
+    }
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> PartialEq for AnticipateOvershootKeyframes<T, X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.end == other.end
+            && self.duration == other.duration
+            && self.anticipation == other.anticipation
+            && self.overshoot == other.overshoot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    #[test]
+    fn pull_back_and_overshoot() {
+        let motion = keyframes::anticipate_overshoot::<f32, f32>(0.0, 10.0, 4.0, 0.1, 0.2);
+        assert_eq!(motion.duration(), 4.0);
+        assert_eq!(motion.get(0.0), 0.0);
+        assert_eq!(motion.get(1.0), -1.0, "Pulled back by the anticipation");
+        assert_eq!(motion.get(3.0), 12.0, "Shot past by the overshoot");
+        assert_eq!(motion.get(4.0), 10.0);
+        assert!(motion.get(0.5) < 0.0 && motion.get(0.5) > -1.0);
+        assert!(motion.get(3.5) > 10.0 && motion.get(3.5) < 12.0);
+    }
+
+    #[test]
+    fn skipped_phases() {
+        let motion = keyframes::anticipate_overshoot::<f32, f32>(0.0, 1.0, 1.0, 0.0, 0.5);
+        assert!(motion.get(0.1) > 0.0, "No pull back");
+        assert_eq!(motion.get(0.75), 1.5);
+
+        let plain = keyframes::anticipate_overshoot::<f32, f32>(0.0, 1.0, 1.0, 0.0, 0.0);
+        assert_eq!(plain.get(0.5), 0.5);
+        assert!(plain.get(0.9) <= 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Anticipation and overshoot must be finite and non-negative")]
+    fn negative_overshoot() {
+        keyframes::anticipate_overshoot::<f32, f32>(0.0, 1.0, 1.0, 0.1, -0.1);
+    }
+}
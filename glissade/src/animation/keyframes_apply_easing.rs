@@ -1,6 +1,7 @@
-use crate::{Easing, Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::{Easing, Keyframes, Time, TimeDiff};
+use core::fmt::Debug;
+use core::marker::PhantomData;
 
 /// Apply easing to keyframes.
 pub struct ApplyEasingKeyframes<T, X: Time, K: Keyframes<T, X>> {
@@ -22,10 +23,14 @@ impl<T, X: Time, K: Keyframes<T, X>> ApplyEasingKeyframes<T, X, K> {
 
 impl<T, X: Time, K: Keyframes<T, X>> Keyframes<T, X> for ApplyEasingKeyframes<T, X, K> {
     fn get(&self, offset: X::Duration) -> T {
-        let t = X::duration_as_f32(offset) / X::duration_as_f32(self.keyframes.duration());
+        let duration = self.keyframes.duration();
+        if duration <= Default::default() {
+            return self.keyframes.get(offset);
+        }
+        let t = offset.as_f32() / duration.as_f32();
         let t = self.easing.ease(t).clamp(0.0, 1.0);
-        let offset = X::duration_scale(self.keyframes.duration(), t);
-        self.keyframes.get(offset)
+        self.keyframes
+            .get(duration.checked_scale(t).unwrap_or(duration))
     }
 
     fn duration(&self) -> X::Duration {
@@ -35,6 +40,12 @@ impl<T, X: Time, K: Keyframes<T, X>> Keyframes<T, X> for ApplyEasingKeyframes<T,
     fn is_finite(&self) -> bool {
         self.keyframes.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::ApplyEasing, self.try_duration())
+            .with_easing(self.easing.clone())
+            .with_children([self.keyframes.describe()])
+    }
 }
 
 impl<T, X: Time, K: Keyframes<T, X> + Clone> Clone for ApplyEasingKeyframes<T, X, K> {
@@ -48,7 +59,7 @@ impl<T, X: Time, K: Keyframes<T, X> + Clone> Clone for ApplyEasingKeyframes<T, X
 }
 
 impl<T, X: Time, K: Keyframes<T, X> + Debug> Debug for ApplyEasingKeyframes<T, X, K> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ApplyEasingKeyframes")
             .field("keyframes", &self.keyframes)
             .field("easing", &self.easing)
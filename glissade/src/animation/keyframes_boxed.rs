@@ -0,0 +1,71 @@
+use super::keyframes_description::KeyframesDescription;
+use crate::{Keyframes, Time};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Keyframes with the type erased, for keyframes built at runtime,
+/// or to store keyframes of different types in a single collection.
+pub type BoxedKeyframes<T, X> = Box<dyn Keyframes<T, X>>;
+
+impl<T, X: Time, K: Keyframes<T, X> + ?Sized> Keyframes<T, X> for Box<K> {
+    fn get(&self, offset: X::Duration) -> T {
+        self.as_ref().get(offset)
+    }
+
+    fn get_many(&self, offsets: &[X::Duration]) -> Vec<T> {
+        self.as_ref().get_many(offsets)
+    }
+
+    fn duration(&self) -> X::Duration {
+        self.as_ref().duration()
+    }
+
+    fn joins(&self) -> Vec<X::Duration> {
+        self.as_ref().joins()
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        self.as_ref().period()
+    }
+
+    fn is_finished(&self, offset: X::Duration) -> bool {
+        self.as_ref().is_finished(offset)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.as_ref().is_finite()
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        self.as_ref().describe()
+    }
+
+    fn start_value(&self) -> T {
+        self.as_ref().start_value()
+    }
+
+    fn end_value(&self) -> T {
+        self.as_ref().end_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{keyframes, BoxedKeyframes, Keyframes};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn boxed_keyframes() {
+        let list: Vec<BoxedKeyframes<f32, f32>> = vec![
+            keyframes::line(0.0, 1.0, 1.0).boxed(),
+            keyframes::from(1.0).go_to(3.0, 2.0).boxed(),
+        ];
+
+        let keyframes = list.into_iter().reduce(|a, b| a.then(b).boxed()).unwrap();
+        assert_eq!(keyframes.duration(), 3.0);
+        assert_eq!(keyframes.get(0.5), 0.5);
+        assert_eq!(keyframes.get(2.0), 2.0);
+        assert_eq!(keyframes.end_value(), 3.0);
+    }
+}
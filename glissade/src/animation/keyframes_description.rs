@@ -0,0 +1,149 @@
+use crate::{Easing, Waveform};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+
+/// The kind of a node in `KeyframesDescription`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KeyframesKind {
+    Stay,
+    Linear,
+    Easing,
+    Poly,
+    Function,
+    AnticipateOvershoot,
+    Oscillator(Waveform),
+    Orbit,
+    Shake,
+    Typewriter,
+    /// `FlatKeyframes`, children are its segments.
+    Flat,
+    Baked,
+    /// Keyframes running one after another, nested sequences are flattened.
+    Sequence,
+    Repeat,
+    RepeatN(f32),
+    Reverse,
+    Slice,
+    Scale(f32),
+    ApplyEasing,
+    Map,
+    FiniteOr,
+    /// Keyframes running side by side, see `GroupKeyframes`.
+    Group,
+    /// A tuple of keyframes running side by side.
+    Tuple,
+    /// Keyframes that don't describe themselves, e.g. user-defined ones.
+    Custom,
+}
+
+/// The structure of keyframes, e.g. for debug overlays or editors. See `Keyframes::describe`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyframesDescription<D> {
+    pub kind: KeyframesKind,
+    /// `None` if the keyframes are infinite.
+    pub duration: Option<D>,
+    pub easing: Option<Easing>,
+    /// Descriptions of the wrapped keyframes.
+    pub children: Vec<KeyframesDescription<D>>,
+}
+
+impl<D> KeyframesDescription<D> {
+    pub fn new(kind: KeyframesKind, duration: Option<D>) -> Self {
+        Self {
+            kind,
+            duration,
+            easing: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = Self>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    fn fmt_indented(&self, f: &mut Formatter<'_>, depth: usize) -> core::fmt::Result
+    where
+        D: Debug,
+    {
+        write!(f, "{:indent$}{:?}", "", self.kind, indent = depth * 2)?;
+        match &self.duration {
+            Some(duration) => write!(f, " {:?}", duration)?,
+            None => write!(f, " infinite")?,
+        }
+        if let Some(easing) = &self.easing {
+            write!(f, " {:?}", easing)?;
+        }
+        writeln!(f)?;
+        for child in &self.children {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// An indented tree, a line per node with its kind, duration, and easing.
+impl<D: Debug> Display for KeyframesDescription<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+    use alloc::string::ToString;
+
+    #[test]
+    fn describe_chain() {
+        let description = keyframes::from::<f32, f32>(0.0)
+            .go_to(1.0, 1.0)
+            .ease_to(0.0, 2.0, Easing::QuadraticIn)
+            .stay(1.0)
+            .scale(2.0)
+            .describe();
+        assert_eq!(description.kind, KeyframesKind::Scale(2.0));
+        assert_eq!(description.duration, Some(8.0));
+
+        let sequence = &description.children[0];
+        let kinds: Vec<_> = sequence.children.iter().map(|c| c.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                KeyframesKind::Stay,
+                KeyframesKind::Linear,
+                KeyframesKind::Easing,
+                KeyframesKind::Stay
+            ],
+            "Nested sequences are flattened"
+        );
+        assert_eq!(sequence.children[2].easing, Some(Easing::QuadraticIn));
+        assert_eq!(
+            description.to_string(),
+            "Scale(2.0) 8.0\n  Sequence 4.0\n    Stay 0.0\n    Linear 1.0\n    Easing 2.0 QuadraticIn\n    Stay 1.0\n"
+        );
+    }
+
+    #[test]
+    fn describe_infinite_and_boxed() {
+        let description = keyframes::line::<f32, f32>(0.0, 1.0, 1.0)
+            .repeat()
+            .boxed()
+            .describe();
+        assert_eq!(description.kind, KeyframesKind::Repeat);
+        assert_eq!(description.duration, None);
+        assert_eq!(description.children[0].kind, KeyframesKind::Linear);
+
+        let flat: crate::FlatKeyframes<f32, f32> = keyframes::line(0.0, 1.0, 1.0).stay(1.0).into();
+        let description = flat.describe();
+        assert_eq!(description.kind, KeyframesKind::Flat);
+        assert_eq!(description.children.len(), 2);
+        assert_eq!(description.children[1].kind, KeyframesKind::Stay);
+    }
+}
@@ -1,20 +1,21 @@
-use crate::{Easing, Keyframes, Mix, Time};
-use std::fmt::Debug;
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::{Easing, Keyframes, Mix, Time, TimeDiff};
+use core::fmt::Debug;
 
 /// An animation that eases between two values.
 #[derive(Clone)]
 pub struct EasingKeyframes<T: Mix + Clone, X: Time> {
-    v1: T,
-    v2: T,
-    duration: X::Duration,
-    easing: Easing,
+    pub(crate) v1: T,
+    pub(crate) v2: T,
+    pub(crate) duration: X::Duration,
+    pub(crate) easing: Easing,
 }
 
 impl<T: Mix + Clone + Debug, X: Time> Debug for EasingKeyframes<T, X>
 where
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("EasingKeyframes")
             .field("v1", &self.v1)
             .field("v2", &self.v2)
@@ -51,10 +52,7 @@ impl<T: Mix + Clone, X: Time> Keyframes<T, X> for EasingKeyframes<T, X> {
         } else if offset >= self.duration {
             self.v2.clone()
         } else {
-            let t = self
-                .easing
-                .ease(X::duration_as_f32(offset) / X::duration_as_f32(self.duration));
-            self.v1.clone().mix(self.v2.clone(), t)
+            ease_between(&self.v1, &self.v2, offset, self.duration, &self.easing)
         }
     }
 
@@ -65,4 +63,28 @@ impl<T: Mix + Clone, X: Time> Keyframes<T, X> for EasingKeyframes<T, X> {
     fn is_finite(&self) -> bool {
         true
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Easing, Some(self.duration))
+            .with_easing(self.easing.clone())
+    }
+}
+
+/// Interpolate between two values at the offset inside the duration.
+/// With the `f64` feature, the factor is computed and applied in `f64`.
+pub(crate) fn ease_between<T: Mix + Clone, D: TimeDiff>(
+    v1: &T,
+    v2: &T,
+    offset: D,
+    duration: D,
+    easing: &Easing,
+) -> T {
+    #[cfg(feature = "f64")]
+    {
+        v1.mix_ref_f64(v2, easing.ease_f64(offset.as_f64() / duration.as_f64()))
+    }
+    #[cfg(not(feature = "f64"))]
+    {
+        v1.mix_ref(v2, easing.ease(offset.as_f32() / duration.as_f32()))
+    }
 }
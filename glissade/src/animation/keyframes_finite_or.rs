@@ -0,0 +1,119 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::{Finite, Keyframes, Time};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// Keyframes that replace non-finite values (NaN or infinite) with a default value.
+pub struct FiniteOrKeyframes<T, X: Time, K: Keyframes<T, X>> {
+    keyframes: K,
+    default: T,
+    phantom: PhantomData<X>,
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> FiniteOrKeyframes<T, X, K> {
+    pub fn new(keyframes: K, default: T) -> Self {
+        Self {
+            keyframes,
+            default,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: Finite + Clone, X: Time, K: Keyframes<T, X>> FiniteOrKeyframes<T, X, K> {
+    fn check(&self, value: T) -> T {
+        if value.is_finite() {
+            value
+        } else {
+            self.default.clone()
+        }
+    }
+}
+
+impl<T: Finite + Clone, X: Time, K: Keyframes<T, X>> Keyframes<T, X>
+    for FiniteOrKeyframes<T, X, K>
+{
+    fn get(&self, offset: X::Duration) -> T {
+        self.check(self.keyframes.get(offset))
+    }
+
+    fn get_many(&self, offsets: &[X::Duration]) -> Vec<T> {
+        let values = self.keyframes.get_many(offsets);
+        values.into_iter().map(|value| self.check(value)).collect()
+    }
+
+    fn duration(&self) -> X::Duration {
+        self.keyframes.duration()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.keyframes.is_finite()
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::FiniteOr, self.try_duration())
+            .with_children([self.keyframes.describe()])
+    }
+
+    fn joins(&self) -> Vec<X::Duration> {
+        self.keyframes.joins()
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        self.keyframes.period()
+    }
+}
+
+impl<T: Debug, X: Time, K: Keyframes<T, X> + Debug> Debug for FiniteOrKeyframes<T, X, K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FiniteOrKeyframes")
+            .field("keyframes", &self.keyframes)
+            .field("default", &self.default)
+            .finish()
+    }
+}
+
+impl<T: Clone, X: Time, K: Keyframes<T, X> + Clone> Clone for FiniteOrKeyframes<T, X, K> {
+    fn clone(&self) -> Self {
+        Self {
+            keyframes: self.keyframes.clone(),
+            default: self.default.clone(),
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: Copy, X: Time, K: Keyframes<T, X> + Copy> Copy for FiniteOrKeyframes<T, X, K> {}
+
+impl<T: PartialEq, X: Time, K: Keyframes<T, X> + PartialEq> PartialEq
+    for FiniteOrKeyframes<T, X, K>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.keyframes == other.keyframes && self.default == other.default
+    }
+}
+
+impl<T: Eq, X: Time, K: Keyframes<T, X> + Eq> Eq for FiniteOrKeyframes<T, X, K> {}
+
+impl<T: Hash, X: Time, K: Keyframes<T, X> + Hash> Hash for FiniteOrKeyframes<T, X, K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.keyframes.hash(state);
+        self.default.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{keyframes, Keyframes};
+
+    #[test]
+    fn replace_non_finite() {
+        let keyframes =
+            keyframes::function::<f32, f32, _>(|offset| 1.0 / (offset - 1.0), 2.0).finite_or(0.0);
+        assert_eq!(keyframes.get(0.0), -1.0);
+        assert_eq!(keyframes.get(1.0), 0.0);
+        assert_eq!(keyframes.get_many(&[1.0, 2.0]), vec![0.0, 1.0]);
+    }
+}
@@ -0,0 +1,454 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use super::keyframes_easing::{ease_between, EasingKeyframes};
+use super::keyframes_function::FunctionKeyframes;
+use super::keyframes_linear::LinearKeyframes;
+use super::keyframes_poly::PolyKeyframes;
+use super::keyframes_sequential::SequentialKeyframes;
+use super::keyframes_stay::StayKeyframes;
+use crate::poly::Poly;
+use crate::{Distance, Easing, Keyframes, Mix, Time, TimeDiff};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+
+/// A single segment of `FlatKeyframes`.
+#[derive(Clone)]
+enum Segment<T, X: Time> {
+    Stay {
+        value: T,
+        duration: X::Duration,
+    },
+    Linear {
+        from: T,
+        to: T,
+        duration: X::Duration,
+    },
+    Ease {
+        from: T,
+        to: T,
+        duration: X::Duration,
+        easing: Easing,
+    },
+    Poly {
+        poly: Poly<T>,
+        duration: X::Duration,
+        easing: Easing,
+    },
+    Function {
+        function: Arc<dyn Fn(X::Duration) -> T>,
+        duration: X::Duration,
+    },
+}
+
+/// Function segments are equal only if they share the same function.
+impl<T: PartialEq, X: Time> PartialEq for Segment<T, X> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Segment::Stay { value, duration },
+                Segment::Stay {
+                    value: other_value,
+                    duration: other_duration,
+                },
+            ) => value == other_value && duration == other_duration,
+            (
+                Segment::Linear { from, to, duration },
+                Segment::Linear {
+                    from: other_from,
+                    to: other_to,
+                    duration: other_duration,
+                },
+            ) => from == other_from && to == other_to && duration == other_duration,
+            (
+                Segment::Ease {
+                    from,
+                    to,
+                    duration,
+                    easing,
+                },
+                Segment::Ease {
+                    from: other_from,
+                    to: other_to,
+                    duration: other_duration,
+                    easing: other_easing,
+                },
+            ) => {
+                from == other_from
+                    && to == other_to
+                    && duration == other_duration
+                    && easing == other_easing
+            }
+            (
+                Segment::Poly {
+                    poly,
+                    duration,
+                    easing,
+                },
+                Segment::Poly {
+                    poly: other_poly,
+                    duration: other_duration,
+                    easing: other_easing,
+                },
+            ) => poly == other_poly && duration == other_duration && easing == other_easing,
+            (
+                Segment::Function { function, duration },
+                Segment::Function {
+                    function: other_function,
+                    duration: other_duration,
+                },
+            ) => Arc::ptr_eq(function, other_function) && duration == other_duration,
+            _ => false,
+        }
+    }
+}
+
+impl<T, X: Time> Segment<T, X> {
+    fn duration(&self) -> X::Duration {
+        match self {
+            Segment::Stay { duration, .. }
+            | Segment::Linear { duration, .. }
+            | Segment::Ease { duration, .. }
+            | Segment::Poly { duration, .. }
+            | Segment::Function { duration, .. } => *duration,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Segment::Stay { .. } => "Stay",
+            Segment::Linear { .. } => "Linear",
+            Segment::Ease { .. } => "Ease",
+            Segment::Poly { .. } => "Poly",
+            Segment::Function { .. } => "Function",
+        }
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        let duration = Some(self.duration());
+        match self {
+            Segment::Stay { .. } => KeyframesDescription::new(KeyframesKind::Stay, duration),
+            Segment::Linear { .. } => KeyframesDescription::new(KeyframesKind::Linear, duration),
+            Segment::Ease { easing, .. } => {
+                KeyframesDescription::new(KeyframesKind::Easing, duration)
+                    .with_easing(easing.clone())
+            }
+            Segment::Poly { easing, .. } => {
+                KeyframesDescription::new(KeyframesKind::Poly, duration).with_easing(easing.clone())
+            }
+            Segment::Function { .. } => {
+                KeyframesDescription::new(KeyframesKind::Function, duration)
+            }
+        }
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Segment<T, X> {
+    fn get(&self, offset: X::Duration) -> T {
+        match self {
+            Segment::Stay { value, .. } => value.clone(),
+            Segment::Linear { from, to, duration } => {
+                Self::mix(from, to, offset, *duration, &Easing::Linear)
+            }
+            Segment::Ease {
+                from,
+                to,
+                duration,
+                easing,
+            } => Self::mix(from, to, offset, *duration, easing),
+            Segment::Poly {
+                poly,
+                duration,
+                easing,
+            } => {
+                let t = if *duration > Default::default() {
+                    offset.as_f32() / duration.as_f32()
+                } else {
+                    1.0
+                };
+                poly.value_at(easing.ease(t))
+            }
+            Segment::Function { function, .. } => function(offset),
+        }
+    }
+
+    fn mix(from: &T, to: &T, offset: X::Duration, duration: X::Duration, easing: &Easing) -> T {
+        if offset < Default::default() {
+            from.clone()
+        } else if offset >= duration {
+            to.clone()
+        } else {
+            ease_between(from, to, offset, duration, easing)
+        }
+    }
+}
+
+/// Keyframes stored as a flat list of segments, instead of nested generic types.
+/// Chains of `go_to`, `ease_to`, `poly_to`, `stay`, `function` and `then` are converted with `into()`,
+/// so crates that build many different animation shapes get a single type for all of them,
+/// and less code to compile.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Easing, FlatKeyframes, Keyframes};
+///
+/// let flat: FlatKeyframes<f32, f32> = keyframes::from(0.0)
+///     .go_to(1.0, 1.0)
+///     .stay(1.0)
+///     .ease_to(0.0, 2.0, Easing::Linear)
+///     .into();
+///
+/// assert_eq!(flat.len(), 4);
+/// assert_eq!(flat.duration(), 4.0);
+/// assert_eq!(flat.get(0.5), 0.5);
+/// assert_eq!(flat.get(1.5), 1.0);
+/// assert_eq!(flat.get(3.0), 0.5);
+/// ```
+pub struct FlatKeyframes<T, X: Time> {
+    segments: Vec<Segment<T, X>>,
+    /// Start offset of each segment.
+    offsets: Vec<X::Duration>,
+    duration: X::Duration,
+}
+
+impl<T, X: Time> Debug for FlatKeyframes<T, X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlatKeyframes")
+            .field(
+                "segments",
+                &self.segments.iter().map(Segment::name).collect::<Vec<_>>(),
+            )
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl<T: Clone, X: Time> Clone for FlatKeyframes<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            segments: self.segments.clone(),
+            offsets: self.offsets.clone(),
+            duration: self.duration,
+        }
+    }
+}
+
+impl<T: PartialEq, X: Time> PartialEq for FlatKeyframes<T, X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
+impl<T, X: Time> FlatKeyframes<T, X> {
+    fn segment(segment: Segment<T, X>) -> Self {
+        Self {
+            duration: segment.duration(),
+            segments: vec![segment],
+            offsets: vec![Default::default()],
+        }
+    }
+
+    /// Add keyframes to the end.
+    pub fn append(&mut self, other: impl Into<Self>) {
+        let other = other.into();
+        for (segment, offset) in other.segments.into_iter().zip(other.offsets) {
+            self.segments.push(segment);
+            self.offsets.push(self.duration + offset);
+        }
+        self.duration = self.duration + other.duration;
+    }
+
+    /// Get the number of segments.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// It's always `false`, there is at least one segment.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+impl<T: Mix + Clone, X: Time> FlatKeyframes<T, X> {
+    fn segment_index(&self, offset: X::Duration) -> usize {
+        self.offsets
+            .partition_point(|start| *start <= offset)
+            .saturating_sub(1)
+    }
+
+    fn get_in_segment(&self, index: usize, offset: X::Duration) -> T {
+        let start = self.offsets[index];
+        let offset = if offset >= start {
+            offset - start
+        } else {
+            offset
+        };
+        self.segments[index].get(offset)
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Keyframes<T, X> for FlatKeyframes<T, X> {
+    fn get(&self, offset: X::Duration) -> T {
+        self.get_in_segment(self.segment_index(offset), offset)
+    }
+
+    /// Segments are walked forward while offsets grow, and looked up from scratch otherwise.
+    fn get_many(&self, offsets: &[X::Duration]) -> Vec<T> {
+        let mut index = 0;
+        offsets
+            .iter()
+            .map(|&offset| {
+                match offset.partial_cmp(&self.offsets[index]) {
+                    Some(Ordering::Less) | None => index = self.segment_index(offset),
+                    _ => {
+                        while index + 1 < self.offsets.len() && self.offsets[index + 1] <= offset {
+                            index += 1;
+                        }
+                    }
+                }
+                self.get_in_segment(index, offset)
+            })
+            .collect()
+    }
+
+    fn duration(&self) -> X::Duration {
+        self.duration
+    }
+
+    fn joins(&self) -> Vec<X::Duration> {
+        self.offsets[1..].to_vec()
+    }
+
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Flat, Some(self.duration))
+            .with_children(self.segments.iter().map(Segment::describe))
+    }
+}
+
+impl<T: Clone, X: Time> From<StayKeyframes<T, X>> for FlatKeyframes<T, X> {
+    fn from(keyframes: StayKeyframes<T, X>) -> Self {
+        Self::segment(Segment::Stay {
+            value: keyframes.value,
+            duration: keyframes.duration,
+        })
+    }
+}
+
+impl<T: Mix + Clone, X: Time> From<LinearKeyframes<T, X>> for FlatKeyframes<T, X> {
+    fn from(keyframes: LinearKeyframes<T, X>) -> Self {
+        Self::segment(Segment::Linear {
+            from: keyframes.v1,
+            to: keyframes.v2,
+            duration: keyframes.duration,
+        })
+    }
+}
+
+impl<T: Mix + Clone, X: Time> From<EasingKeyframes<T, X>> for FlatKeyframes<T, X> {
+    fn from(keyframes: EasingKeyframes<T, X>) -> Self {
+        Self::segment(Segment::Ease {
+            from: keyframes.v1,
+            to: keyframes.v2,
+            duration: keyframes.duration,
+            easing: keyframes.easing,
+        })
+    }
+}
+
+impl<T: Mix + Distance + Clone, X: Time> From<PolyKeyframes<T, X>> for FlatKeyframes<T, X> {
+    fn from(keyframes: PolyKeyframes<T, X>) -> Self {
+        Self::segment(Segment::Poly {
+            poly: keyframes.poly,
+            duration: keyframes.duration,
+            easing: keyframes.easing,
+        })
+    }
+}
+
+impl<T, X, F> From<FunctionKeyframes<T, X, F>> for FlatKeyframes<T, X>
+where
+    X: Time,
+    F: Fn(X::Duration) -> T + 'static,
+{
+    fn from(keyframes: FunctionKeyframes<T, X, F>) -> Self {
+        Self::segment(Segment::Function {
+            function: Arc::new(keyframes.function),
+            duration: keyframes.duration,
+        })
+    }
+}
+
+impl<T, X, S1, S2> From<SequentialKeyframes<T, X, S1, S2>> for FlatKeyframes<T, X>
+where
+    X: Time,
+    S1: Keyframes<T, X> + Into<FlatKeyframes<T, X>>,
+    S2: Keyframes<T, X> + Into<FlatKeyframes<T, X>>,
+{
+    fn from(keyframes: SequentialKeyframes<T, X, S1, S2>) -> Self {
+        let mut result = keyframes.t1.into();
+        result.append(keyframes.t2);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    #[test]
+    fn same_as_chain() {
+        let chain = keyframes::from::<f32, f32>(0.0)
+            .go_to(4.0, 1.0)
+            .ease_to(0.0, 2.0, Easing::QuadraticIn)
+            .stay(0.5)
+            .poly_to(vec![2.0, 4.0], 1.0, Easing::Linear)
+            .function(|offset| 4.0 - offset, 1.0)
+            .then(keyframes::line(3.0, 1.0, 1.0));
+        let flat: FlatKeyframes<f32, f32> = chain.clone().into();
+
+        assert_eq!(flat.len(), 7);
+        assert_eq!(flat.duration(), chain.duration());
+        for i in -2..=30 {
+            let offset = i as f32 * 0.25;
+            assert_eq!(flat.get(offset), chain.get(offset), "offset {}", offset);
+        }
+    }
+
+    #[test]
+    fn append() {
+        let mut flat: FlatKeyframes<f32, f32> = keyframes::line(0.0, 1.0, 1.0).into();
+        flat.append(keyframes::line(1.0, 3.0, 1.0).then(keyframes::stay(3.0, 1.0)));
+
+        assert_eq!(flat.len(), 3);
+        assert_eq!(flat.duration(), 3.0);
+        assert_eq!(flat.get(1.5), 2.0);
+        assert_eq!(flat.get(2.5), 3.0);
+        assert_eq!(flat.end_value(), 3.0);
+    }
+
+    #[test]
+    fn get_many() {
+        let flat: FlatKeyframes<f32, f32> = keyframes::from(0.0)
+            .go_to(1.0, 1.0)
+            .stay(1.0)
+            .go_to(0.0, 1.0)
+            .into();
+
+        let sorted = [-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0];
+        let unsorted = [2.5, 0.5, 3.0, 1.0, -1.0, 1.5];
+        for offsets in [&sorted[..], &unsorted[..]] {
+            let expected: Vec<f32> = offsets.iter().map(|offset| flat.get(*offset)).collect();
+            assert_eq!(flat.get_many(offsets), expected);
+        }
+    }
+}
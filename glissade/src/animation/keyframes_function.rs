@@ -1,9 +1,11 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use crate::Time;
-use std::fmt::Debug;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
 
 pub struct FunctionKeyframes<T, X: Time, F: Fn(X::Duration) -> T> {
-    function: F,
-    duration: X::Duration,
+    pub(crate) function: F,
+    pub(crate) duration: X::Duration,
 }
 
 impl<T, X: Time, F: Fn(X::Duration) -> T> FunctionKeyframes<T, X, F> {
@@ -24,6 +26,10 @@ impl<T, X: Time, F: Fn(X::Duration) -> T> crate::Keyframes<T, X> for FunctionKey
     fn is_finite(&self) -> bool {
         true
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Function, Some(self.duration))
+    }
 }
 
 impl<T, X: Time, F: Clone + Fn(X::Duration) -> T> Clone for FunctionKeyframes<T, X, F> {
@@ -37,13 +43,35 @@ impl<T, X: Time, F: Clone + Fn(X::Duration) -> T> Clone for FunctionKeyframes<T,
 
 impl<T, X: Time, F: Copy + Fn(X::Duration) -> T> Copy for FunctionKeyframes<T, X, F> {}
 
+/// Closures can't be compared, it's implemented for function pointers.
+impl<T, X: Time, F: PartialEq + Fn(X::Duration) -> T> PartialEq for FunctionKeyframes<T, X, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.function == other.function && self.duration == other.duration
+    }
+}
+
+impl<T, X: Time, F: Eq + Fn(X::Duration) -> T> Eq for FunctionKeyframes<T, X, F> where
+    X::Duration: Eq
+{
+}
+
+impl<T, X: Time, F: Hash + Fn(X::Duration) -> T> Hash for FunctionKeyframes<T, X, F>
+where
+    X::Duration: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.function.hash(state);
+        self.duration.hash(state);
+    }
+}
+
 impl<T, X, F> Debug for FunctionKeyframes<T, X, F>
 where
     X: Time,
     X::Duration: Debug,
     F: Debug + Fn(X::Duration) -> T,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("KeyframesFunction")
             .field("duration", &self.duration)
             .finish()
@@ -0,0 +1,222 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::{Keyframes, Time};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// When a group of keyframes or animations is finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Barrier {
+    /// The group is finished when all items are finished.
+    All,
+    /// The group is finished when the first item is finished.
+    Race,
+}
+
+/// Keyframes running side by side, the value is a vector of item values.
+/// Each item can be delayed, see `keyframes::all`, `keyframes::race`, and `keyframes::staggered`.
+pub struct GroupKeyframes<T, X: Time, K: Keyframes<T, X>> {
+    /// Items with their delays from the start of the group.
+    items: Vec<(X::Duration, K)>,
+    barrier: Barrier,
+    phantom: PhantomData<T>,
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> GroupKeyframes<T, X, K> {
+    /// The group ends when all items end, it's infinite if any item is infinite.
+    pub fn all(items: impl IntoIterator<Item = K>) -> Self {
+        Self::new(Self::undelayed(items), Barrier::All)
+    }
+
+    /// The group ends when the first item ends, other items snap to their end values.
+    /// It's infinite only if all items are infinite.
+    pub fn race(items: impl IntoIterator<Item = K>) -> Self {
+        Self::new(Self::undelayed(items), Barrier::Race)
+    }
+
+    /// Same as `all`, but each next item starts `delay` later than the previous one.
+    pub fn staggered(items: impl IntoIterator<Item = K>, delay: X::Duration) -> Self {
+        let mut offset = X::Duration::default();
+        let items = items
+            .into_iter()
+            .map(|item| {
+                let item = (offset, item);
+                offset = offset + delay;
+                item
+            })
+            .collect();
+        Self::new(items, Barrier::All)
+    }
+
+    fn undelayed(items: impl IntoIterator<Item = K>) -> Vec<(X::Duration, K)> {
+        items
+            .into_iter()
+            .map(|item| (Default::default(), item))
+            .collect()
+    }
+
+    fn new(items: Vec<(X::Duration, K)>, barrier: Barrier) -> Self {
+        Self {
+            items,
+            barrier,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Get the number of items in the group.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check if the group has no items, it's finished from the start.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// End offsets of finite items.
+    fn ends(&self) -> impl Iterator<Item = X::Duration> + '_ {
+        self.items
+            .iter()
+            .filter_map(|(delay, item)| item.try_duration().map(|duration| *delay + duration))
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> Keyframes<Vec<T>, X> for GroupKeyframes<T, X, K> {
+    fn get(&self, offset: X::Duration) -> Vec<T> {
+        let end = match self.barrier {
+            Barrier::Race => self.try_duration(),
+            Barrier::All => None,
+        };
+        match end {
+            Some(end) if offset >= end => self
+                .items
+                .iter()
+                .map(|(delay, item)| {
+                    if item.is_finite() {
+                        item.end_value()
+                    } else if end > *delay {
+                        item.get(end - *delay)
+                    } else {
+                        item.start_value()
+                    }
+                })
+                .collect(),
+            _ => self
+                .items
+                .iter()
+                .map(|(delay, item)| {
+                    if offset > *delay {
+                        item.get(offset - *delay)
+                    } else {
+                        item.start_value()
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    fn duration(&self) -> X::Duration {
+        let ends = self.ends();
+        let end = match self.barrier {
+            Barrier::All => ends.reduce(|a, b| if b > a { b } else { a }),
+            Barrier::Race => ends.reduce(|a, b| if b < a { b } else { a }),
+        };
+        end.unwrap_or_default()
+    }
+
+    fn is_finite(&self) -> bool {
+        match self.barrier {
+            Barrier::All => self.items.iter().all(|(_, item)| item.is_finite()),
+            Barrier::Race => {
+                self.items.is_empty() || self.items.iter().any(|(_, item)| item.is_finite())
+            }
+        }
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Group, self.try_duration())
+            .with_children(self.items.iter().map(|(_, item)| item.describe()))
+    }
+}
+
+impl<T, X, K> Debug for GroupKeyframes<T, X, K>
+where
+    X: Time,
+    X::Duration: Debug,
+    K: Keyframes<T, X> + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GroupKeyframes")
+            .field("items", &self.items)
+            .field("barrier", &self.barrier)
+            .finish()
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Clone> Clone for GroupKeyframes<T, X, K> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            barrier: self.barrier,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + PartialEq> PartialEq for GroupKeyframes<T, X, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.barrier == other.barrier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, BoxedKeyframes, Keyframes};
+
+    #[test]
+    fn all_keyframes() {
+        let group = keyframes::all([
+            keyframes::line::<f32, f32>(0.0, 1.0, 1.0),
+            keyframes::line(0.0, 4.0, 2.0),
+        ]);
+        assert_eq!(group.duration(), 2.0);
+        assert_eq!(group.get(0.5), vec![0.5, 1.0]);
+        assert_eq!(group.get(1.5), vec![1.0, 3.0]);
+        assert!(!group.is_finished(1.5));
+        assert!(group.is_finished(2.0));
+    }
+
+    #[test]
+    fn race_keyframes() {
+        let items: Vec<BoxedKeyframes<f32, f32>> = vec![
+            keyframes::line(0.0, 1.0, 1.0).boxed(),
+            keyframes::line(0.0, 4.0, 2.0).boxed(),
+            keyframes::line(0.0, 1.0, 1.0).repeat().boxed(),
+        ];
+        let group = keyframes::race(items);
+        assert_eq!(group.duration(), 1.0);
+        assert_eq!(group.get(0.5), vec![0.5, 1.0, 0.5]);
+        assert_eq!(group.get(1.0), vec![1.0, 4.0, 1.0], "Others snap");
+        assert!(group.is_finished(1.0));
+
+        let endless = keyframes::race([keyframes::line::<f32, f32>(0.0, 1.0, 1.0).repeat()]);
+        assert!(!endless.is_finite());
+    }
+
+    #[test]
+    fn staggered_keyframes() {
+        let group = keyframes::staggered(
+            (0..3).map(|_| keyframes::line::<f32, f32>(0.0, 1.0, 1.0)),
+            0.5,
+        );
+        assert_eq!(group.len(), 3);
+        assert_eq!(group.duration(), 2.0);
+        assert_eq!(group.get(0.0), vec![0.0, 0.0, 0.0]);
+        assert_eq!(group.get(0.75), vec![0.75, 0.25, 0.0]);
+        assert_eq!(group.get(2.0), vec![1.0, 1.0, 1.0]);
+
+        let animation = group.run(1.0);
+        assert_eq!(crate::Animated::get(&animation, 2.0), vec![1.0, 0.5, 0.0]);
+    }
+}
@@ -1,19 +1,22 @@
-use crate::{Keyframes, Mix, Time};
-use std::fmt::Debug;
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use super::keyframes_easing::ease_between;
+use crate::{Easing, Keyframes, Mix, Time};
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
 
 /// An animation that linearly interpolates between two values.
 #[derive(Clone)]
 pub struct LinearKeyframes<T: Mix + Clone, X: Time> {
-    v1: T,
-    v2: T,
-    duration: X::Duration,
+    pub(crate) v1: T,
+    pub(crate) v2: T,
+    pub(crate) duration: X::Duration,
 }
 
 impl<T: Mix + Clone + Debug, X: Time> Debug for LinearKeyframes<T, X>
 where
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("LinearKeyframes")
             .field("v1", &self.v1)
             .field("v2", &self.v2)
@@ -28,6 +31,19 @@ impl<T: Mix + Clone + PartialEq, X: Time> PartialEq for LinearKeyframes<T, X> {
     }
 }
 
+impl<T: Mix + Clone + Eq, X: Time> Eq for LinearKeyframes<T, X> where X::Duration: Eq {}
+
+impl<T: Mix + Clone + Hash, X: Time> Hash for LinearKeyframes<T, X>
+where
+    X::Duration: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.v1.hash(state);
+        self.v2.hash(state);
+        self.duration.hash(state);
+    }
+}
+
 impl<T: Mix + Clone, X: Time> LinearKeyframes<T, X> {
     pub fn new(v1: T, v2: T, duration: X::Duration) -> Self {
         Self { v1, v2, duration }
@@ -41,8 +57,7 @@ impl<T: Mix + Clone, X: Time> Keyframes<T, X> for LinearKeyframes<T, X> {
         } else if offset >= self.duration {
             self.v2.clone()
         } else {
-            let t = X::duration_as_f32(offset) / X::duration_as_f32(self.duration);
-            self.v1.clone().mix(self.v2.clone(), t)
+            ease_between(&self.v1, &self.v2, offset, self.duration, &Easing::Linear)
         }
     }
 
@@ -53,6 +68,10 @@ impl<T: Mix + Clone, X: Time> Keyframes<T, X> for LinearKeyframes<T, X> {
     fn is_finite(&self) -> bool {
         true
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Linear, Some(self.duration))
+    }
 }
 
 impl<T: Mix + Clone + Copy, X: Time> Copy for LinearKeyframes<T, X> {}
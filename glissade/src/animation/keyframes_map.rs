@@ -1,6 +1,11 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use crate::{Keyframes, Time};
-use std::marker::PhantomData;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
+/// Keyframes with values converted by a function.
 pub struct MapKeyframes<T, R, X, K, F>
 where
     X: Time,
@@ -37,11 +42,97 @@ where
         (self.map)(self.keyframes.get(offset))
     }
 
+    fn get_many(&self, offsets: &[X::Duration]) -> Vec<R> {
+        let values = self.keyframes.get_many(offsets);
+        values.into_iter().map(&self.map).collect()
+    }
+
     fn duration(&self) -> X::Duration {
         self.keyframes.duration()
     }
 
+    fn joins(&self) -> Vec<X::Duration> {
+        self.keyframes.joins()
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        self.keyframes.period()
+    }
+
     fn is_finite(&self) -> bool {
         self.keyframes.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Map, self.try_duration())
+            .with_children([self.keyframes.describe()])
+    }
+}
+
+impl<T, R, X, K, F> Debug for MapKeyframes<T, R, X, K, F>
+where
+    X: Time,
+    K: Keyframes<T, X> + Debug,
+    F: Fn(T) -> R,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MapKeyframes")
+            .field("keyframes", &self.keyframes)
+            .finish()
+    }
+}
+
+impl<T, R, X, K, F> Clone for MapKeyframes<T, R, X, K, F>
+where
+    X: Time,
+    K: Keyframes<T, X> + Clone,
+    F: Fn(T) -> R + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            keyframes: self.keyframes.clone(),
+            map: self.map.clone(),
+            phantom_data: Default::default(),
+        }
+    }
+}
+
+impl<T, R, X, K, F> Copy for MapKeyframes<T, R, X, K, F>
+where
+    X: Time,
+    K: Keyframes<T, X> + Copy,
+    F: Fn(T) -> R + Copy,
+{
+}
+
+/// Closures can't be compared, it's implemented for function pointers.
+impl<T, R, X, K, F> PartialEq for MapKeyframes<T, R, X, K, F>
+where
+    X: Time,
+    K: Keyframes<T, X> + PartialEq,
+    F: Fn(T) -> R + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.keyframes == other.keyframes && self.map == other.map
+    }
+}
+
+impl<T, R, X, K, F> Eq for MapKeyframes<T, R, X, K, F>
+where
+    X: Time,
+    K: Keyframes<T, X> + Eq,
+    F: Fn(T) -> R + Eq,
+{
+}
+
+impl<T, R, X, K, F> Hash for MapKeyframes<T, R, X, K, F>
+where
+    X: Time,
+    K: Keyframes<T, X> + Hash,
+    F: Fn(T) -> R + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.keyframes.hash(state);
+        self.map.hash(state);
+    }
 }
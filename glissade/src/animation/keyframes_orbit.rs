@@ -0,0 +1,155 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::math::{cos, floor_f64, sin};
+use crate::{Keyframes, Mix, Time, TimeDiff};
+use core::f32::consts::PI;
+use core::fmt::Debug;
+
+/// Infinite motion along an ellipse, see `keyframes::orbit` and `keyframes::ellipse`.
+/// Points are computed with `Mix` only, so it works for any point type, including 3D ones.
+pub struct OrbitKeyframes<T: Mix + Clone, X: Time> {
+    center: T,
+    x_axis: T,
+    y_axis: T,
+    period: X::Duration,
+}
+
+impl<T: Mix + Clone, X: Time> OrbitKeyframes<T, X> {
+    /// Go around `center` through `x_axis` and `y_axis`, the ends of the ellipse axes,
+    /// one revolution per `period`. The motion starts at `x_axis`, and reaches `y_axis` in a quarter of the period.
+    pub fn new(center: T, x_axis: T, y_axis: T, period: X::Duration) -> Self {
+        assert!(period > Default::default(), "Orbit period must be positive");
+        Self {
+            center,
+            x_axis,
+            y_axis,
+            period,
+        }
+    }
+
+    /// Get `center + cos(angle) * (x_axis - center) + sin(angle) * (y_axis - center)`.
+    fn point_at(&self, angle: f32) -> T {
+        let along_x = self.center.mix_ref(&self.x_axis, cos(angle));
+        let along_y = self.center.mix_ref(&self.y_axis, sin(angle));
+        self.center.mix_ref(&along_x.mix(along_y, 0.5), 2.0)
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Keyframes<T, X> for OrbitKeyframes<T, X> {
+    fn get(&self, offset: X::Duration) -> T {
+        let cycles = offset.as_f64() / self.period.as_f64();
+        let cycle = (cycles - floor_f64(cycles)) as f32;
+        self.point_at(2.0 * PI * cycle)
+    }
+
+    fn duration(&self) -> X::Duration {
+        panic!("OrbitKeyframes has infinite duration");
+    }
+
+    fn is_finite(&self) -> bool {
+        false
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Orbit, None)
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        Some(self.period)
+    }
+
+    fn end_value(&self) -> T {
+        panic!("OrbitKeyframes has no end value");
+    }
+}
+
+impl<T: Mix + Clone + Debug, X: Time> Debug for OrbitKeyframes<T, X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OrbitKeyframes")
+            .field("center", &self.center)
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("period", &self.period)
+            .finish()
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Clone for OrbitKeyframes<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            center: self.center.clone(),
+            x_axis: self.x_axis.clone(),
+            y_axis: self.y_axis.clone(),
+            period: self.period,
+        }
+    }
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> PartialEq for OrbitKeyframes<T, X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center == other.center
+            && self.x_axis == other.x_axis
+            && self.y_axis == other.y_axis
+            && self.period == other.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+    use crate::testing::assert_approx_eq;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn circle() {
+        let orbit = keyframes::orbit::<(f32, f32), f32>((10.0, 20.0), 2.0, 4.0);
+        assert_approx_eq(orbit.get(0.0), (12.0, 20.0), 1e-5);
+        assert_approx_eq(orbit.get(1.0), (10.0, 22.0), 1e-5);
+        assert_approx_eq(orbit.get(2.0), (8.0, 20.0), 1e-5);
+        assert_approx_eq(orbit.get(7.0), (10.0, 18.0), 1e-5);
+        assert_approx_eq(
+            orbit.get(0.5),
+            (10.0 + 2f32.sqrt(), 20.0 + 2f32.sqrt()),
+            1e-5,
+        );
+        assert!(!orbit.is_finite());
+        assert_eq!(orbit.describe().kind, KeyframesKind::Orbit);
+
+        let animation = orbit.run(0.0);
+        assert_eq!(
+            animation.progress(1.0),
+            0.25,
+            "Progress of the current revolution"
+        );
+        assert_eq!(animation.iteration(9.0), 2);
+    }
+
+    #[test]
+    fn ellipse_and_axes() {
+        let ellipse = keyframes::ellipse::<(f32, f32), Instant>(
+            (0.0, 0.0),
+            (3.0, 1.0),
+            Duration::from_secs(2),
+        );
+        assert_approx_eq(ellipse.get(Duration::from_millis(500)), (0.0, 1.0), 1e-5);
+        assert_approx_eq(ellipse.get(Duration::from_secs(1)), (-3.0, 0.0), 1e-5);
+
+        let tilted = OrbitKeyframes::<[f32; 3], f32>::new(
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 1.0],
+            [0.0, 1.0, 2.0],
+            1.0,
+        );
+        assert_approx_eq(tilted.get(0.25), [0.0, 1.0, 2.0], 1e-5);
+        assert_approx_eq(tilted.get(0.5), [-1.0, 0.0, 1.0], 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Orbit period must be positive")]
+    fn zero_period() {
+        keyframes::orbit::<(f32, f32), f32>((0.0, 0.0), 1.0, 0.0);
+    }
+}
@@ -0,0 +1,222 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::math::{abs, floor_f64, sin};
+use crate::{Keyframes, Mix, Time, TimeDiff};
+use core::f32::consts::PI;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A shape of the oscillator cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Waveform {
+    /// Starts in the middle and goes up to `high`.
+    Sine,
+    /// Stays at `high` for the first half of the cycle, and at `low` for the second half.
+    Square,
+    /// Starts in the middle and goes up to `high` with a constant speed.
+    Triangle,
+    /// Goes from `low` to `high` with a constant speed, and jumps back.
+    Sawtooth,
+}
+
+impl Waveform {
+    /// Get the mix factor between `low` and `high` at the cycle position in `[0, 1)`.
+    fn factor(self, cycle: f32) -> f32 {
+        match self {
+            Waveform::Sine => 0.5 + 0.5 * sin(2.0 * PI * cycle),
+            Waveform::Square => {
+                if cycle < 0.5 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Waveform::Triangle => {
+                let shifted = cycle + 0.25;
+                let shifted = if shifted >= 1.0 {
+                    shifted - 1.0
+                } else {
+                    shifted
+                };
+                1.0 - 2.0 * abs(shifted - 0.5)
+            }
+            Waveform::Sawtooth => cycle,
+        }
+    }
+}
+
+/// Infinite keyframes oscillating between two values, see `keyframes::sine` and others.
+pub struct OscillatorKeyframes<T: Mix + Clone, X: Time> {
+    low: T,
+    high: T,
+    frequency: f32,
+    phase: f32,
+    waveform: Waveform,
+    phantom: PhantomData<X>,
+}
+
+impl<T: Mix + Clone, X: Time> OscillatorKeyframes<T, X> {
+    /// Oscillate between `low` and `high` with `frequency` cycles per time unit,
+    /// the time unit is a second for `Duration`. The phase is a part of the cycle the oscillation starts at.
+    pub fn new(low: T, high: T, frequency: f32, phase: f32, waveform: Waveform) -> Self {
+        assert!(
+            frequency >= 0.0 && frequency.is_finite(),
+            "Oscillator frequency must be finite and non-negative"
+        );
+        assert!(phase.is_finite(), "Oscillator phase must be finite");
+        Self {
+            low,
+            high,
+            frequency,
+            phase,
+            waveform,
+            phantom: Default::default(),
+        }
+    }
+
+    pub fn waveform(&self) -> Waveform {
+        self.waveform
+    }
+
+    pub fn frequency(&self) -> f32 {
+        self.frequency
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Keyframes<T, X> for OscillatorKeyframes<T, X> {
+    fn get(&self, offset: X::Duration) -> T {
+        let cycles = offset.as_f64() * self.frequency as f64 + self.phase as f64;
+        let cycle = (cycles - floor_f64(cycles)) as f32;
+        let t = self.waveform.factor(cycle.min(1.0 - f32::EPSILON));
+        self.low.mix_ref(&self.high, t)
+    }
+
+    fn duration(&self) -> X::Duration {
+        panic!("OscillatorKeyframes has infinite duration");
+    }
+
+    fn is_finite(&self) -> bool {
+        false
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Oscillator(self.waveform), None)
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        if self.frequency > 0.0 {
+            X::Duration::from_f64(1.0 / self.frequency as f64)
+        } else {
+            None
+        }
+    }
+
+    fn end_value(&self) -> T {
+        panic!("OscillatorKeyframes has no end value");
+    }
+}
+
+impl<T: Mix + Clone + Debug, X: Time> Debug for OscillatorKeyframes<T, X> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OscillatorKeyframes")
+            .field("low", &self.low)
+            .field("high", &self.high)
+            .field("frequency", &self.frequency)
+            .field("phase", &self.phase)
+            .field("waveform", &self.waveform)
+            .finish()
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Clone for OscillatorKeyframes<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            low: self.low.clone(),
+            high: self.high.clone(),
+            frequency: self.frequency,
+            phase: self.phase,
+            waveform: self.waveform,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> PartialEq for OscillatorKeyframes<T, X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.low == other.low
+            && self.high == other.high
+            && self.frequency == other.frequency
+            && self.phase == other.phase
+            && self.waveform == other.waveform
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+    use std::time::{Duration, Instant};
+
+    fn assert_close(value: f32, expected: f32) {
+        assert!(
+            abs(value - expected) < 1e-4,
+            "Expected {expected}, got {value}"
+        );
+    }
+
+    #[test]
+    fn waveforms() {
+        let sine = keyframes::sine::<f32, f32>((0.0, 2.0), 1.0, 0.0);
+        assert_close(sine.get(0.0), 1.0);
+        assert_close(sine.get(0.25), 2.0);
+        assert_close(sine.get(0.75), 0.0);
+        assert_close(sine.get(10.25), 2.0);
+        assert!(!sine.is_finite());
+        assert!(!sine.is_finished(1000.0));
+
+        let square = keyframes::square::<f32, f32>((0.0, 1.0), 2.0, 0.0);
+        assert_eq!(square.get(0.1), 1.0);
+        assert_eq!(square.get(0.3), 0.0);
+        assert_eq!(square.get(0.5), 1.0);
+
+        let triangle = keyframes::triangle::<f32, f32>((0.0, 2.0), 1.0, 0.0);
+        assert_close(triangle.get(0.0), 1.0);
+        assert_close(triangle.get(0.25), 2.0);
+        assert_close(triangle.get(0.5), 1.0);
+        assert_close(triangle.get(0.75), 0.0);
+
+        let sawtooth = keyframes::sawtooth::<f32, f32>((0.0, 4.0), 1.0, 0.0);
+        assert_close(sawtooth.get(0.5), 2.0);
+        assert_close(sawtooth.get(1.25), 1.0);
+    }
+
+    #[test]
+    fn phase_and_duration_time() {
+        let sine = keyframes::sine::<f32, Instant>((-1.0, 1.0), 0.5, 0.25);
+        assert_close(sine.get(Duration::ZERO), 1.0);
+        assert_close(sine.get(Duration::from_secs(1)), -1.0);
+        assert_eq!(sine.period(), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn period() {
+        let sine = keyframes::sine::<f32, f32>((0.0, 1.0), 0.5, 0.0);
+        assert_eq!(sine.period(), Some(2.0));
+
+        let animation = sine.run(1.0);
+        assert_eq!(
+            animation.progress(2.0),
+            0.5,
+            "Progress of the current cycle"
+        );
+        assert_eq!(animation.iteration(6.0), 2);
+
+        let still = keyframes::sine::<f32, f32>((0.0, 1.0), 0.0, 0.0);
+        assert_eq!(still.period(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Oscillator frequency must be finite and non-negative")]
+    fn negative_frequency() {
+        keyframes::sine::<f32, f32>((0.0, 1.0), -1.0, 0.0);
+    }
+}
@@ -1,12 +1,14 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use crate::poly::Poly;
-use crate::{Distance, Easing, Keyframes, Mix, Time};
-use std::fmt::Debug;
+use crate::{Distance, Easing, Keyframes, Mix, Time, TimeDiff};
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 #[derive(Clone)]
 pub struct PolyKeyframes<T: Clone + Mix + Distance, X: Time> {
-    poly: Poly<T>,
-    duration: X::Duration,
-    easing: Easing,
+    pub(crate) poly: Poly<T>,
+    pub(crate) duration: X::Duration,
+    pub(crate) easing: Easing,
 }
 
 impl<T, X> Debug for PolyKeyframes<T, X>
@@ -15,7 +17,7 @@ where
     X: Time,
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("PolyKeyframes")
             .field("poly", &self.poly)
             .field("duration", &self.duration)
@@ -29,7 +31,7 @@ where
     X: Time,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.poly == other.poly && self.duration == other.duration
+        self.poly == other.poly && self.duration == other.duration && self.easing == other.easing
     }
 }
 
@@ -45,10 +47,12 @@ impl<T: Clone + Mix + Distance, X: Time> PolyKeyframes<T, X> {
 
 impl<T: Clone + Mix + Distance, X: Time> Keyframes<T, X> for PolyKeyframes<T, X> {
     fn get(&self, offset: X::Duration) -> T {
-        self.poly.value_at(
-            self.easing
-                .ease(X::duration_as_f32(offset) / X::duration_as_f32(self.duration)),
-        )
+        let t = if self.duration > Default::default() {
+            offset.as_f32() / self.duration.as_f32()
+        } else {
+            1.0
+        };
+        self.poly.value_at(self.easing.ease(t))
     }
 
     fn duration(&self) -> X::Duration {
@@ -58,4 +62,9 @@ impl<T: Clone + Mix + Distance, X: Time> Keyframes<T, X> for PolyKeyframes<T, X>
     fn is_finite(&self) -> bool {
         true
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Poly, Some(self.duration))
+            .with_easing(self.easing.clone())
+    }
 }
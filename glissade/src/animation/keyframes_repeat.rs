@@ -1,6 +1,8 @@
-use crate::{Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::{Keyframes, Time, TimeDiff};
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
 /// An animation that repeats keyframes indefinitely.
 pub struct RepeatKeyframes<T, X: Time, S: Keyframes<T, X>> {
@@ -12,7 +14,7 @@ impl<T, X: Time, S: Keyframes<T, X> + Debug> Debug for RepeatKeyframes<T, X, S>
 where
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("RepeatKeyframes")
             .field("keyframes", &self.keyframes)
             .finish()
@@ -25,45 +27,117 @@ impl<T, X: Time, S: Keyframes<T, X> + PartialEq> PartialEq for RepeatKeyframes<T
     }
 }
 
+impl<T, X: Time, S: Keyframes<T, X> + Eq> Eq for RepeatKeyframes<T, X, S> {}
+
+impl<T, X: Time, S: Keyframes<T, X> + Hash> Hash for RepeatKeyframes<T, X, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.keyframes.hash(state);
+    }
+}
+
+/// An error of constructing repeated keyframes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepeatError {
+    /// The repeated keyframes have zero duration, so the repeat would never advance.
+    ZeroDuration,
+    /// The repeat count is negative, NaN or infinite.
+    InvalidCount,
+}
+
+impl Display for RepeatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RepeatError::ZeroDuration => write!(f, "can't repeat keyframes of zero duration"),
+            RepeatError::InvalidCount => {
+                write!(f, "repeat count must be finite and non-negative")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RepeatError {}
+
 impl<T, X: Time, S: Keyframes<T, X>> RepeatKeyframes<T, X, S> {
+    /// Repeated keyframes of zero duration are finished immediately and stay at the start value.
+    /// Use `try_new` to reject them instead.
     pub fn new(keyframes: S) -> Self {
         Self {
             keyframes,
             phantom: Default::default(),
         }
     }
+
+    /// Same as `new`, but fails if the keyframes have zero duration.
+    pub fn try_new(keyframes: S) -> Result<Self, RepeatError> {
+        if keyframes.try_duration() == Some(Default::default()) {
+            Err(RepeatError::ZeroDuration)
+        } else {
+            Ok(Self::new(keyframes))
+        }
+    }
+
+    fn is_zero_duration(&self) -> bool {
+        self.keyframes.try_duration() == Some(Default::default())
+    }
 }
 
+/// Repeating keyframes of zero duration is finite, it has zero duration and the start value.
 impl<T, X: Time, S: Keyframes<T, X>> Keyframes<T, X> for RepeatKeyframes<T, X, S> {
     fn get(&self, offset: X::Duration) -> T {
-        if !self.keyframes.is_finite() {
-            return self.keyframes.get(offset);
+        match self.keyframes.try_duration() {
+            Some(duration) => cycle_value(&self.keyframes, offset, duration),
+            None => self.keyframes.get(offset),
         }
-
-        let n = X::duration_as_f32(offset) / X::duration_as_f32(self.keyframes.duration());
-        let step_offset = X::duration_scale(self.keyframes.duration(), n.floor());
-
-        let offset = if step_offset < offset {
-            X::duration_diff(offset, step_offset)
-        } else {
-            Default::default()
-        };
-        self.keyframes.get(offset)
     }
 
     fn duration(&self) -> X::Duration {
+        if self.is_zero_duration() {
+            return Default::default();
+        }
         panic!("RepeatKeyframes has infinite duration");
     }
 
     fn is_finite(&self) -> bool {
-        false
+        self.is_zero_duration()
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Repeat, self.try_duration())
+            .with_children([self.keyframes.describe()])
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        match self.keyframes.try_duration() {
+            Some(duration) if duration > Default::default() => Some(duration),
+            Some(_) => None,
+            None => self.keyframes.period(),
+        }
     }
 
     fn end_value(&self) -> T {
+        if self.is_zero_duration() {
+            return self.keyframes.start_value();
+        }
         panic!("RepeatKeyframes has no end value");
     }
 }
 
+/// Get the value of repeated keyframes. Cycles include their end,
+/// so at multiples of the duration the end value is returned, instead of the start of the next cycle.
+pub(crate) fn cycle_value<T, X: Time>(
+    keyframes: &impl Keyframes<T, X>,
+    offset: X::Duration,
+    duration: X::Duration,
+) -> T {
+    let rest = offset.wrap(duration);
+    if rest == Default::default() && offset > Default::default() {
+        keyframes.end_value()
+    } else {
+        keyframes.get(rest)
+    }
+}
+
 impl<T, X: Time, S: Keyframes<T, X> + Clone> Clone for RepeatKeyframes<T, X, S> {
     fn clone(&self) -> Self {
         Self {
@@ -77,7 +151,8 @@ impl<T, X: Time, S: Keyframes<T, X> + Copy> Copy for RepeatKeyframes<T, X, S> {}
 
 #[cfg(test)]
 mod tests {
-    use crate::{keyframes, Keyframes};
+    use super::*;
+    use crate::keyframes;
 
     #[test]
     fn test_repeat_keyframes() {
@@ -85,9 +160,44 @@ mod tests {
         assert_eq!(keyframes.get(0.0), 0.0);
         assert_eq!(keyframes.get(0.5), 4.0);
         assert_eq!(keyframes.get(0.75), 6.0);
+        assert_eq!(keyframes.get(1.0), 8.0);
         assert_eq!(keyframes.get(1.5), 4.0);
+        assert_eq!(keyframes.get(2.0), 8.0);
         assert_eq!(keyframes.get(2.25), 2.0);
         assert_eq!(keyframes.get(2.5), 4.0);
         assert_eq!(keyframes.get(8.25), 2.0);
     }
+
+    #[test]
+    fn test_repeat_long_running() {
+        use std::time::{Duration, Instant};
+
+        let keyframes = keyframes::from::<f64, Instant>(0.0)
+            .go_to(1.0, Duration::from_secs(1))
+            .repeat();
+        let ten_hours = Duration::from_secs(36_000);
+        assert_eq!(keyframes.get(ten_hours + Duration::from_millis(250)), 0.25);
+        assert_eq!(keyframes.get(ten_hours + Duration::from_millis(750)), 0.75);
+    }
+
+    #[test]
+    fn test_repeat_zero_duration() {
+        let keyframes = keyframes::line::<f32, f32>(0.0, 1.0, 0.0).repeat();
+        assert_eq!(keyframes.get(0.0), 1.0);
+        assert_eq!(keyframes.get(10.0), 1.0);
+        assert!(keyframes.is_finite());
+        assert!(keyframes.is_finished(0.0));
+        assert_eq!(keyframes.duration(), 0.0);
+        assert_eq!(keyframes.end_value(), 1.0);
+
+        let repeat_n = keyframes::stay::<f32, f32>(2.0, 0.0).repeat_n(3.0);
+        assert_eq!(repeat_n.get(1.0), 2.0);
+        assert!(repeat_n.is_finished(0.0));
+
+        assert_eq!(
+            keyframes::stay::<f32, f32>(2.0, 0.0).try_repeat(),
+            Err(RepeatError::ZeroDuration)
+        );
+        assert!(keyframes::stay::<f32, f32>(2.0, 1.0).try_repeat().is_ok());
+    }
 }
@@ -1,6 +1,10 @@
-use crate::{Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use super::keyframes_repeat::{cycle_value, RepeatError};
+use super::keyframes_scale::is_valid_factor;
+use crate::{Keyframes, Time, TimeDiff};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
 
 /// An animation that repeats another keyframes n times.
 pub struct RepeatNKeyframes<T, X: Time, S: Keyframes<T, X>> {
@@ -13,7 +17,7 @@ impl<T, X: Time, S: Keyframes<T, X> + Debug> Debug for RepeatNKeyframes<T, X, S>
 where
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("RepeatNKeyframes")
             .field("keyframes", &self.keyframes)
             .field("n", &self.n)
@@ -28,13 +32,28 @@ impl<T, X: Time, S: Keyframes<T, X> + PartialEq> PartialEq for RepeatNKeyframes<
 }
 
 impl<T, X: Time, S: Keyframes<T, X>> RepeatNKeyframes<T, X, S> {
+    /// The count must be finite and non-negative, it can be fractional.
+    /// It panics otherwise, use `try_new` to get an error instead.
     pub fn new(keyframes: S, n: f32) -> Self {
+        assert!(
+            is_valid_factor(n),
+            "Repeat count must be finite and non-negative"
+        );
         Self {
             keyframes,
             n,
             phantom: Default::default(),
         }
     }
+
+    /// Same as `new`, but fails if the count is negative, NaN or infinite.
+    pub fn try_new(keyframes: S, n: f32) -> Result<Self, RepeatError> {
+        if is_valid_factor(n) {
+            Ok(Self::new(keyframes, n))
+        } else {
+            Err(RepeatError::InvalidCount)
+        }
+    }
 }
 
 impl<T, X: Time, S: Keyframes<T, X>> Keyframes<T, X> for RepeatNKeyframes<T, X, S> {
@@ -43,30 +62,68 @@ impl<T, X: Time, S: Keyframes<T, X>> Keyframes<T, X> for RepeatNKeyframes<T, X,
             return self.keyframes.get(offset);
         }
 
-        let duration = X::duration_as_f32(self.keyframes.duration());
-        let n = X::duration_as_f32(offset) / duration;
-
-        if n < self.n {
-            let step_offset = X::duration_scale(self.keyframes.duration(), n.floor());
-
-            let offset = if step_offset < offset {
-                X::duration_diff(offset, step_offset)
-            } else {
-                Default::default()
-            };
-            self.keyframes.get(offset)
+        let duration = self.keyframes.duration();
+        if duration == Default::default() {
+            return self.keyframes.start_value();
+        }
+        if offset < self.duration() {
+            cycle_value(&self.keyframes, offset, duration)
         } else {
             self.keyframes.end_value()
         }
     }
 
     fn duration(&self) -> X::Duration {
-        X::duration_scale(self.keyframes.duration(), self.n)
+        self.keyframes.duration().scale(self.n)
+    }
+
+    /// Joins of each cycle, and boundaries between cycles.
+    fn joins(&self) -> Vec<X::Duration> {
+        let Some(duration) = self.keyframes.try_duration() else {
+            return self.keyframes.joins();
+        };
+        if duration == Default::default() {
+            return Vec::new();
+        }
+
+        let end = self.duration();
+        let inner = self.keyframes.joins();
+        let mut joins = Vec::new();
+        let mut cycle = 0;
+        loop {
+            let start = duration.scale(cycle as f32);
+            if start >= end {
+                return joins;
+            }
+            if cycle > 0 {
+                joins.push(start);
+            }
+            joins.extend(
+                inner
+                    .iter()
+                    .map(|join| start + *join)
+                    .filter(|join| *join < end),
+            );
+            cycle += 1;
+        }
     }
 
     fn is_finite(&self) -> bool {
         self.keyframes.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::RepeatN(self.n), self.try_duration())
+            .with_children([self.keyframes.describe()])
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        match self.keyframes.try_duration() {
+            Some(duration) if duration > Default::default() => Some(duration),
+            Some(_) => None,
+            None => self.keyframes.period(),
+        }
+    }
 }
 
 impl<T, X: Time, S: Keyframes<T, X> + Clone> Clone for RepeatNKeyframes<T, X, S> {
@@ -93,9 +150,30 @@ mod test {
         assert_eq!(keyframes.get(0.0), 0.0);
         assert_eq!(keyframes.get(0.5), 5.0);
         assert_eq!(keyframes.get(0.75), 7.5);
+        assert_eq!(keyframes.get(1.0), 10.0);
         assert_eq!(keyframes.get(1.5), 5.0);
         assert_eq!(keyframes.get(2.0), 10.0);
         assert_eq!(keyframes.get(2.1), 10.0);
         assert_eq!(keyframes.get(100.0), 10.0);
     }
+
+    #[test]
+    fn invalid_count() {
+        let line = keyframes::line::<f32, f32>(0.0, 1.0, 1.0);
+        assert!(line.try_repeat_n(0.0).is_ok());
+        assert_eq!(
+            line.try_repeat_n(-2.0).err(),
+            Some(crate::RepeatError::InvalidCount)
+        );
+        assert_eq!(
+            line.try_repeat_n(f32::NAN).err(),
+            Some(crate::RepeatError::InvalidCount)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Repeat count must be finite and non-negative")]
+    fn negative_count() {
+        keyframes::line::<f32, f32>(0.0, 1.0, 1.0).repeat_n(-2.0);
+    }
 }
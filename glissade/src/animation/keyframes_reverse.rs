@@ -1,6 +1,9 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use crate::{Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
 /// An animation that reverses the order of keyframes.
 pub struct ReverseKeyframes<T, X: Time, S: Keyframes<T, X>> {
@@ -9,7 +12,7 @@ pub struct ReverseKeyframes<T, X: Time, S: Keyframes<T, X>> {
 }
 
 impl<T, X: Time, S: Keyframes<T, X> + Debug> Debug for ReverseKeyframes<T, X, S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ReverseKeyframes")
             .field("keyframes", &self.keyframes)
             .finish()
@@ -22,8 +25,21 @@ impl<T, X: Time, S: Keyframes<T, X> + PartialEq> PartialEq for ReverseKeyframes<
     }
 }
 
+impl<T, X: Time, S: Keyframes<T, X> + Eq> Eq for ReverseKeyframes<T, X, S> {}
+
+impl<T, X: Time, S: Keyframes<T, X> + Hash> Hash for ReverseKeyframes<T, X, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.keyframes.hash(state);
+    }
+}
+
 impl<T, X: Time, S: Keyframes<T, X>> ReverseKeyframes<T, X, S> {
+    /// It panics if the keyframes are infinite, use `Keyframes::reverse_window` for them.
     pub fn new(keyframes: S) -> Self {
+        assert!(
+            keyframes.is_finite(),
+            "Infinite keyframes can't be reversed, use reverse_window"
+        );
         Self {
             keyframes,
             phantom: Default::default(),
@@ -33,17 +49,38 @@ impl<T, X: Time, S: Keyframes<T, X>> ReverseKeyframes<T, X, S> {
 
 impl<T, X: Time, S: Keyframes<T, X>> Keyframes<T, X> for ReverseKeyframes<T, X, S> {
     fn get(&self, offset: X::Duration) -> T {
-        self.keyframes
-            .get(X::duration_diff(self.keyframes.duration(), offset))
+        let duration = self.keyframes.duration();
+        if offset >= duration {
+            self.keyframes.get(Default::default())
+        } else if offset > Default::default() {
+            self.keyframes.get(duration - offset)
+        } else {
+            self.keyframes.get(duration)
+        }
     }
 
     fn duration(&self) -> X::Duration {
         self.keyframes.duration()
     }
 
+    fn joins(&self) -> Vec<X::Duration> {
+        let duration = self.keyframes.duration();
+        let joins = self.keyframes.joins();
+        joins
+            .into_iter()
+            .rev()
+            .map(|join| duration - join)
+            .collect()
+    }
+
     fn is_finite(&self) -> bool {
         true
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Reverse, self.try_duration())
+            .with_children([self.keyframes.describe()])
+    }
 }
 
 impl<T, X: Time, S: Keyframes<T, X> + Clone> Clone for ReverseKeyframes<T, X, S> {
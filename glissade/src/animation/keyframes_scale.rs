@@ -1,6 +1,8 @@
-use crate::{Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::{Keyframes, Time, TimeDiff};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
 
 /// An animation that scales the time of keyframes.
 pub struct ScaleKeyframes<T, X: Time, S: Keyframes<T, X>> {
@@ -10,7 +12,7 @@ pub struct ScaleKeyframes<T, X: Time, S: Keyframes<T, X>> {
 }
 
 impl<T, X: Time, S: Keyframes<T, X> + Debug> Debug for ScaleKeyframes<T, X, S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ScaleKeyframes")
             .field("keyframes", &self.keyframes)
             .field("scale", &self.scale)
@@ -24,29 +26,93 @@ impl<T, X: Time, S: Keyframes<T, X> + PartialEq> PartialEq for ScaleKeyframes<T,
     }
 }
 
+/// An error of constructing scaled keyframes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScaleError {
+    /// The scale is negative, NaN or infinite.
+    InvalidScale,
+}
+
+impl Display for ScaleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScaleError::InvalidScale => write!(f, "scale must be finite and non-negative"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ScaleError {}
+
 impl<T, X: Time, S: Keyframes<T, X>> ScaleKeyframes<T, X, S> {
+    /// The scale must be finite and non-negative, zero makes the keyframes take no time.
+    /// It panics otherwise, use `try_new` to get an error instead.
     pub fn new(keyframes: S, scale: f32) -> Self {
+        assert!(
+            is_valid_factor(scale),
+            "Scale must be finite and non-negative"
+        );
         Self {
             keyframes,
             scale,
             phantom: Default::default(),
         }
     }
+
+    /// Same as `new`, but fails if the scale is negative, NaN or infinite.
+    pub fn try_new(keyframes: S, scale: f32) -> Result<Self, ScaleError> {
+        if is_valid_factor(scale) {
+            Ok(Self::new(keyframes, scale))
+        } else {
+            Err(ScaleError::InvalidScale)
+        }
+    }
+}
+
+/// Check that a scale or a repeat count is finite and non-negative.
+pub(crate) fn is_valid_factor(factor: f32) -> bool {
+    factor.is_finite() && factor >= 0.0
 }
 
 impl<T, X: Time, S: Keyframes<T, X>> Keyframes<T, X> for ScaleKeyframes<T, X, S> {
     fn get(&self, offset: X::Duration) -> T {
-        self.keyframes
-            .get(X::duration_scale(offset, 1.0 / self.scale))
+        match offset.checked_scale(1.0 / self.scale) {
+            Some(offset) => self.keyframes.get(offset),
+            // Zero scale, the animation takes no time.
+            None => match self.keyframes.try_end_value() {
+                Some(value) => value,
+                None => self.keyframes.start_value(),
+            },
+        }
     }
 
     fn duration(&self) -> X::Duration {
-        X::duration_scale(self.keyframes.duration(), self.scale)
+        self.keyframes.duration().scale(self.scale)
+    }
+
+    fn joins(&self) -> Vec<X::Duration> {
+        self.keyframes
+            .joins()
+            .into_iter()
+            .filter_map(|join| join.checked_scale(self.scale))
+            .collect()
+    }
+
+    fn period(&self) -> Option<X::Duration> {
+        self.keyframes
+            .period()
+            .and_then(|period| period.checked_scale(self.scale))
+            .filter(|period| *period > Default::default())
     }
 
     fn is_finite(&self) -> bool {
         self.keyframes.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Scale(self.scale), self.try_duration())
+            .with_children([self.keyframes.describe()])
+    }
 }
 
 impl<T, X: Time, S: Keyframes<T, X> + Clone> Clone for ScaleKeyframes<T, X, S> {
@@ -60,3 +126,30 @@ impl<T, X: Time, S: Keyframes<T, X> + Clone> Clone for ScaleKeyframes<T, X, S> {
 }
 
 impl<T, X: Time, S: Keyframes<T, X> + Copy> Copy for ScaleKeyframes<T, X, S> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    #[test]
+    fn invalid_scale() {
+        let line = keyframes::line::<f32, f32>(0.0, 1.0, 1.0);
+        assert!(line.try_scale(0.0).is_ok());
+        assert_eq!(line.try_scale(-1.0).err(), Some(ScaleError::InvalidScale));
+        assert_eq!(
+            line.try_scale(f32::NAN).err(),
+            Some(ScaleError::InvalidScale)
+        );
+        assert_eq!(
+            line.try_scale(f32::INFINITY).err(),
+            Some(ScaleError::InvalidScale)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale must be finite and non-negative")]
+    fn negative_scale() {
+        keyframes::line::<f32, f32>(0.0, 1.0, 1.0).scale(-1.0);
+    }
+}
@@ -1,11 +1,21 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use crate::{Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
 /// A sequence of two keyframes set.
+/// The duration of the first one and the finiteness are cached, so long chains built with `go_to`,
+/// `ease_to`, etc. don't walk the whole chain on every step.
 pub struct SequentialKeyframes<T, X: Time, S1: Keyframes<T, X>, S2: Keyframes<T, X>> {
-    t1: S1,
-    t2: S2,
+    pub(crate) t1: S1,
+    pub(crate) t2: S2,
+    /// `None` if `t1` is infinite.
+    t1_duration: Option<X::Duration>,
+    /// Both `t1` and `t2` are finite.
+    is_finite: bool,
     phantom: PhantomData<(T, X)>,
 }
 
@@ -14,7 +24,7 @@ impl<T, X: Time, S1: Keyframes<T, X> + Debug, S2: Keyframes<T, X> + Debug> Debug
 where
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SequentialKeyframes")
             .field("t1", &self.t1)
             .field("t2", &self.t2)
@@ -30,36 +40,85 @@ impl<T, X: Time, S1: Keyframes<T, X> + PartialEq, S2: Keyframes<T, X> + PartialE
     }
 }
 
+impl<T, X: Time, S1: Keyframes<T, X> + Eq, S2: Keyframes<T, X> + Eq> Eq
+    for SequentialKeyframes<T, X, S1, S2>
+{
+}
+
+impl<T, X: Time, S1: Keyframes<T, X> + Hash, S2: Keyframes<T, X> + Hash> Hash
+    for SequentialKeyframes<T, X, S1, S2>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.t1.hash(state);
+        self.t2.hash(state);
+    }
+}
+
 impl<T, X: Time, S1: Keyframes<T, X>, S2: Keyframes<T, X>> Keyframes<T, X>
     for SequentialKeyframes<T, X, S1, S2>
 {
     fn get(&self, offset: X::Duration) -> T {
-        if !self.t1.is_finite() {
-            return self.t1.get(offset);
+        match self.t1_duration {
+            Some(t1) if offset >= t1 => self.t2.get(offset - t1),
+            _ => self.t1.get(offset),
         }
+    }
 
-        let t1 = self.t1.duration();
-        if offset < t1 {
-            self.t1.get(offset)
-        } else {
-            self.t2.get(X::duration_diff(offset, t1))
+    fn duration(&self) -> X::Duration {
+        match self.t1_duration {
+            Some(t1) => t1 + self.t2.duration(),
+            None => self.t1.duration(),
         }
     }
 
-    fn duration(&self) -> X::Duration {
-        X::duration_sum(self.t1.duration(), self.t2.duration())
+    fn joins(&self) -> Vec<X::Duration> {
+        let mut joins = self.t1.joins();
+        if let Some(t1) = self.t1_duration {
+            joins.push(t1);
+            joins.extend(self.t2.joins().into_iter().map(|join| t1 + join));
+        }
+        joins
+    }
+
+    fn end_value(&self) -> T {
+        match self.t1_duration {
+            Some(_) => self.t2.end_value(),
+            None => self.t1.end_value(),
+        }
     }
 
     fn is_finite(&self) -> bool {
-        self.t1.is_finite() && self.t2.is_finite()
+        self.is_finite
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        let children = [self.t1.describe(), self.t2.describe()]
+            .into_iter()
+            .flat_map(|child| {
+                if child.kind == KeyframesKind::Sequence {
+                    child.children
+                } else {
+                    vec![child]
+                }
+            });
+        KeyframesDescription::new(KeyframesKind::Sequence, self.try_duration())
+            .with_children(children)
     }
 }
 
 impl<T, X: Time, S1: Keyframes<T, X>, S2: Keyframes<T, X>> SequentialKeyframes<T, X, S1, S2> {
     pub fn new(t1: S1, t2: S2) -> Self {
+        let t1_duration = if t1.is_finite() {
+            Some(t1.duration())
+        } else {
+            None
+        };
+        let is_finite = t1_duration.is_some() && t2.is_finite();
         Self {
             t1,
             t2,
+            t1_duration,
+            is_finite,
             phantom: Default::default(),
         }
     }
@@ -72,6 +131,8 @@ impl<T, X: Time, S1: Keyframes<T, X> + Clone, S2: Keyframes<T, X> + Clone> Clone
         Self {
             t1: self.t1.clone(),
             t2: self.t2.clone(),
+            t1_duration: self.t1_duration,
+            is_finite: self.is_finite,
             phantom: Default::default(),
         }
     }
@@ -81,3 +142,46 @@ impl<T, X: Time, S1: Keyframes<T, X> + Copy, S2: Keyframes<T, X> + Copy> Copy
     for SequentialKeyframes<T, X, S1, S2>
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BoxedKeyframes;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    /// Counts `duration` and `is_finite` calls, to check that chains don't re-evaluate their beginning.
+    struct CountingKeyframes(Rc<Cell<usize>>);
+
+    impl Keyframes<f32, f32> for CountingKeyframes {
+        fn get(&self, _offset: f32) -> f32 {
+            0.0
+        }
+
+        fn duration(&self) -> f32 {
+            self.0.set(self.0.get() + 1);
+            1.0
+        }
+
+        fn is_finite(&self) -> bool {
+            self.0.set(self.0.get() + 1);
+            true
+        }
+    }
+
+    #[test]
+    fn long_chain() {
+        let calls = Rc::new(Cell::new(0));
+        let mut chain: BoxedKeyframes<f32, f32> = CountingKeyframes(calls.clone()).boxed();
+        for i in 0..100 {
+            chain = chain.go_to(i as f32, 1.0).boxed();
+        }
+
+        assert!(calls.get() <= 3, "calls: {}", calls.get());
+        assert!(chain.is_finite());
+        assert_eq!(chain.duration(), 101.0);
+        assert!(calls.get() <= 3, "calls: {}", calls.get());
+        assert_eq!(chain.get(50.5), 48.5);
+        assert_eq!(chain.end_value(), 99.0);
+    }
+}
@@ -0,0 +1,173 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::math::{exp, floor_f64, ln};
+use crate::{Keyframes, Mix, Time, TimeDiff};
+use core::fmt::Debug;
+
+/// A decaying random shake between two values, see `keyframes::shake`.
+pub struct ShakeKeyframes<T: Mix + Clone, X: Time> {
+    low: T,
+    high: T,
+    frequency: f32,
+    duration: X::Duration,
+    decay: f32,
+    seed: u64,
+}
+
+impl<T: Mix + Clone, X: Time> ShakeKeyframes<T, X> {
+    /// Shake between `low` and `high` with `frequency` random targets per time unit,
+    /// the time unit is a second for `Duration`. The shake starts and ends in the middle,
+    /// and its amplitude falls as `(1 - progress) ^ decay`, so zero decay keeps it constant.
+    pub fn new(low: T, high: T, frequency: f32, duration: X::Duration, decay: f32) -> Self {
+        assert!(
+            frequency >= 0.0 && frequency.is_finite(),
+            "Shake frequency must be finite and non-negative"
+        );
+        assert!(
+            decay >= 0.0 && decay.is_finite(),
+            "Shake decay must be finite and non-negative"
+        );
+        Self {
+            low,
+            high,
+            frequency,
+            duration,
+            decay,
+            seed: 0,
+        }
+    }
+
+    /// Set the seed of the random targets, the same seed gives the same shake.
+    /// Shakes with different seeds can be joined into a tuple to shake several axes independently.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Get a random target in `[-1, 1]` of the shake step, the first target is 0 to start in the middle.
+    fn target(&self, step: u64) -> f32 {
+        if step == 0 {
+            return 0.0;
+        }
+        let mut hash = self.seed ^ step.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        hash ^= hash >> 31;
+        (hash >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+}
+
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+impl<T: Mix + Clone, X: Time> Keyframes<T, X> for ShakeKeyframes<T, X> {
+    fn get(&self, offset: X::Duration) -> T {
+        if offset <= Default::default() || offset >= self.duration {
+            return self.low.mix_ref(&self.high, 0.5);
+        }
+
+        let steps = offset.as_f64() * self.frequency as f64;
+        let step = floor_f64(steps);
+        let s = smoothstep((steps - step) as f32);
+        let noise = self.target(step as u64) * (1.0 - s) + self.target(step as u64 + 1) * s;
+
+        let progress = offset.as_f32() / self.duration.as_f32();
+        let envelope = exp(self.decay * ln(1.0 - progress));
+        self.low.mix_ref(&self.high, 0.5 + 0.5 * noise * envelope)
+    }
+
+    fn duration(&self) -> X::Duration {
+        self.duration
+    }
+
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Shake, Some(self.duration))
+    }
+}
+
+impl<T: Mix + Clone + Debug, X: Time> Debug for ShakeKeyframes<T, X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ShakeKeyframes")
+            .field("low", &self.low)
+            .field("high", &self.high)
+            .field("frequency", &self.frequency)
+            .field("duration", &self.duration)
+            .field("decay", &self.decay)
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+impl<T: Mix + Clone, X: Time> Clone for ShakeKeyframes<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            low: self.low.clone(),
+            high: self.high.clone(),
+            frequency: self.frequency,
+            duration: self.duration,
+            decay: self.decay,
+            seed: self.seed,
+        }
+    }
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> PartialEq for ShakeKeyframes<T, X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.low == other.low
+            && self.high == other.high
+            && self.frequency == other.frequency
+            && self.duration == other.duration
+            && self.decay == other.decay
+            && self.seed == other.seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    #[test]
+    fn decaying_shake() {
+        let shake = keyframes::shake::<f32, f32>((-1.0, 1.0), 20.0, 1.0, 1.0);
+        assert_eq!(shake.get(0.0), 0.0, "Starts in the middle");
+        assert_eq!(shake.get(1.0), 0.0, "Ends in the middle");
+        assert_eq!(shake.duration(), 1.0);
+
+        let values: Vec<f32> = (1..100).map(|i| shake.get(i as f32 / 100.0)).collect();
+        assert!(values.iter().all(|v| v.abs() <= 1.0));
+        assert!(values.iter().any(|v| *v > 0.1) && values.iter().any(|v| *v < -0.1));
+
+        let early = values[..20].iter().map(|v| v.abs()).fold(0.0, f32::max);
+        let late = values[80..].iter().map(|v| v.abs()).fold(0.0, f32::max);
+        assert!(late < early, "Decays from {} to {}", early, late);
+        assert!(late <= 0.2, "The amplitude is at most 20% at the last 20%");
+    }
+
+    #[test]
+    fn seeds() {
+        let shake = keyframes::shake::<f32, f32>((0.0, 10.0), 10.0, 1.0, 0.0);
+        let same = shake.clone().with_seed(0);
+        let other = shake.clone().with_seed(1);
+        assert_eq!(shake.get(0.35), same.get(0.35));
+        assert_ne!(shake.get(0.35), other.get(0.35));
+        assert_eq!(shake.get(0.3), shake.get(0.3), "Deterministic");
+    }
+
+    #[test]
+    #[should_panic(expected = "Shake decay must be finite and non-negative")]
+    fn negative_decay() {
+        keyframes::shake::<f32, f32>((0.0, 1.0), 10.0, 1.0, -1.0);
+    }
+}
@@ -1,18 +1,51 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use crate::{Keyframes, Time};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
+/// A part of keyframes, offsets are cropped to it.
 pub struct SliceKeyframes<T, X: Time, K: Keyframes<T, X>> {
     keyframes: K,
-    range: (X::Duration, X::Duration),
+    start: X::Duration,
+    /// `None` if the slice goes until the end of the keyframes.
+    end: Option<X::Duration>,
     phantom: PhantomData<T>,
 }
 
 impl<T, X: Time, K: Keyframes<T, X>> SliceKeyframes<T, X, K> {
+    /// It panics if the start is after the end, or the range is out of finite keyframes.
     pub fn new(keyframes: K, range: (X::Duration, X::Duration)) -> Self {
+        Self::with_bounds(keyframes, range.0, Some(range.1))
+    }
+
+    /// Slice from the start offset until the end of the keyframes, they can be infinite.
+    /// It panics if the start is after the end of finite keyframes.
+    pub fn from_start(keyframes: K, start: X::Duration) -> Self {
+        Self::with_bounds(keyframes, start, None)
+    }
+
+    fn with_bounds(keyframes: K, start: X::Duration, end: Option<X::Duration>) -> Self {
+        assert!(
+            start >= Default::default(),
+            "Slice start must not be negative"
+        );
+        assert!(
+            end.is_none_or(|end| start <= end),
+            "Slice start must not be after its end"
+        );
+        if let Some(duration) = keyframes.try_duration() {
+            assert!(
+                end.unwrap_or(start) <= duration,
+                "Slice must be within the keyframes"
+            );
+        }
+
         Self {
             keyframes,
-            range,
+            start,
+            end,
             phantom: Default::default(),
         }
     }
@@ -20,23 +53,37 @@ impl<T, X: Time, K: Keyframes<T, X>> SliceKeyframes<T, X, K> {
 
 impl<T, X: Time, K: Keyframes<T, X>> Keyframes<T, X> for SliceKeyframes<T, X, K> {
     fn get(&self, offset: X::Duration) -> T {
-        let offset = X::duration_sum(offset, self.range.0);
-        let offset = if offset < self.range.0 {
-            self.range.0
-        } else if offset > self.range.1 {
-            self.range.1
+        let offset = if offset > Default::default() {
+            self.start + offset
         } else {
-            offset
+            self.start
         };
-        self.keyframes.get(offset)
+        match self.end {
+            Some(end) if offset > end => self.keyframes.get(end),
+            _ => self.keyframes.get(offset),
+        }
     }
 
     fn duration(&self) -> X::Duration {
-        X::duration_diff(self.range.1, self.range.0)
+        self.end.unwrap_or_else(|| self.keyframes.duration()) - self.start
     }
 
     fn is_finite(&self) -> bool {
-        true
+        self.end.is_some() || self.keyframes.is_finite()
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Slice, self.try_duration())
+            .with_children([self.keyframes.describe()])
+    }
+
+    fn joins(&self) -> Vec<X::Duration> {
+        self.keyframes
+            .joins()
+            .into_iter()
+            .filter(|join| *join > self.start && self.end.is_none_or(|end| *join < end))
+            .map(|join| join - self.start)
+            .collect()
     }
 }
 
@@ -46,10 +93,11 @@ where
     X::Duration: Debug,
     K: Keyframes<T, X> + Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SliceKeyframes")
             .field("keyframes", &self.keyframes)
-            .field("range", &self.range)
+            .field("start", &self.start)
+            .field("end", &self.end)
             .finish()
     }
 }
@@ -58,7 +106,8 @@ impl<T, X: Time, K: Keyframes<T, X> + Clone> Clone for SliceKeyframes<T, X, K> {
     fn clone(&self) -> Self {
         Self {
             keyframes: self.keyframes.clone(),
-            range: self.range,
+            start: self.start,
+            end: self.end,
             phantom: Default::default(),
         }
     }
@@ -68,7 +117,20 @@ impl<T, X: Time, K: Keyframes<T, X> + Copy> Copy for SliceKeyframes<T, X, K> {}
 
 impl<T, X: Time, K: Keyframes<T, X> + PartialEq> PartialEq for SliceKeyframes<T, X, K> {
     fn eq(&self, other: &Self) -> bool {
-        self.keyframes == other.keyframes && self.range == other.range
+        self.keyframes == other.keyframes && self.start == other.start && self.end == other.end
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Eq> Eq for SliceKeyframes<T, X, K> where X::Duration: Eq {}
+
+impl<T, X: Time, K: Keyframes<T, X> + Hash> Hash for SliceKeyframes<T, X, K>
+where
+    X::Duration: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.keyframes.hash(state);
+        self.start.hash(state);
+        self.end.hash(state);
     }
 }
 
@@ -76,11 +138,13 @@ impl<T, X: Time, K: Keyframes<T, X> + PartialEq> PartialEq for SliceKeyframes<T,
 mod tests {
     use super::*;
     use crate::animation::keyframes_linear::LinearKeyframes;
+    use crate::keyframes;
 
     #[test]
     fn test_slice_keyframes() {
         let keyframes: LinearKeyframes<f32, f64> = LinearKeyframes::new(1.0, 5.0, 4.0);
         let keyframes = keyframes.slice(1.0, 3.0);
+        assert_eq!(keyframes.get(-1.0), 2.0);
         assert_eq!(keyframes.get(0.0), 2.0);
         assert_eq!(keyframes.get(1.0), 3.0);
         assert_eq!(keyframes.get(2.0), 4.0);
@@ -89,4 +153,39 @@ mod tests {
         assert_eq!(keyframes.get(5.0), 4.0);
         assert_eq!(keyframes.duration(), 2.0);
     }
+
+    #[test]
+    fn test_open_ended_slices() {
+        let line = keyframes::line::<f32, f32>(0.0, 4.0, 4.0);
+        let from = line.slice_from(1.0);
+        assert_eq!(from.duration(), 3.0);
+        assert_eq!(from.get(0.0), 1.0);
+        assert_eq!(from.get(5.0), 4.0);
+
+        let to = line.slice_to(1.0);
+        assert_eq!(to.duration(), 1.0);
+        assert_eq!(to.get(2.0), 1.0);
+
+        let repeat = line.repeat().slice_from(5.0);
+        assert!(!repeat.is_finite());
+        assert_eq!(repeat.get(0.0), 1.0);
+        assert_eq!(repeat.get(4.0), 1.0);
+
+        let window = line.repeat().slice(3.0, 6.0);
+        assert_eq!(window.duration(), 3.0);
+        assert_eq!(window.get(2.0), 1.0);
+        assert_eq!(window.end_value(), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slice start must not be after its end")]
+    fn test_slice_reversed_range() {
+        keyframes::line::<f32, f32>(0.0, 4.0, 4.0).slice(3.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slice must be within the keyframes")]
+    fn test_slice_out_of_range() {
+        keyframes::line::<f32, f32>(0.0, 4.0, 4.0).slice(1.0, 5.0);
+    }
 }
@@ -0,0 +1,204 @@
+use crate::{keyframes, BoxedKeyframes, Distance, Easing, Keyframes, Mix, Time, TimeDiff};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// A data description of keyframes, that can be stored in a config file and compiled at runtime.
+/// It implements `Serialize` and `Deserialize` if the `"serde"` feature is enabled.
+///
+/// Durations are numbers, they are multiplied by the `unit` duration passed to `compile`,
+/// so the same description can be used with any time type.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{Easing, Keyframes, KeyframesSpec};
+///
+/// let spec = KeyframesSpec::Sequence(vec![
+///     KeyframesSpec::Linear { from: 0.0, to: 1.0, duration: 1.0 },
+///     KeyframesSpec::Stay { value: 1.0, duration: 0.5 },
+///     KeyframesSpec::Ease { from: 1.0, to: 0.0, duration: 1.0, easing: Easing::Linear },
+/// ]);
+///
+/// let keyframes = spec.compile::<f64>(1000.0);
+/// assert_eq!(keyframes.duration(), 2500.0);
+/// assert_eq!(keyframes.get(500.0), 0.5);
+/// assert_eq!(keyframes.get(2000.0), 0.5);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyframesSpec<T> {
+    /// Stay at a single value, see `keyframes::stay`.
+    Stay { value: T, duration: f32 },
+    /// Linearly go from one value to another, see `keyframes::line`.
+    Linear { from: T, to: T, duration: f32 },
+    /// Go from one value to another with easing, see `keyframes::ease`.
+    Ease {
+        from: T,
+        to: T,
+        duration: f32,
+        #[cfg_attr(feature = "serde", serde(default))]
+        easing: Easing,
+    },
+    /// Go along a path, see `keyframes::poly`.
+    Poly {
+        points: Vec<T>,
+        duration: f32,
+        #[cfg_attr(feature = "serde", serde(default))]
+        easing: Easing,
+    },
+    /// Repeat keyframes indefinitely, see `Keyframes::repeat`.
+    Repeat(Box<KeyframesSpec<T>>),
+    /// Repeat keyframes `n` times, see `Keyframes::repeat_n`.
+    RepeatN {
+        keyframes: Box<KeyframesSpec<T>>,
+        n: f32,
+    },
+    /// Inverse keyframes order, see `Keyframes::reverse`.
+    Reverse(Box<KeyframesSpec<T>>),
+    /// Scale the time of keyframes, see `Keyframes::scale`.
+    Scale {
+        keyframes: Box<KeyframesSpec<T>>,
+        scale: f32,
+    },
+    /// Apply easing to keyframes, see `Keyframes::apply_easing`.
+    ApplyEasing {
+        keyframes: Box<KeyframesSpec<T>>,
+        easing: Easing,
+    },
+    /// Play keyframes one after another, see `Keyframes::then`. It should not be empty.
+    Sequence(Vec<KeyframesSpec<T>>),
+}
+
+impl<T: Mix + Distance + Clone + 'static> KeyframesSpec<T> {
+    /// Build runtime keyframes from the description.
+    /// Panics if a sequence is empty, or if a duration or a scale is negative.
+    /// * `unit` - The duration of `1.0` in the description, for example, `Duration::from_secs(1)`.
+    pub fn compile<X: Time + 'static>(&self, unit: X::Duration) -> BoxedKeyframes<T, X> {
+        match self {
+            KeyframesSpec::Stay { value, duration } => {
+                keyframes::stay(value.clone(), unit.scale(*duration)).boxed()
+            }
+            KeyframesSpec::Linear { from, to, duration } => {
+                keyframes::line(from.clone(), to.clone(), unit.scale(*duration)).boxed()
+            }
+            KeyframesSpec::Ease {
+                from,
+                to,
+                duration,
+                easing,
+            } => keyframes::ease(
+                from.clone(),
+                to.clone(),
+                unit.scale(*duration),
+                easing.clone(),
+            )
+            .boxed(),
+            KeyframesSpec::Poly {
+                points,
+                duration,
+                easing,
+            } => keyframes::poly(points.clone(), unit.scale(*duration), easing.clone()).boxed(),
+            KeyframesSpec::Repeat(keyframes) => keyframes.compile(unit).repeat().boxed(),
+            KeyframesSpec::RepeatN { keyframes, n } => keyframes.compile(unit).repeat_n(*n).boxed(),
+            KeyframesSpec::Reverse(keyframes) => keyframes.compile(unit).reverse().boxed(),
+            KeyframesSpec::Scale { keyframes, scale } => {
+                keyframes.compile(unit).scale(*scale).boxed()
+            }
+            KeyframesSpec::ApplyEasing { keyframes, easing } => {
+                keyframes.compile(unit).apply_easing(easing.clone()).boxed()
+            }
+            KeyframesSpec::Sequence(list) => list
+                .iter()
+                .map(|keyframes| keyframes.compile(unit))
+                .reduce(|a, b| a.then(b).boxed())
+                .expect("KeyframesSpec::compile: empty sequence"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn compile_with_duration_unit() {
+        let spec = KeyframesSpec::RepeatN {
+            keyframes: Box::new(KeyframesSpec::Linear {
+                from: 0.0,
+                to: 1.0,
+                duration: 0.5,
+            }),
+            n: 2.0,
+        };
+
+        let keyframes = spec.compile::<Instant>(Duration::from_secs(1));
+        assert_eq!(keyframes.duration(), Duration::from_secs(1));
+        assert_eq!(keyframes.get(Duration::from_millis(250)), 0.5);
+        assert_eq!(keyframes.get(Duration::from_millis(750)), 0.5);
+    }
+
+    #[test]
+    fn compile_poly() {
+        let spec = KeyframesSpec::Reverse(Box::new(KeyframesSpec::Poly {
+            points: vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)],
+            duration: 2.0,
+            easing: Easing::Linear,
+        }));
+
+        let keyframes = spec.compile::<f32>(1.0);
+        assert_eq!(keyframes.get(0.0), (1.0, 1.0));
+        assert_eq!(keyframes.get(1.0), (1.0, 0.0));
+        assert_eq!(keyframes.get(2.0), (0.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "empty sequence")]
+    fn compile_empty_sequence() {
+        KeyframesSpec::<f32>::Sequence(vec![]).compile::<f32>(1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let spec: KeyframesSpec<f32> = serde_json::from_str(
+            r#"{
+                "Sequence": [
+                    { "Ease": { "from": 0.0, "to": 1.0, "duration": 1.0 } },
+                    { "Stay": { "value": 1.0, "duration": 1.0 } },
+                    { "Ease": { "from": 1.0, "to": 0.0, "duration": 1.0, "easing": "Linear" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            spec,
+            KeyframesSpec::Sequence(vec![
+                KeyframesSpec::Ease {
+                    from: 0.0,
+                    to: 1.0,
+                    duration: 1.0,
+                    easing: Easing::QuadraticInOut,
+                },
+                KeyframesSpec::Stay {
+                    value: 1.0,
+                    duration: 1.0,
+                },
+                KeyframesSpec::Ease {
+                    from: 1.0,
+                    to: 0.0,
+                    duration: 1.0,
+                    easing: Easing::Linear,
+                },
+            ])
+        );
+
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(
+            serde_json::from_str::<KeyframesSpec<f32>>(&json).unwrap(),
+            spec
+        );
+    }
+}
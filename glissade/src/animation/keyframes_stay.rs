@@ -1,18 +1,20 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use crate::{Keyframes, Time};
-use std::fmt::Debug;
+use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
 
 /// An animation that stays at a single value.
 #[derive(Clone)]
 pub struct StayKeyframes<T: Clone, X: Time> {
-    value: T,
-    duration: X::Duration,
+    pub(crate) value: T,
+    pub(crate) duration: X::Duration,
 }
 
 impl<T: Clone + Debug, X: Time> Debug for StayKeyframes<T, X>
 where
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("NoneKeyframes")
             .field("value", &self.value)
             .field("duration", &self.duration)
@@ -26,6 +28,18 @@ impl<T: Clone + PartialEq, X: Time> PartialEq for StayKeyframes<T, X> {
     }
 }
 
+impl<T: Clone + Eq, X: Time> Eq for StayKeyframes<T, X> where X::Duration: Eq {}
+
+impl<T: Clone + Hash, X: Time> Hash for StayKeyframes<T, X>
+where
+    X::Duration: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.duration.hash(state);
+    }
+}
+
 impl<T: Clone, X: Time> StayKeyframes<T, X> {
     pub fn new(value: T, duration: X::Duration) -> Self {
         Self { value, duration }
@@ -44,6 +58,10 @@ impl<T: Clone, X: Time> Keyframes<T, X> for StayKeyframes<T, X> {
     fn is_finite(&self) -> bool {
         true
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Stay, Some(self.duration))
+    }
 }
 
 impl<T: Clone + Copy, X: Time> Copy for StayKeyframes<T, X> {}
@@ -1,10 +1,14 @@
 use super::animation_struct::Animation;
+use super::discontinuity::{Discontinuity, DiscontinuityKind};
+use super::keyframes_boxed::BoxedKeyframes;
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
 use super::keyframes_easing::EasingKeyframes;
+use super::keyframes_finite_or::FiniteOrKeyframes;
 use super::keyframes_linear::LinearKeyframes;
-use super::keyframes_repeat::RepeatKeyframes;
+use super::keyframes_repeat::{RepeatError, RepeatKeyframes};
 use super::keyframes_repeat_n::RepeatNKeyframes;
 use super::keyframes_reverse::ReverseKeyframes;
-use super::keyframes_scale::ScaleKeyframes;
+use super::keyframes_scale::{ScaleError, ScaleKeyframes};
 use super::keyframes_sequential::SequentialKeyframes;
 use super::keyframes_stay::StayKeyframes;
 use crate::animation::keyframes_apply_easing::ApplyEasingKeyframes;
@@ -12,8 +16,15 @@ use crate::animation::keyframes_function::FunctionKeyframes;
 use crate::animation::keyframes_map::MapKeyframes;
 use crate::animation::keyframes_poly::PolyKeyframes;
 use crate::animation::keyframes_slice::SliceKeyframes;
-use crate::{Distance, Easing, Mix, Time};
-use std::iter::once;
+use crate::math::ceil;
+use crate::{Distance, Easing, Finite, Mix, Time, TimeDiff};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::once;
+
+/// Sampling step around joins in `validate_continuity`, relative to the last join offset.
+const CONTINUITY_STEP: f32 = 1e-3;
 
 /// A transition of a value over time. It works like an animation template, or set of keyframes.
 pub trait Keyframes<T, X: Time> {
@@ -22,7 +33,7 @@ pub trait Keyframes<T, X: Time> {
     fn get(&self, offset: X::Duration) -> T;
 
     /// Get the duration of the animation.
-    /// If the animation is infinite, it will panic.
+    /// If the animation is infinite, it will panic, see `try_duration`.
     fn duration(&self) -> X::Duration;
 
     /// Check if the animation is finished at the given offset.
@@ -39,11 +50,119 @@ pub trait Keyframes<T, X: Time> {
     }
 
     /// Get the value of the animation at the end.
-    /// If the animation is infinite, it will panic.
+    /// If the animation is infinite, it will panic, see `try_end_value`.
     fn end_value(&self) -> T {
         self.get(self.duration())
     }
 
+    /// Get values at several offsets. Keyframes with many segments (like `FlatKeyframes`)
+    /// override it to walk the segments instead of looking up each offset from scratch,
+    /// it's the fastest when offsets are sorted.
+    fn get_many(&self, offsets: &[X::Duration]) -> Vec<T> {
+        offsets.iter().map(|offset| self.get(*offset)).collect()
+    }
+
+    /// Same as `get`, but returns `None` if the offset is out of the animation,
+    /// instead of clamping it: negative, after the end of finite keyframes, or not comparable (e.g. NaN).
+    fn try_get(&self, offset: X::Duration) -> Option<T> {
+        if matches!(
+            offset.partial_cmp(&Default::default()),
+            None | Some(Ordering::Less)
+        ) || self
+            .try_duration()
+            .is_some_and(|duration| offset > duration)
+        {
+            None
+        } else {
+            Some(self.get(offset))
+        }
+    }
+
+    /// Same as `duration`, but returns `None` if the animation is infinite.
+    fn try_duration(&self) -> Option<X::Duration> {
+        if self.is_finite() {
+            Some(self.duration())
+        } else {
+            None
+        }
+    }
+
+    /// Get offsets where segments of the keyframes meet, in ascending order.
+    /// Combinators translate joins of the keyframes they wrap, leaf keyframes have none.
+    fn joins(&self) -> Vec<X::Duration> {
+        Vec::new()
+    }
+
+    /// Describe the structure of the keyframes: kinds of segments and combinators, durations, and easings.
+    /// Combinators describe the keyframes they wrap, keyframes that don't override it are `Custom` leaves.
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Custom, self.try_duration())
+    }
+
+    /// Get the cycle duration of repeating keyframes, cycles start at offset zero.
+    /// It's `None` if the keyframes don't repeat, or the cycle is unknown.
+    fn period(&self) -> Option<X::Duration> {
+        None
+    }
+
+    /// Check that the keyframes are continuous at their joins, e.g. in tests of long hand-built chains.
+    /// Values are sampled around each join, and jumps bigger than `epsilon` are reported:
+    /// value jumps (C0) as a distance, and velocity jumps (C1) per `TimeDiff::as_f32` unit.
+    /// Velocity is estimated numerically, so `epsilon` shouldn't be smaller than about `0.01`.
+    fn validate_continuity(&self, epsilon: f32) -> Vec<Discontinuity<X::Duration>>
+    where
+        T: Mix + Distance + Clone,
+    {
+        let joins = self.joins();
+        let Some(last) = joins.last() else {
+            return Vec::new();
+        };
+        let step = last.scale(CONTINUITY_STEP);
+        if step == Default::default() {
+            return Vec::new();
+        }
+
+        let duration = self.try_duration();
+        joins
+            .into_iter()
+            // Joins at the edges (e.g. after `keyframes::from`) can't be sampled from both sides.
+            .filter(|offset| {
+                *offset >= step && duration.is_none_or(|duration| *offset + step <= duration)
+            })
+            .filter_map(|offset| {
+                let before = self.get(offset - step);
+                let at = self.get(offset);
+                let after = self.get(offset + step);
+
+                let jump = before.clone().distance(at.clone());
+                if jump > epsilon {
+                    return Some(Discontinuity {
+                        offset,
+                        kind: DiscontinuityKind::Value,
+                        jump,
+                    });
+                }
+
+                let middle = before.mix(after, 0.5);
+                let jump = middle.distance(at) * 2.0 / step.as_f32();
+                (jump > epsilon).then_some(Discontinuity {
+                    offset,
+                    kind: DiscontinuityKind::Velocity,
+                    jump,
+                })
+            })
+            .collect()
+    }
+
+    /// Same as `end_value`, but returns `None` if the animation is infinite.
+    fn try_end_value(&self) -> Option<T> {
+        if self.is_finite() {
+            Some(self.end_value())
+        } else {
+            None
+        }
+    }
+
     /// Create an animation that stays at the end value for the given duration.
     fn stay(self, duration: X::Duration) -> SequentialKeyframes<T, X, Self, StayKeyframes<T, X>>
     where
@@ -114,6 +233,8 @@ pub trait Keyframes<T, X: Time> {
     }
 
     /// Create an animation that repeats the given keyframes indefinitely.
+    /// Each cycle includes its end: at multiples of the duration the end value is returned,
+    /// only the offset zero returns the start value.
     fn repeat(self) -> RepeatKeyframes<T, X, Self>
     where
         Self: Sized,
@@ -121,7 +242,18 @@ pub trait Keyframes<T, X: Time> {
         RepeatKeyframes::new(self)
     }
 
+    /// Same as `repeat`, but fails if the keyframes have zero duration,
+    /// instead of producing an animation that is finished immediately.
+    fn try_repeat(self) -> Result<RepeatKeyframes<T, X, Self>, RepeatError>
+    where
+        Self: Sized,
+    {
+        RepeatKeyframes::try_new(self)
+    }
+
     /// Create an animation that repeats the given keyframes n times.
+    /// Boundaries between cycles return the end value, the same as `repeat`.
+    /// It panics if `n` is negative, NaN or infinite.
     /// * `n` - The number of times to repeat the keyframes. It can be not integer, and repeat the keyframes partially.
     fn repeat_n(self, n: f32) -> RepeatNKeyframes<T, X, Self>
     where
@@ -130,7 +262,16 @@ pub trait Keyframes<T, X: Time> {
         RepeatNKeyframes::new(self, n)
     }
 
+    /// Same as `repeat_n`, but fails if `n` is negative, NaN or infinite.
+    fn try_repeat_n(self, n: f32) -> Result<RepeatNKeyframes<T, X, Self>, RepeatError>
+    where
+        Self: Sized,
+    {
+        RepeatNKeyframes::try_new(self, n)
+    }
+
     /// Inverse keyframes order.
+    /// It panics if the keyframes are infinite, use `reverse_window` for them.
     fn reverse(self) -> ReverseKeyframes<T, X, Self>
     where
         Self: Sized,
@@ -138,7 +279,19 @@ pub trait Keyframes<T, X: Time> {
         ReverseKeyframes::new(self)
     }
 
+    /// Inverse the order of the first `duration` of keyframes, they can be infinite.
+    fn reverse_window(
+        self,
+        duration: X::Duration,
+    ) -> ReverseKeyframes<T, X, SliceKeyframes<T, X, Self>>
+    where
+        Self: Sized,
+    {
+        self.slice_to(duration).reverse()
+    }
+
     /// Scale the time of the animation by the given factor.
+    /// It panics if the factor is negative, NaN or infinite, zero makes the keyframes take no time.
     fn scale(self, scale: f32) -> ScaleKeyframes<T, X, Self>
     where
         Self: Sized,
@@ -146,15 +299,28 @@ pub trait Keyframes<T, X: Time> {
         ScaleKeyframes::new(self, scale)
     }
 
+    /// Same as `scale`, but fails if the factor is negative, NaN or infinite.
+    fn try_scale(self, scale: f32) -> Result<ScaleKeyframes<T, X, Self>, ScaleError>
+    where
+        Self: Sized,
+    {
+        ScaleKeyframes::try_new(self, scale)
+    }
+
     /// Scale the time of the animation to the given duration.
+    /// It panics if the keyframes are infinite, or the resulting scale is too large.
     fn scale_to(self, new_duration: X::Duration) -> ScaleKeyframes<T, X, Self>
     where
         Self: Sized,
     {
+        assert!(
+            self.is_finite(),
+            "Infinite keyframes can't be scaled to a duration"
+        );
         let scale = if self.duration() == Default::default() {
             1.0
         } else {
-            X::duration_as_f32(new_duration) / X::duration_as_f32(self.duration())
+            (new_duration.as_f64() / self.duration().as_f64()) as f32
         };
 
         ScaleKeyframes::new(self, scale)
@@ -177,7 +343,8 @@ pub trait Keyframes<T, X: Time> {
         SequentialKeyframes::new(self, other)
     }
 
-    /// Get a slice of the keyframes from the start to the end.
+    /// Get a slice of the keyframes from the start to the end, offsets out of it are cropped.
+    /// It panics if the start is after the end, or the range is out of finite keyframes.
     fn slice(self, start_offset: X::Duration, end_offset: X::Duration) -> SliceKeyframes<T, X, Self>
     where
         Self: Sized,
@@ -185,6 +352,32 @@ pub trait Keyframes<T, X: Time> {
         SliceKeyframes::new(self, (start_offset, end_offset))
     }
 
+    /// Get a slice of the keyframes from the start offset until their end, they can be infinite.
+    fn slice_from(self, start_offset: X::Duration) -> SliceKeyframes<T, X, Self>
+    where
+        Self: Sized,
+    {
+        SliceKeyframes::from_start(self, start_offset)
+    }
+
+    /// Get a slice of the keyframes from the beginning to the end offset.
+    fn slice_to(self, end_offset: X::Duration) -> SliceKeyframes<T, X, Self>
+    where
+        Self: Sized,
+    {
+        SliceKeyframes::new(self, (Default::default(), end_offset))
+    }
+
+    /// Replace values with NaN or infinite components by `default`,
+    /// so a single bad value (e.g. from a custom function) doesn't corrupt the rest of a pipeline.
+    fn finite_or(self, default: T) -> FiniteOrKeyframes<T, X, Self>
+    where
+        T: Finite + Clone,
+        Self: Sized,
+    {
+        FiniteOrKeyframes::new(self, default)
+    }
+
     fn map<R, F>(self, f: F) -> MapKeyframes<T, R, X, Self, F>
     where
         F: Fn(T) -> R,
@@ -193,6 +386,49 @@ pub trait Keyframes<T, X: Time> {
         MapKeyframes::new(self, f)
     }
 
+    /// Sample values with a fixed interval into a flat buffer, e.g. to upload it to the GPU,
+    /// so shaders can evaluate the same animation. The last sample is taken exactly at the end.
+    /// It panics if the keyframes are infinite, or the interval isn't positive.
+    fn bake_channels<const N: usize>(&self, interval: X::Duration) -> Vec<[f32; N]>
+    where
+        T: Into<[f32; N]>,
+        Self: Sized,
+    {
+        let duration = self.duration();
+        let count = sample_count(duration, interval);
+        (0..=count)
+            .map(|i| self.get(sample_offset(i, count, duration, interval)).into())
+            .collect()
+    }
+
+    /// Sample values with a fixed interval on all cores, for heavy offline baking.
+    /// Offsets are the same as in `bake_channels`.
+    /// It panics if the keyframes are infinite, or the interval isn't positive.
+    #[cfg(feature = "rayon")]
+    fn samples_par(&self, interval: X::Duration) -> Vec<T>
+    where
+        T: Send,
+        X::Duration: Send + Sync,
+        Self: Sized + Sync,
+    {
+        use rayon::prelude::*;
+
+        let duration = self.duration();
+        let count = sample_count(duration, interval);
+        (0..=count)
+            .into_par_iter()
+            .map(|i| self.get(sample_offset(i, count, duration, interval)))
+            .collect()
+    }
+
+    /// Erase the keyframes type, to build keyframes at runtime or store them in a collection.
+    fn boxed(self) -> BoxedKeyframes<T, X>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+
     /// Run keyframes at a specific time.
     /// * `start_time` - The time to start the transition, usually `Instant::now()`.
     fn run(self, start_time: X) -> Animation<T, X, Self>
@@ -201,6 +437,36 @@ pub trait Keyframes<T, X: Time> {
     {
         Animation::start(self, start_time)
     }
+
+    /// Same as `run`, but returns `None` if the end time of finite keyframes can't be represented,
+    /// so `Animation::end_time` can't panic.
+    fn try_run(self, start_time: X) -> Option<Animation<T, X, Self>>
+    where
+        Self: Sized,
+    {
+        if let Some(duration) = self.try_duration() {
+            start_time.checked_advance(duration)?;
+        }
+        Some(self.run(start_time))
+    }
+}
+
+/// Get the number of intervals to sample, the last one can be shorter.
+fn sample_count<D: TimeDiff>(duration: D, interval: D) -> usize {
+    assert!(
+        interval > Default::default(),
+        "Bake interval must be positive"
+    );
+    ceil(duration.as_f32() / interval.as_f32()) as usize
+}
+
+/// Get the offset of the sample, the last one is exactly at the end.
+fn sample_offset<D: TimeDiff>(i: usize, count: usize, duration: D, interval: D) -> D {
+    if i == count {
+        duration
+    } else {
+        interval.scale(i as f32)
+    }
 }
 
 fn max<X: PartialOrd>(v1: X, v2: X) -> X {
@@ -227,6 +493,11 @@ where
     fn is_finite(&self) -> bool {
         self.0.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Tuple, self.try_duration())
+            .with_children([self.0.describe()])
+    }
 }
 
 impl<X, T1, T2, K1, K2> Keyframes<(T1, T2), X> for (K1, K2)
@@ -246,6 +517,11 @@ where
     fn is_finite(&self) -> bool {
         self.0.is_finite() && self.1.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Tuple, self.try_duration())
+            .with_children([self.0.describe(), self.1.describe()])
+    }
 }
 
 impl<X, T1, T2, T3, K1, K2, K3> Keyframes<(T1, T2, T3), X> for (K1, K2, K3)
@@ -269,6 +545,14 @@ where
     fn is_finite(&self) -> bool {
         self.0.is_finite() && self.1.is_finite() && self.2.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Tuple, self.try_duration()).with_children([
+            self.0.describe(),
+            self.1.describe(),
+            self.2.describe(),
+        ])
+    }
 }
 
 impl<X, T1, T2, T3, T4, K1, K2, K3, K4> Keyframes<(T1, T2, T3, T4), X> for (K1, K2, K3, K4)
@@ -299,6 +583,15 @@ where
     fn is_finite(&self) -> bool {
         self.0.is_finite() && self.1.is_finite() && self.2.is_finite() && self.3.is_finite()
     }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        KeyframesDescription::new(KeyframesKind::Tuple, self.try_duration()).with_children([
+            self.0.describe(),
+            self.1.describe(),
+            self.2.describe(),
+            self.3.describe(),
+        ])
+    }
 }
 
 /// Start `Animation` constructing with this module.
@@ -332,20 +625,27 @@ where
 /// assert_eq!(transition.get(Duration::from_secs(74)), 9.0);
 /// ```
 pub mod keyframes {
-    use super::Keyframes;
+    use crate::animation::keyframes_anticipate::AnticipateOvershootKeyframes;
     use crate::animation::keyframes_easing::EasingKeyframes;
     use crate::animation::keyframes_function::FunctionKeyframes;
+    use crate::animation::keyframes_group::GroupKeyframes;
     use crate::animation::keyframes_linear::LinearKeyframes;
+    use crate::animation::keyframes_orbit::OrbitKeyframes;
+    use crate::animation::keyframes_oscillator::{OscillatorKeyframes, Waveform};
     use crate::animation::keyframes_poly::PolyKeyframes;
+    use crate::animation::keyframes_shake::ShakeKeyframes;
     use crate::animation::keyframes_stay::StayKeyframes;
-    use crate::{Distance, Easing, Mix, Time};
+    use crate::animation::keyframes_typewriter::TypewriterKeyframes;
+    use crate::{Distance, Easing, Keyframes, Mix, Time};
+    use alloc::string::String;
+    use alloc::vec::Vec;
 
-    pub fn from<T: Clone, X: Time>(point: T) -> impl Keyframes<T, X> {
+    pub fn from<T: Clone, X: Time>(point: T) -> StayKeyframes<T, X> {
         stay(point, Default::default())
     }
 
     /// Create a new keyframes that stays at a single value.
-    pub fn stay<T: Clone, X: Time>(value: T, duration: X::Duration) -> impl Keyframes<T, X> {
+    pub fn stay<T: Clone, X: Time>(value: T, duration: X::Duration) -> StayKeyframes<T, X> {
         StayKeyframes::new(value, duration)
     }
 
@@ -354,7 +654,7 @@ pub mod keyframes {
         start: T,
         end: T,
         duration: X::Duration,
-    ) -> impl Keyframes<T, X> {
+    ) -> LinearKeyframes<T, X> {
         LinearKeyframes::new(start, end, duration)
     }
 
@@ -364,27 +664,164 @@ pub mod keyframes {
         end: T,
         duration: X::Duration,
         easing: Easing,
-    ) -> impl Keyframes<T, X> {
+    ) -> EasingKeyframes<T, X> {
         EasingKeyframes::new(start, end, duration, easing)
     }
 
+    /// Create a new keyframes that pulls back from `start`, shoots past `end`, and settles at `end`.
+    /// `anticipation` and `overshoot` are parts of the distance between the values, so `T` should
+    /// mix beyond `[0, 1]` like floats do. See `AnticipateOvershootKeyframes`.
+    pub fn anticipate_overshoot<T: Mix + Clone, X: Time>(
+        start: T,
+        end: T,
+        duration: X::Duration,
+        anticipation: f32,
+        overshoot: f32,
+    ) -> AnticipateOvershootKeyframes<T, X> {
+        AnticipateOvershootKeyframes::new(start, end, duration, anticipation, overshoot)
+    }
+
     /// Create a new keyframes that goes along a path.
     pub fn poly<T: Mix + Distance + Clone, X: Time>(
         points: Vec<T>,
         duration: X::Duration,
         easing: Easing,
-    ) -> impl Keyframes<T, X> {
+    ) -> PolyKeyframes<T, X> {
         PolyKeyframes::new(points, duration, easing)
     }
 
     /// Create a new keyframes that goes along functionally defined path.
-    pub fn function<T, X, F>(f: F, duration: X::Duration) -> impl Keyframes<T, X>
+    pub fn function<T, X, F>(f: F, duration: X::Duration) -> FunctionKeyframes<T, X, F>
     where
         X: Time,
         F: Fn(X::Duration) -> T,
     {
         FunctionKeyframes::new(f, duration)
     }
+
+    /// Run keyframes side by side until all of them end, see `GroupKeyframes::all`.
+    pub fn all<T, X: Time, K: Keyframes<T, X>>(
+        items: impl IntoIterator<Item = K>,
+    ) -> GroupKeyframes<T, X, K> {
+        GroupKeyframes::all(items)
+    }
+
+    /// Run keyframes side by side until the first of them ends, see `GroupKeyframes::race`.
+    pub fn race<T, X: Time, K: Keyframes<T, X>>(
+        items: impl IntoIterator<Item = K>,
+    ) -> GroupKeyframes<T, X, K> {
+        GroupKeyframes::race(items)
+    }
+
+    /// Run keyframes side by side, each next one starts `delay` later, see `GroupKeyframes::staggered`.
+    pub fn staggered<T, X: Time, K: Keyframes<T, X>>(
+        items: impl IntoIterator<Item = K>,
+        delay: X::Duration,
+    ) -> GroupKeyframes<T, X, K> {
+        GroupKeyframes::staggered(items, delay)
+    }
+
+    /// Create infinite keyframes that oscillate between `amplitude.0` and `amplitude.1` as a sine wave,
+    /// e.g. for pulsing or breathing effects. `frequency` is in cycles per time unit, a second for `Duration`,
+    /// and `phase` is a part of the cycle to start at. See `Waveform` for the shapes of cycles.
+    pub fn sine<T: Mix + Clone, X: Time>(
+        amplitude: (T, T),
+        frequency: f32,
+        phase: f32,
+    ) -> OscillatorKeyframes<T, X> {
+        OscillatorKeyframes::new(amplitude.0, amplitude.1, frequency, phase, Waveform::Sine)
+    }
+
+    /// Same as `sine`, but oscillates as a square wave, e.g. for blinking.
+    pub fn square<T: Mix + Clone, X: Time>(
+        amplitude: (T, T),
+        frequency: f32,
+        phase: f32,
+    ) -> OscillatorKeyframes<T, X> {
+        OscillatorKeyframes::new(amplitude.0, amplitude.1, frequency, phase, Waveform::Square)
+    }
+
+    /// Same as `sine`, but oscillates as a triangle wave.
+    pub fn triangle<T: Mix + Clone, X: Time>(
+        amplitude: (T, T),
+        frequency: f32,
+        phase: f32,
+    ) -> OscillatorKeyframes<T, X> {
+        OscillatorKeyframes::new(
+            amplitude.0,
+            amplitude.1,
+            frequency,
+            phase,
+            Waveform::Triangle,
+        )
+    }
+
+    /// Same as `sine`, but oscillates as a sawtooth wave.
+    pub fn sawtooth<T: Mix + Clone, X: Time>(
+        amplitude: (T, T),
+        frequency: f32,
+        phase: f32,
+    ) -> OscillatorKeyframes<T, X> {
+        OscillatorKeyframes::new(
+            amplitude.0,
+            amplitude.1,
+            frequency,
+            phase,
+            Waveform::Sawtooth,
+        )
+    }
+
+    /// Create keyframes that shake randomly between `amplitude.0` and `amplitude.1`, starting and ending
+    /// in the middle, e.g. for camera shakes or a rejected input. Put the current value in the middle
+    /// to shake around it. `frequency` is in random targets per time unit, a second for `Duration`,
+    /// and the amplitude falls as `(1 - progress) ^ decay`. Use `ShakeKeyframes::with_seed` for different shakes.
+    pub fn shake<T: Mix + Clone, X: Time>(
+        amplitude: (T, T),
+        frequency: f32,
+        duration: X::Duration,
+        decay: f32,
+    ) -> ShakeKeyframes<T, X> {
+        ShakeKeyframes::new(amplitude.0, amplitude.1, frequency, duration, decay)
+    }
+
+    /// Create keyframes that type `text` character by character, spending `char_duration` per character,
+    /// e.g. `Duration::from_millis(50)` for 20 characters per second. The value is the typed part of the text.
+    /// See `TypewriterKeyframes` for easing and a blinking cursor.
+    pub fn typewriter<X: Time>(
+        text: impl Into<String>,
+        char_duration: X::Duration,
+    ) -> TypewriterKeyframes<X> {
+        TypewriterKeyframes::new(text, char_duration)
+    }
+
+    /// Create infinite keyframes that go around `center` in a circle, one revolution per `period`,
+    /// e.g. for loading spinners or ambient motion. It starts at `center + (radius, 0)`,
+    /// and goes towards `center + (0, radius)`, i.e. clockwise on screens with the y-axis pointing down.
+    /// See `OrbitKeyframes::new` for orbits of other point types, e.g. tilted 3D ones.
+    pub fn orbit<T: Mix + Clone + From<(f32, f32)>, X: Time>(
+        center: T,
+        radius: f32,
+        period: X::Duration,
+    ) -> OrbitKeyframes<T, X> {
+        ellipse(center, (radius, radius), period)
+    }
+
+    /// Same as `orbit`, but the motion goes along an ellipse with the given radii along the x and y axes.
+    pub fn ellipse<T: Mix + Clone + From<(f32, f32)>, X: Time>(
+        center: T,
+        radii: (f32, f32),
+        period: X::Duration,
+    ) -> OrbitKeyframes<T, X> {
+        // `center + offset`, computed with mixes, as the point type is only known to be affine.
+        let origin = T::from((0.0, 0.0));
+        let offset = |x: f32, y: f32| {
+            let middle = center.clone().mix(T::from((x, y)), 0.5);
+            origin.clone().mix(middle, 2.0)
+        };
+        let x_axis = offset(radii.0, 0.0);
+        let y_axis = offset(0.0, radii.1);
+        OrbitKeyframes::new(center, x_axis, y_axis, period)
+    }
 }
 
 //----------------------------------------------------------------
@@ -395,6 +832,9 @@ mod tests {
     use super::*;
     use crate::easing::Easing;
     use crate::mix::Mix;
+    use crate::{BakedAnimation, FlatKeyframes};
+    use std::cell::Cell;
+    use std::rc::Rc;
     use std::time::{Duration, Instant};
 
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -467,6 +907,35 @@ mod tests {
         assert_eq!(keyframes.get(ONE_SECOND), TestItem(0.0));
     }
 
+    #[test]
+    fn reverse_window() {
+        let keyframes = keyframes::line::<f32, f32>(0.0, 1.0, 1.0)
+            .repeat()
+            .reverse_window(1.5);
+
+        assert_eq!(keyframes.duration(), 1.5);
+        assert_eq!(keyframes.get(0.0), 0.5);
+        assert_eq!(keyframes.get(0.25), 0.25);
+        assert_eq!(keyframes.get(1.0), 0.5);
+        assert_eq!(keyframes.get(1.5), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Infinite keyframes can't be reversed")]
+    fn reverse_infinite() {
+        keyframes::line::<f32, f32>(0.0, 1.0, 1.0)
+            .repeat()
+            .reverse();
+    }
+
+    #[test]
+    #[should_panic(expected = "Infinite keyframes can't be scaled")]
+    fn scale_to_infinite() {
+        keyframes::line::<f32, f32>(0.0, 1.0, 1.0)
+            .repeat()
+            .scale_to(2.0);
+    }
+
     #[test]
     fn map_keyframes() {
         let keyframes = keyframes::from::<f32, Instant>(0.0)
@@ -489,4 +958,210 @@ mod tests {
         assert_eq!(keyframes.get(ONE_SECOND), 0.5);
         assert_eq!(keyframes.get(ONE_SECOND * 2), 1.0);
     }
+
+    #[test]
+    fn bake_channels() {
+        let keyframes = keyframes::from::<f32, Instant>(0.0)
+            .go_to(1.0, ONE_SECOND)
+            .map(|v| [v, 1.0 - v]);
+
+        assert_eq!(
+            keyframes.bake_channels(Duration::from_millis(400)),
+            vec![[0.0, 1.0], [0.4, 0.6], [0.8, 0.19999999], [1.0, 0.0]]
+        );
+    }
+
+    /// A value that counts its clones, and doesn't clone in `mix_ref`.
+    #[derive(Debug, PartialEq)]
+    struct Heavy(Vec<f32>, Rc<Cell<usize>>);
+
+    impl Clone for Heavy {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            Heavy(self.0.clone(), self.1.clone())
+        }
+    }
+
+    impl Mix for Heavy {
+        fn mix(self, other: Self, t: f32) -> Self {
+            self.mix_ref(&other, t)
+        }
+
+        fn mix_ref(&self, other: &Self, t: f32) -> Self {
+            let values = self.0.iter().zip(&other.0).map(|(a, b)| a.mix(*b, t));
+            Heavy(values.collect(), self.1.clone())
+        }
+    }
+
+    #[test]
+    fn mix_without_clones() {
+        let clones = Rc::new(Cell::new(0));
+        let keyframes = keyframes::line::<Heavy, Instant>(
+            Heavy(vec![0.0, 2.0], clones.clone()),
+            Heavy(vec![2.0, 0.0], clones.clone()),
+            ONE_SECOND,
+        );
+
+        assert_eq!(keyframes.get(HALF_SECOND).0, vec![1.0, 1.0]);
+        assert_eq!(clones.get(), 0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn samples_par() {
+        let keyframes =
+            keyframes::from::<f32, f32>(0.0)
+                .go_to(1.0, 1.0)
+                .ease_to(0.0, 1.0, Easing::QuadraticIn);
+
+        let samples = keyframes.samples_par(0.1);
+        assert_eq!(samples.len(), 21);
+        for (i, value) in samples.into_iter().enumerate() {
+            assert_eq!(value, keyframes.get(0.1 * i as f32));
+        }
+    }
+
+    #[test]
+    fn try_methods() {
+        let keyframes = keyframes::line::<f32, f32>(0.0, 1.0, 1.0);
+        assert_eq!(keyframes.try_get(0.5), Some(0.5));
+        assert_eq!(keyframes.try_get(-0.5), None);
+        assert_eq!(keyframes.try_get(1.5), None);
+        assert_eq!(keyframes.try_get(f32::NAN), None);
+        assert_eq!(keyframes.try_duration(), Some(1.0));
+        assert_eq!(keyframes.try_end_value(), Some(1.0));
+
+        let repeat = keyframes.repeat();
+        assert_eq!(repeat.try_get(10.5), Some(0.5));
+        assert_eq!(repeat.try_duration(), None);
+        assert_eq!(repeat.try_end_value(), None);
+
+        assert!(keyframes::stay::<f32, u32>(0.0, 10)
+            .try_run(u32::MAX)
+            .is_none());
+        let animation = keyframes::stay::<f32, u32>(0.0, 10).try_run(5).unwrap();
+        assert_eq!(animation.try_end_time(), Some(15));
+        assert_eq!(repeat.run(0.0).try_end_time(), None);
+    }
+
+    #[test]
+    fn get_never_panics() {
+        let ticks = keyframes::line::<f32, u32>(0.0, 10.0, 10);
+        assert_eq!(ticks.reverse().get(20), 0.0);
+        assert_eq!(ticks.scale(0.0).get(1), 10.0);
+
+        let repeat = keyframes::line::<f32, f32>(0.0, 1.0, 1.0).repeat();
+        assert_eq!(repeat.scale(0.0).get(1.0), 0.0);
+
+        let empty = keyframes::poly::<f32, f32>(vec![0.0, 1.0], 0.0, Easing::Linear);
+        assert_eq!(empty.get(0.0), 1.0);
+        assert_eq!(empty.apply_easing(Easing::QuadraticIn).get(0.0), 1.0);
+    }
+
+    #[test]
+    fn zero_duration_segments_jump() {
+        let chain = keyframes::from::<f32, f32>(0.0)
+            .go_to(1.0, 0.0)
+            .ease_to(2.0, 0.0, Easing::CubicInOut)
+            .poly_to(vec![2.5, 3.0], 0.0, Easing::Linear)
+            .go_to(4.0, 1.0);
+        let flat: FlatKeyframes<f32, f32> = chain.clone().into();
+        let baked = BakedAnimation::new(&chain, 0.25);
+
+        let combinators: [BoxedKeyframes<f32, f32>; 4] = [
+            chain.clone().boxed(),
+            flat.boxed(),
+            baked.boxed(),
+            chain.clone().slice(0.0, 1.0).boxed(),
+        ];
+        for keyframes in combinators {
+            assert_eq!(keyframes.get(0.0), 3.0);
+            assert_eq!(keyframes.get(0.5), 3.5);
+            assert_eq!(keyframes.end_value(), 4.0);
+        }
+
+        let jumps: [BoxedKeyframes<f32, f32>; 9] = [
+            keyframes::line(0.0, 1.0, 0.0).boxed(),
+            keyframes::ease(0.0, 1.0, 0.0, Easing::QuarticOut).boxed(),
+            keyframes::poly(vec![0.0, 1.0], 0.0, Easing::Linear).boxed(),
+            keyframes::line(0.0, 1.0, 0.0).reverse().boxed(),
+            keyframes::line(0.0, 1.0, 0.0).scale(2.0).boxed(),
+            keyframes::line(0.0, 1.0, 0.0).scale_to(1.0).boxed(),
+            keyframes::line(0.0, 1.0, 0.0).repeat_n(2.0).boxed(),
+            keyframes::line(0.0, 1.0, 0.0).repeat().boxed(),
+            keyframes::line(0.0, 1.0, 0.0)
+                .apply_easing(Easing::QuadraticIn)
+                .boxed(),
+        ];
+        for keyframes in jumps {
+            assert_eq!(keyframes.duration(), 0.0);
+            for offset in [0.0, 0.5, 10.0] {
+                assert_eq!(keyframes.get(offset), 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn validate_continuity() {
+        let smooth = keyframes::from::<f32, f32>(0.0)
+            .ease_to(1.0, 1.0, Easing::QuadraticIn)
+            .go_to(3.0, 1.0)
+            .ease_to(4.0, 1.0, Easing::QuadraticOut)
+            .stay(1.0);
+        assert_eq!(smooth.joins(), vec![0.0, 1.0, 2.0, 3.0]);
+        assert!(smooth.validate_continuity(0.05).is_empty());
+
+        let broken = keyframes::from::<f32, f32>(0.0)
+            .go_to(1.0, 1.0)
+            .go_to(3.0, 1.0)
+            .then(keyframes::line(5.0, 6.0, 1.0));
+        let discontinuities = broken.validate_continuity(0.05);
+        assert_eq!(discontinuities.len(), 2);
+        assert_eq!(discontinuities[0].offset, 1.0);
+        assert_eq!(discontinuities[0].kind, DiscontinuityKind::Velocity);
+        assert!((discontinuities[0].jump - 1.0).abs() < 0.01);
+        assert_eq!(discontinuities[1].offset, 2.0);
+        assert_eq!(discontinuities[1].kind, DiscontinuityKind::Value);
+        assert!((discontinuities[1].jump - 2.0).abs() < 0.01);
+
+        let flat: FlatKeyframes<f32, f32> = broken.into();
+        assert_eq!(flat.validate_continuity(0.05), discontinuities);
+        assert_eq!(broken.reverse().joins(), vec![1.0, 2.0, 3.0]);
+        assert_eq!(broken.scale(2.0).joins(), vec![0.0, 2.0, 4.0]);
+        assert_eq!(broken.slice_from(1.5).joins(), vec![0.5]);
+        assert_eq!(
+            keyframes::line::<f32, f32>(0.0, 1.0, 1.0)
+                .go_to(0.0, 1.0)
+                .repeat_n(1.5)
+                .joins(),
+            vec![1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn equality() {
+        fn chain(to: f32) -> impl Keyframes<f32, f32> + PartialEq {
+            keyframes::from::<f32, f32>(0.0)
+                .go_to(to, 1.0)
+                .ease_to(0.0, 1.0, Easing::QuadraticIn)
+                .scale(2.0)
+                .repeat_n(2.0)
+                .map(f32::abs as fn(f32) -> f32)
+        }
+        assert!(chain(1.0) == chain(1.0));
+        assert!(chain(1.0) != chain(2.0));
+
+        let flat: FlatKeyframes<f32, f32> = keyframes::from(0.0).go_to(1.0, 1.0).into();
+        assert_eq!(flat, flat.clone());
+
+        fn hash<H: core::hash::Hash>(value: &H) -> u64 {
+            use core::hash::Hasher;
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        let ticks = keyframes::from::<i32, u64>(0).stay(10).reverse().run(5);
+        assert_eq!(ticks, ticks.clone());
+        assert_eq!(hash(&ticks), hash(&ticks.clone()));
+    }
 }
@@ -0,0 +1,203 @@
+use super::keyframes_description::{KeyframesDescription, KeyframesKind};
+use crate::math::floor_f64;
+use crate::{Easing, Keyframes, Time, TimeDiff};
+use alloc::string::String;
+use core::fmt::Debug;
+
+/// Text revealed character by character, see `keyframes::typewriter`.
+pub struct TypewriterKeyframes<X: Time> {
+    text: String,
+    char_count: usize,
+    char_duration: X::Duration,
+    easing: Easing,
+    cursor: Option<char>,
+    blink_period: Option<X::Duration>,
+}
+
+impl<X: Time> TypewriterKeyframes<X> {
+    /// Type `text` spending `char_duration` per character.
+    pub fn new(text: impl Into<String>, char_duration: X::Duration) -> Self {
+        let text = text.into();
+        Self {
+            char_count: text.chars().count(),
+            text,
+            char_duration,
+            easing: Easing::Linear,
+            cursor: None,
+            blink_period: None,
+        }
+    }
+
+    /// Ease the typing progress, e.g. to start slowly, the total duration stays the same.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Show the cursor after the typed text.
+    pub fn with_cursor(mut self, cursor: char) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Blink the cursor after the text is typed, it's shown for the first half of each period.
+    /// The keyframes become infinite, see `with_cursor` to set the cursor.
+    pub fn with_blink(mut self, period: X::Duration) -> Self {
+        assert!(
+            period > Default::default(),
+            "Cursor blink period must be positive"
+        );
+        self.blink_period = Some(period);
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn typing_duration(&self) -> X::Duration {
+        self.char_duration.scale(self.char_count as f32)
+    }
+
+    /// Get the number of characters typed at the offset.
+    fn typed_chars(&self, offset: X::Duration) -> usize {
+        let duration = self.typing_duration();
+        if offset >= duration {
+            return self.char_count;
+        }
+        if offset <= Default::default() {
+            return 0;
+        }
+        let progress = self.easing.ease_f64(offset.as_f64() / duration.as_f64());
+        // The epsilon keeps a character typed exactly at its time despite the rounding.
+        let typed = floor_f64(progress * self.char_count as f64 + 1e-6) as usize;
+        typed.min(self.char_count)
+    }
+
+    fn is_cursor_visible(&self, offset: X::Duration) -> bool {
+        let typing_duration = self.typing_duration();
+        match self.blink_period {
+            Some(period) if offset > typing_duration => {
+                (offset - typing_duration).wrap(period) < period.scale(0.5)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl<X: Time> Keyframes<String, X> for TypewriterKeyframes<X> {
+    fn get(&self, offset: X::Duration) -> String {
+        let typed = self.typed_chars(offset);
+        let end = self
+            .text
+            .char_indices()
+            .nth(typed)
+            .map_or(self.text.len(), |(index, _)| index);
+        let mut value = String::from(&self.text[..end]);
+        if let Some(cursor) = self.cursor {
+            if self.is_cursor_visible(offset) {
+                value.push(cursor);
+            }
+        }
+        value
+    }
+
+    fn duration(&self) -> X::Duration {
+        if self.blink_period.is_some() {
+            panic!("TypewriterKeyframes with a blinking cursor have infinite duration");
+        }
+        self.typing_duration()
+    }
+
+    fn is_finite(&self) -> bool {
+        self.blink_period.is_none()
+    }
+
+    fn describe(&self) -> KeyframesDescription<X::Duration> {
+        let duration = self.blink_period.is_none().then(|| self.typing_duration());
+        KeyframesDescription::new(KeyframesKind::Typewriter, duration)
+            .with_easing(self.easing.clone())
+    }
+}
+
+impl<X: Time> Debug for TypewriterKeyframes<X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypewriterKeyframes")
+            .field("text", &self.text)
+            .field("char_duration", &self.char_duration)
+            .field("easing", &self.easing)
+            .field("cursor", &self.cursor)
+            .field("blink_period", &self.blink_period)
+            .finish()
+    }
+}
+
+impl<X: Time> Clone for TypewriterKeyframes<X> {
+    fn clone(&self) -> Self {
+        Self {
+            text: self.text.clone(),
+            char_count: self.char_count,
+            char_duration: self.char_duration,
+            easing: self.easing.clone(),
+            cursor: self.cursor,
+            blink_period: self.blink_period,
+        }
+    }
+}
+
+impl<X: Time> PartialEq for TypewriterKeyframes<X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.char_duration == other.char_duration
+            && self.easing == other.easing
+            && self.cursor == other.cursor
+            && self.blink_period == other.blink_period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn typing() {
+        let typewriter = keyframes::typewriter::<f32>("héllo", 0.25);
+        assert_eq!(typewriter.duration(), 1.25);
+        assert_eq!(typewriter.get(0.0), "");
+        assert_eq!(typewriter.get(0.25), "h");
+        assert_eq!(
+            typewriter.get(0.6),
+            "hé",
+            "Multibyte characters are kept whole"
+        );
+        assert_eq!(typewriter.get(2.0), "héllo");
+        assert_eq!(typewriter.describe().kind, KeyframesKind::Typewriter);
+
+        let eased = keyframes::typewriter::<f32>("abcd", 1.0).with_easing(Easing::QuadraticIn);
+        assert_eq!(
+            eased.get(2.0),
+            "a",
+            "A quarter of the text at the half of the time"
+        );
+    }
+
+    #[test]
+    fn cursor() {
+        let typewriter = keyframes::typewriter::<Instant>("ab", Duration::from_millis(100))
+            .with_cursor('_')
+            .with_blink(Duration::from_secs(1));
+        assert!(!typewriter.is_finite());
+        assert_eq!(typewriter.get(Duration::from_millis(150)), "a_");
+        assert_eq!(typewriter.get(Duration::from_millis(400)), "ab_");
+        assert_eq!(typewriter.get(Duration::from_millis(800)), "ab");
+        assert_eq!(typewriter.get(Duration::from_millis(1300)), "ab_");
+
+        let solid = keyframes::typewriter::<f32>("ab", 1.0).with_cursor('|');
+        assert_eq!(solid.get(10.0), "ab|");
+    }
+}
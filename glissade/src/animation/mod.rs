@@ -1,18 +1,51 @@
+mod animation_group;
 mod animation_struct;
+mod baked_animation;
+mod discontinuity;
+mod keyframes_anticipate;
 mod keyframes_apply_easing;
+mod keyframes_boxed;
+mod keyframes_description;
 mod keyframes_easing;
+mod keyframes_finite_or;
+mod keyframes_flat;
 mod keyframes_function;
+mod keyframes_group;
 mod keyframes_linear;
 mod keyframes_map;
+mod keyframes_orbit;
+mod keyframes_oscillator;
 mod keyframes_poly;
 mod keyframes_repeat;
 mod keyframes_repeat_n;
 mod keyframes_reverse;
 mod keyframes_scale;
 mod keyframes_sequential;
+mod keyframes_shake;
 mod keyframes_slice;
+mod keyframes_spec;
 mod keyframes_stay;
 mod keyframes_trait;
+mod keyframes_typewriter;
+mod playable_animation;
+mod scrubbed;
 
-pub use animation_struct::Animation;
+pub use animation_group::AnimationGroup;
+pub use animation_struct::{Animation, BeforeStart};
+pub use baked_animation::BakedAnimation;
+pub use discontinuity::{Discontinuity, DiscontinuityKind};
+pub use keyframes_anticipate::AnticipateOvershootKeyframes;
+pub use keyframes_boxed::BoxedKeyframes;
+pub use keyframes_description::{KeyframesDescription, KeyframesKind};
+pub use keyframes_flat::FlatKeyframes;
+pub use keyframes_group::GroupKeyframes;
+pub use keyframes_orbit::OrbitKeyframes;
+pub use keyframes_oscillator::{OscillatorKeyframes, Waveform};
+pub use keyframes_repeat::RepeatError;
+pub use keyframes_scale::ScaleError;
+pub use keyframes_shake::ShakeKeyframes;
+pub use keyframes_spec::KeyframesSpec;
 pub use keyframes_trait::{keyframes, Keyframes};
+pub use keyframes_typewriter::TypewriterKeyframes;
+pub use playable_animation::PlayableAnimation;
+pub use scrubbed::Scrubbed;
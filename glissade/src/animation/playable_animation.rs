@@ -0,0 +1,159 @@
+use crate::{Animated, Animation, Keyframes, Time};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// An animation that can be paused and resumed.
+/// Paused time is accumulated by moving the start time of the wrapped animation on resume,
+/// so the animation continues from the value it was paused at.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Animated, Keyframes, PlayableAnimation};
+///
+/// let mut animation = PlayableAnimation::new(keyframes::line(0.0, 4.0, 4.0).run(0.0));
+///
+/// animation.pause(1.0);
+/// assert!(animation.is_paused());
+/// assert_eq!(animation.get(3.0), 1.0);
+///
+/// animation.resume(3.0);
+/// assert_eq!(animation.get(4.0), 2.0);
+/// assert!(animation.is_finished(6.0));
+/// ```
+pub struct PlayableAnimation<T, X: Time, K: Keyframes<T, X>> {
+    animation: Animation<T, X, K>,
+    /// The time of the pause, `None` if the animation is playing.
+    paused_at: Option<X>,
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> PlayableAnimation<T, X, K> {
+    /// Wrap a playing animation.
+    pub fn new(animation: Animation<T, X, K>) -> Self {
+        Self {
+            animation,
+            paused_at: None,
+        }
+    }
+
+    /// Stop the animation at `time`, it does nothing if the animation is already paused.
+    pub fn pause(&mut self, time: X) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(time);
+        }
+    }
+
+    /// Continue the animation from the moment it was paused.
+    pub fn resume(&mut self, time: X) {
+        if let Some(paused_at) = self.paused_at.take() {
+            let start_time = self.animation.start_time();
+            self.animation
+                .set_start_time(start_time.advance(time.saturating_since(paused_at)));
+        }
+    }
+
+    /// Check if the animation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Get the wrapped animation, its start time includes the time spent in past pauses.
+    pub fn animation(&self) -> &Animation<T, X, K> {
+        &self.animation
+    }
+
+    /// Unwrap the animation, a paused animation is resumed at `time`.
+    pub fn into_animation(mut self, time: X) -> Animation<T, X, K> {
+        self.resume(time);
+        self.animation
+    }
+
+    /// Get the time of the wrapped animation, that is frozen during a pause.
+    fn animation_time(&self, time: X) -> X {
+        match self.paused_at {
+            Some(paused_at) if paused_at < time => paused_at,
+            _ => time,
+        }
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> From<Animation<T, X, K>> for PlayableAnimation<T, X, K> {
+    fn from(animation: Animation<T, X, K>) -> Self {
+        Self::new(animation)
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> Animated<T, X> for PlayableAnimation<T, X, K> {
+    fn get(&self, time: X) -> T {
+        self.animation.get(self.animation_time(time))
+    }
+
+    fn get_many(&self, times: &[X]) -> Vec<T> {
+        let times: Vec<X> = times
+            .iter()
+            .map(|time| self.animation_time(*time))
+            .collect();
+        self.animation.get_many(&times)
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        self.animation.is_finished(self.animation_time(time))
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Debug> Debug for PlayableAnimation<T, X, K>
+where
+    X: Debug,
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PlayableAnimation")
+            .field("animation", &self.animation)
+            .field("paused_at", &self.paused_at)
+            .finish()
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Clone> Clone for PlayableAnimation<T, X, K> {
+    fn clone(&self) -> Self {
+        Self {
+            animation: self.animation.clone(),
+            paused_at: self.paused_at,
+        }
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Copy> Copy for PlayableAnimation<T, X, K> {}
+
+impl<T, X: Time, K: Keyframes<T, X> + PartialEq> PartialEq for PlayableAnimation<T, X, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation == other.animation && self.paused_at == other.paused_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    #[test]
+    fn pause_and_resume() {
+        let mut animation: PlayableAnimation<f32, f32, _> =
+            keyframes::line(0.0, 10.0, 10.0).run(2.0).into();
+        animation.pause(1.0);
+        animation.resume(3.0);
+        assert_eq!(animation.animation().start_time(), 4.0);
+        assert_eq!(animation.get(5.0), 1.0);
+
+        animation.pause(6.0);
+        animation.pause(7.0);
+        assert_eq!(animation.get(5.0), 1.0);
+        assert_eq!(animation.get(20.0), 2.0);
+        assert!(!animation.is_finished(20.0));
+        assert_eq!(animation.get_many(&[5.0, 9.0]), vec![1.0, 2.0]);
+
+        let animation = animation.into_animation(8.0);
+        assert_eq!(animation.start_time(), 6.0);
+        assert_eq!(animation.get(9.0), 3.0);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::math::abs;
+use crate::{Animated, Keyframes, Spring, Time, TimeDiff};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Keyframes driven by a gesture, e.g. a swipe-to-dismiss card or a bottom sheet.
+/// While dragging, the progress in the range `[0, 1]` is set directly with `set_progress`.
+/// On `release` the progress continues with the gesture velocity, and a spring brings it to the nearest snap point.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Animated, Keyframes, Scrubbed};
+///
+/// let mut sheet = Scrubbed::new(keyframes::line::<f32, f32>(0.0, 100.0, 1.0));
+///
+/// sheet.set_progress(0.4);
+/// assert_eq!(sheet.get(0.0), 40.0);
+///
+/// // A fast flick goes to the far snap point.
+/// sheet.release(0.0, 3.0);
+/// assert_eq!(sheet.target_progress(), 1.0);
+/// assert_eq!(sheet.get(10.0), 100.0);
+/// ```
+pub struct Scrubbed<T, X: Time, K: Keyframes<T, X>> {
+    keyframes: K,
+    progress: Spring<f32, X>,
+    snap_points: Vec<f32>,
+    projection: f32,
+    phantom: PhantomData<T>,
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> Scrubbed<T, X, K> {
+    /// Wrap finite keyframes, the progress is zero and snap points are `0` and `1`.
+    pub fn new(keyframes: K) -> Self {
+        assert!(keyframes.is_finite(), "Scrubbed keyframes must be finite");
+        Self {
+            keyframes,
+            progress: Spring::new(0.0),
+            snap_points: vec![0.0, 1.0],
+            projection: 0.2,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Set progress values the released gesture can settle at, they are clamped to `[0, 1]`.
+    pub fn with_snap_points(mut self, snap_points: impl IntoIterator<Item = f32>) -> Self {
+        self.snap_points = snap_points
+            .into_iter()
+            .map(|point| point.clamp(0.0, 1.0))
+            .collect();
+        assert!(
+            !self.snap_points.is_empty(),
+            "Scrubbed needs at least one snap point"
+        );
+        self
+    }
+
+    /// Set the spring that brings the released progress to a snap point, see `Spring::with_response`.
+    pub fn with_response(mut self, response: f32, damping_ratio: f32) -> Self {
+        self.progress = self.progress.with_response(response, damping_ratio);
+        self
+    }
+
+    /// Set how far the release velocity carries the progress when the snap point is chosen,
+    /// the progress is projected by `velocity * projection`. It's `0.2` by default.
+    pub fn with_projection(mut self, projection: f32) -> Self {
+        assert!(
+            projection >= 0.0 && projection.is_finite(),
+            "Scrubbed projection must be finite and non-negative"
+        );
+        self.projection = projection;
+        self
+    }
+
+    /// Set the progress directly, e.g. while the gesture is dragging.
+    /// Any motion from a previous release is stopped.
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress.reset(progress.clamp(0.0, 1.0));
+    }
+
+    /// Release the gesture at `time` with `velocity` in progress units per time unit,
+    /// the progress moves to the snap point nearest to where the velocity would carry it.
+    pub fn release(&mut self, time: X, velocity: f32) {
+        let progress = self.progress(time);
+        let projected = progress + velocity * self.projection;
+        let target = self
+            .snap_points
+            .iter()
+            .copied()
+            .min_by(|a, b| abs(a - projected).total_cmp(&abs(b - projected)))
+            .unwrap_or(progress);
+        self.progress.set_velocity(velocity, time);
+        self.progress.set_target(target, time);
+    }
+
+    /// Get the progress at `time`, it can overshoot `[0, 1]` a bit after a release.
+    pub fn progress(&self, time: X) -> f32 {
+        self.progress.get(time)
+    }
+
+    /// Get the progress the released gesture settles at, or the current progress while dragging.
+    pub fn target_progress(&self) -> f32 {
+        self.progress.target()
+    }
+
+    /// Get the wrapped keyframes.
+    pub fn keyframes(&self) -> &K {
+        &self.keyframes
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> Animated<T, X> for Scrubbed<T, X, K> {
+    fn get(&self, time: X) -> T {
+        let progress = self.progress(time).clamp(0.0, 1.0);
+        let duration = self.keyframes.duration();
+        self.keyframes
+            .get(duration.checked_scale(progress).unwrap_or(duration))
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        self.progress.is_finished(time)
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Debug> Debug for Scrubbed<T, X, K>
+where
+    X: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Scrubbed")
+            .field("keyframes", &self.keyframes)
+            .field("progress", &self.progress)
+            .field("snap_points", &self.snap_points)
+            .field("projection", &self.projection)
+            .finish()
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Clone> Clone for Scrubbed<T, X, K> {
+    fn clone(&self) -> Self {
+        Self {
+            keyframes: self.keyframes.clone(),
+            progress: self.progress,
+            snap_points: self.snap_points.clone(),
+            projection: self.projection,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + PartialEq> PartialEq for Scrubbed<T, X, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keyframes == other.keyframes
+            && self.progress == other.progress
+            && self.snap_points == other.snap_points
+            && self.projection == other.projection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    #[test]
+    fn drag_and_release() {
+        let mut card = Scrubbed::new(keyframes::line::<f32, f32>(0.0, 10.0, 2.0))
+            .with_snap_points([0.0, 0.5, 1.0]);
+        assert_eq!(card.get(0.0), 0.0);
+        assert!(card.is_finished(0.0));
+
+        card.set_progress(0.3);
+        assert_eq!(card.get(1.0), 3.0);
+        assert!(
+            card.is_finished(1.0),
+            "A dragged value doesn't move by itself"
+        );
+
+        card.release(1.0, 0.0);
+        assert_eq!(card.target_progress(), 0.5);
+        assert_eq!(card.get(1.0), 3.0, "Release doesn't jump");
+        assert!(!card.is_finished(1.1));
+        assert_eq!(card.get(10.0), 5.0);
+        assert!(card.is_finished(10.0));
+
+        card.set_progress(0.6);
+        card.release(10.0, -2.0);
+        assert_eq!(
+            card.target_progress(),
+            0.0,
+            "Velocity carries to the far point"
+        );
+        assert!(card.progress(10.05) < 0.6);
+        assert_eq!(card.get(20.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Scrubbed keyframes must be finite")]
+    fn infinite_keyframes() {
+        Scrubbed::new(keyframes::line::<f32, f32>(0.0, 1.0, 1.0).repeat());
+    }
+}
@@ -0,0 +1,299 @@
+//! Load keyframes from JSON assets, so animations can be tweaked and reloaded without recompiling.
+//!
+//! An asset is a JSON object with named keyframes and optional named easings.
+//! Keyframes are described with [`KeyframesSpec`], and can refer to other named keyframes
+//! with `{ "Ref": "name" }`. An `"easing"` field can be a name of an easing from the `"easings"` object.
+//!
+//! # Example
+//!
+//! ```
+//! use glissade::{assets, Keyframes};
+//!
+//! let asset = r#"{
+//!     "easings": {
+//!         "snappy": { "Tabular": [0.0, 0.6, 0.9, 1.0] }
+//!     },
+//!     "keyframes": {
+//!         "fade_in": { "Ease": { "from": 0.0, "to": 1.0, "duration": 0.5, "easing": "snappy" } },
+//!         "main": {
+//!             "Sequence": [
+//!                 { "Ref": "fade_in" },
+//!                 { "Stay": { "value": 1.0, "duration": 1.0 } },
+//!                 { "Reverse": { "Ref": "fade_in" } }
+//!             ]
+//!         }
+//!     }
+//! }"#;
+//!
+//! let keyframes = assets::load_str::<f32, f32>(asset, 1.0).unwrap();
+//! assert_eq!(keyframes.duration(), 2.0);
+//! assert_eq!(keyframes.get(1.0), 1.0);
+//! assert_eq!(keyframes.get(2.0), 0.0);
+//! ```
+
+use crate::{BoxedKeyframes, Distance, KeyframesSpec, Mix, Time};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::fmt::{Display, Formatter};
+use std::string::String;
+use std::vec::Vec;
+
+/// The name of keyframes loaded by `load_str`.
+pub const MAIN: &str = "main";
+
+/// An error that can occur while loading an asset.
+#[derive(Debug)]
+pub enum AssetError {
+    /// The asset is not valid JSON, or doesn't match the expected structure.
+    Json(serde_json::Error),
+    /// Keyframes with the name are not found in the asset.
+    NotFound(String),
+    /// Keyframes with the name refer to themselves.
+    Cycle(String),
+    /// Keyframes can't be built, for example, because of a negative duration.
+    Invalid(&'static str),
+}
+
+impl Display for AssetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetError::Json(error) => write!(f, "invalid asset: {}", error),
+            AssetError::NotFound(name) => write!(f, "keyframes \"{}\" not found", name),
+            AssetError::Cycle(name) => write!(f, "keyframes \"{}\" refer to themselves", name),
+            AssetError::Invalid(message) => write!(f, "invalid keyframes: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AssetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AssetError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for AssetError {
+    fn from(error: serde_json::Error) -> Self {
+        AssetError::Json(error)
+    }
+}
+
+/// Load the `"main"` keyframes from a JSON asset.
+/// * `unit` - The duration of `1.0` in the asset, for example, `Duration::from_secs(1)`.
+pub fn load_str<T, X>(source: &str, unit: X::Duration) -> Result<BoxedKeyframes<T, X>, AssetError>
+where
+    T: DeserializeOwned + Mix + Distance + Clone + 'static,
+    X: Time + 'static,
+{
+    load_str_named(source, MAIN, unit)
+}
+
+/// Load keyframes with the given name from a JSON asset.
+/// * `unit` - The duration of `1.0` in the asset, for example, `Duration::from_secs(1)`.
+pub fn load_str_named<T, X>(
+    source: &str,
+    name: &str,
+    unit: X::Duration,
+) -> Result<BoxedKeyframes<T, X>, AssetError>
+where
+    T: DeserializeOwned + Mix + Distance + Clone + 'static,
+    X: Time + 'static,
+{
+    let asset: Asset = serde_json::from_str(source)?;
+    let value = asset.resolve(name, &mut Vec::new())?;
+    let spec: KeyframesSpec<T> = serde_json::from_value(value)?;
+    check::<T, X>(&spec, unit)?;
+    Ok(spec.compile(unit))
+}
+
+#[derive(Deserialize)]
+struct Asset {
+    #[serde(default)]
+    easings: Map<String, Value>,
+    keyframes: Map<String, Value>,
+}
+
+impl Asset {
+    /// Get named keyframes with all references and easing names replaced by their values.
+    fn resolve(&self, name: &str, stack: &mut Vec<String>) -> Result<Value, AssetError> {
+        if stack.iter().any(|item| item == name) {
+            return Err(AssetError::Cycle(name.into()));
+        }
+
+        let value = self
+            .keyframes
+            .get(name)
+            .ok_or_else(|| AssetError::NotFound(name.into()))?;
+
+        stack.push(name.into());
+        let result = self.resolve_value(value, stack);
+        stack.pop();
+        result
+    }
+
+    fn resolve_value(&self, value: &Value, stack: &mut Vec<String>) -> Result<Value, AssetError> {
+        match value {
+            Value::Object(object) => {
+                if let (1, Some(Value::String(name))) = (object.len(), object.get("Ref")) {
+                    return self.resolve(name, stack);
+                }
+
+                let mut result = Map::new();
+                for (key, value) in object {
+                    let value = match value {
+                        Value::String(easing) if key == "easing" => {
+                            self.easings.get(easing).unwrap_or(value).clone()
+                        }
+                        _ => self.resolve_value(value, stack)?,
+                    };
+                    result.insert(key.clone(), value);
+                }
+                Ok(Value::Object(result))
+            }
+            Value::Array(items) => items
+                .iter()
+                .map(|item| self.resolve_value(item, stack))
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            _ => Ok(value.clone()),
+        }
+    }
+}
+
+/// Check the conditions `KeyframesSpec::compile` panics on.
+/// Finiteness depends on the unit, e.g. repeated keyframes of zero duration are finite,
+/// so reversed keyframes are compiled to check it.
+fn check<T, X>(spec: &KeyframesSpec<T>, unit: X::Duration) -> Result<(), AssetError>
+where
+    T: Mix + Distance + Clone + 'static,
+    X: Time + 'static,
+{
+    fn ensure(condition: bool, message: &'static str) -> Result<(), AssetError> {
+        if condition {
+            Ok(())
+        } else {
+            Err(AssetError::Invalid(message))
+        }
+    }
+
+    fn check_duration(duration: f32) -> Result<(), AssetError> {
+        ensure(
+            duration >= 0.0 && duration.is_finite(),
+            "duration must be finite and non-negative",
+        )
+    }
+
+    match spec {
+        KeyframesSpec::Stay { duration, .. }
+        | KeyframesSpec::Linear { duration, .. }
+        | KeyframesSpec::Ease { duration, .. } => check_duration(*duration),
+        KeyframesSpec::Poly {
+            points, duration, ..
+        } => {
+            ensure(!points.is_empty(), "poly must have at least one point")?;
+            check_duration(*duration)
+        }
+        KeyframesSpec::Repeat(keyframes) | KeyframesSpec::ApplyEasing { keyframes, .. } => {
+            check::<T, X>(keyframes, unit)
+        }
+        KeyframesSpec::Reverse(keyframes) => {
+            check::<T, X>(keyframes, unit)?;
+            ensure(
+                keyframes.compile::<X>(unit).is_finite(),
+                "infinite keyframes can't be reversed",
+            )
+        }
+        KeyframesSpec::RepeatN { keyframes, n } => {
+            ensure(
+                *n >= 0.0 && n.is_finite(),
+                "n must be finite and non-negative",
+            )?;
+            check::<T, X>(keyframes, unit)
+        }
+        KeyframesSpec::Scale { keyframes, scale } => {
+            ensure(
+                *scale >= 0.0 && scale.is_finite(),
+                "scale must be finite and non-negative",
+            )?;
+            check::<T, X>(keyframes, unit)
+        }
+        KeyframesSpec::Sequence(list) => {
+            ensure(!list.is_empty(), "sequence must not be empty")?;
+            list.iter()
+                .try_for_each(|keyframes| check::<T, X>(keyframes, unit))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Keyframes;
+
+    #[test]
+    fn load_named() {
+        let asset = r#"{
+            "keyframes": {
+                "up": { "Linear": { "from": 0.0, "to": 2.0, "duration": 2.0 } },
+                "twice": { "RepeatN": { "keyframes": { "Ref": "up" }, "n": 2.0 } }
+            }
+        }"#;
+
+        let keyframes = load_str_named::<f64, f64>(asset, "twice", 1000.0).unwrap();
+        assert_eq!(keyframes.duration(), 4000.0);
+        assert_eq!(keyframes.get(3000.0), 1.0);
+    }
+
+    #[test]
+    fn scale_and_reverse() {
+        let asset = r#"{
+            "keyframes": {
+                "flash": { "Repeat": { "Stay": { "value": 1.0, "duration": 0.0 } } },
+                "main": {
+                    "Sequence": [
+                        { "Scale": { "keyframes": { "Reverse": { "Ref": "flash" } }, "scale": 0.0 } },
+                        { "Linear": { "from": 0.0, "to": 1.0, "duration": 1.0 } }
+                    ]
+                }
+            }
+        }"#;
+
+        let keyframes = load_str::<f32, f32>(asset, 1.0).unwrap();
+        assert_eq!(keyframes.duration(), 1.0);
+        assert_eq!(keyframes.get(0.5), 0.5);
+    }
+
+    #[test]
+    fn errors() {
+        let result = load_str::<f32, f32>(r#"{ "keyframes": {} }"#, 1.0);
+        assert!(matches!(result, Err(AssetError::NotFound(name)) if name == "main"));
+
+        let result = load_str::<f32, f32>(
+            r#"{ "keyframes": { "main": { "Sequence": [{ "Ref": "main" }] } } }"#,
+            1.0,
+        );
+        assert!(matches!(result, Err(AssetError::Cycle(name)) if name == "main"));
+
+        let result = load_str::<f32, f32>(
+            r#"{ "keyframes": { "main": { "Stay": { "value": 1.0, "duration": -1.0 } } } }"#,
+            1.0,
+        );
+        assert!(matches!(result, Err(AssetError::Invalid(_))));
+
+        let result = load_str::<f32, f32>(
+            r#"{ "keyframes": { "main": { "Reverse": { "Repeat": { "Ref": "up" } } },
+                 "up": { "Linear": { "from": 0.0, "to": 1.0, "duration": 1.0 } } } }"#,
+            1.0,
+        );
+        assert!(matches!(
+            result,
+            Err(AssetError::Invalid("infinite keyframes can't be reversed"))
+        ));
+
+        let result = load_str::<f32, f32>(r#"{ "keyframes": { "main": "Stay" } }"#, 1.0);
+        assert!(matches!(result, Err(AssetError::Json(_))));
+    }
+}
@@ -0,0 +1,300 @@
+//! Evaluation of many animations at a single timestamp, e.g. particles or list items.
+
+use crate::{Animated, Keyframes, Time};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Get values of all animations at the same time.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{batch, keyframes, Keyframes};
+///
+/// let animations = [
+///     keyframes::line(0.0, 1.0, 1.0).run(0.0),
+///     keyframes::line(0.0, 1.0, 1.0).run(0.5),
+/// ];
+/// assert_eq!(batch::get_all(&animations, 1.0), vec![1.0, 0.5]);
+/// ```
+pub fn get_all<'a, T, X, A>(animations: impl IntoIterator<Item = &'a A>, time: X) -> Vec<T>
+where
+    X: Time,
+    A: Animated<T, X> + 'a,
+{
+    animations
+        .into_iter()
+        .map(|animation| animation.get(time))
+        .collect()
+}
+
+/// Many animations sharing the same keyframes, but started at different times.
+/// Only start times are stored per animation, and keyframes are a type parameter,
+/// so evaluating thousands of them is a tight loop without per-item dispatch,
+/// unless the keyframes are `BoxedKeyframes`.
+///
+/// Indexes only shift on `remove` and `remove_finished`, so a fixed pool, e.g. of particles,
+/// can reuse finished slots with `restart`, and `sample_into` writes values straight
+/// into the caller's structure-of-arrays buffers.
+///
+/// # Example
+///
+/// ```
+/// use glissade::batch::AnimationSet;
+/// use glissade::{keyframes, Keyframes};
+///
+/// let mut particles = AnimationSet::new(keyframes::line(0.0, 10.0, 1.0));
+/// particles.insert(0.0);
+/// particles.insert(0.5);
+///
+/// assert_eq!(particles.get_all(0.75), vec![7.5, 2.5]);
+///
+/// particles.remove_finished(1.0);
+/// assert_eq!(particles.start_times(), &[0.5]);
+///
+/// let mut sizes = AnimationSet::from_start_times(keyframes::line(0.0, 10.0, 1.0), [0.0, 0.5, 1.0]);
+/// let mut values = [0.0; 3];
+/// sizes.sample_into(1.0, &mut values);
+/// assert_eq!(values, [10.0, 5.0, 0.0]);
+///
+/// sizes.restart(0, 1.0);
+/// assert_eq!(sizes.get(0, 1.5), 5.0);
+/// ```
+pub struct AnimationSet<T, X: Time, K: Keyframes<T, X>> {
+    keyframes: K,
+    start_times: Vec<X>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Debug> Debug for AnimationSet<T, X, K>
+where
+    X: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimationSet")
+            .field("keyframes", &self.keyframes)
+            .field("start_times", &self.start_times)
+            .finish()
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X> + Clone> Clone for AnimationSet<T, X, K> {
+    fn clone(&self) -> Self {
+        Self {
+            keyframes: self.keyframes.clone(),
+            start_times: self.start_times.clone(),
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T, X: Time, K: Keyframes<T, X>> AnimationSet<T, X, K> {
+    pub fn new(keyframes: K) -> Self {
+        Self {
+            keyframes,
+            start_times: Vec::new(),
+            phantom: Default::default(),
+        }
+    }
+
+    /// Create an animation per start time.
+    pub fn from_start_times(keyframes: K, start_times: impl IntoIterator<Item = X>) -> Self {
+        Self {
+            keyframes,
+            start_times: start_times.into_iter().collect(),
+            phantom: Default::default(),
+        }
+    }
+
+    /// Get the keyframes shared by all animations.
+    pub fn keyframes(&self) -> &K {
+        &self.keyframes
+    }
+
+    /// Get start times of the animations, indexes are the same as in `get_all`.
+    pub fn start_times(&self) -> &[X] {
+        &self.start_times
+    }
+
+    /// Get start times to change many of them at once.
+    pub fn start_times_mut(&mut self) -> &mut [X] {
+        &mut self.start_times
+    }
+
+    /// Start a new animation, returns its index.
+    pub fn insert(&mut self, start_time: X) -> usize {
+        self.start_times.push(start_time);
+        self.start_times.len() - 1
+    }
+
+    /// Run the animation at `index` from the beginning at `start_time`, its index is kept.
+    pub fn restart(&mut self, index: usize, start_time: X) {
+        self.start_times[index] = start_time;
+    }
+
+    /// Remove the animation, indexes of the following animations are shifted.
+    pub fn remove(&mut self, index: usize) -> X {
+        self.start_times.remove(index)
+    }
+
+    /// Remove animations finished at the time, the order of the rest is kept.
+    pub fn remove_finished(&mut self, time: X) {
+        if self.keyframes.is_finite() {
+            let duration = self.keyframes.duration();
+            self.start_times
+                .retain(|start_time| time.saturating_since(*start_time) < duration);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.start_times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start_times.is_empty()
+    }
+
+    /// Get the value of a single animation.
+    pub fn get(&self, index: usize, time: X) -> T {
+        self.keyframes
+            .get(time.saturating_since(self.start_times[index]))
+    }
+
+    /// Check if the animation at `index` is finished, its slot can be reused with `restart`.
+    pub fn is_finished(&self, index: usize, time: X) -> bool {
+        self.keyframes
+            .is_finished(time.saturating_since(self.start_times[index]))
+    }
+
+    /// Get values of all animations.
+    pub fn get_all(&self, time: X) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.start_times.len());
+        self.get_all_into(time, &mut values);
+        values
+    }
+
+    /// Same as `get_all`, but values are written to the existing buffer, to reuse it between frames.
+    pub fn get_all_into(&self, time: X, values: &mut Vec<T>) {
+        values.clear();
+        values.extend(
+            self.start_times
+                .iter()
+                .map(|start_time| self.keyframes.get(time.saturating_since(*start_time))),
+        );
+    }
+
+    /// Same as `get_all_into`, but values are written to a slice of the same length as the set,
+    /// e.g. a column of a structure-of-arrays buffer.
+    pub fn sample_into(&self, time: X, values: &mut [T]) {
+        assert_eq!(
+            values.len(),
+            self.start_times.len(),
+            "AnimationSet values must have the same length as the set"
+        );
+        for (value, start_time) in values.iter_mut().zip(&self.start_times) {
+            *value = self.keyframes.get(time.saturating_since(*start_time));
+        }
+    }
+
+    /// Same as `get_all`, but animations are evaluated on all cores.
+    #[cfg(feature = "rayon")]
+    pub fn get_all_par(&self, time: X) -> Vec<T>
+    where
+        T: Send,
+        X: Sync,
+        K: Sync,
+    {
+        use rayon::prelude::*;
+
+        self.start_times
+            .par_iter()
+            .map(|start_time| self.keyframes.get(time.saturating_since(*start_time)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, BoxedKeyframes};
+    use alloc::vec;
+
+    #[test]
+    fn get_all_animations() {
+        let animations: Vec<BoxedKeyframes<f32, f32>> = vec![
+            keyframes::stay(1.0, 1.0).boxed(),
+            keyframes::line(0.0, 4.0, 1.0).boxed(),
+        ];
+        let animations: Vec<_> = animations
+            .into_iter()
+            .map(|keyframes| keyframes.run(1.0))
+            .collect();
+
+        assert_eq!(get_all(&animations, 1.5), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn animation_set() {
+        let mut set = AnimationSet::new(keyframes::line::<f32, f32>(0.0, 1.0, 1.0).boxed());
+        assert!(set.is_empty());
+        assert_eq!(set.insert(0.0), 0);
+        assert_eq!(set.insert(1.0), 1);
+        assert_eq!(set.insert(2.0), 2);
+
+        assert_eq!(set.get_all(1.5), vec![1.0, 0.5, 0.0]);
+        assert_eq!(set.get(1, 1.25), 0.25);
+
+        let mut values = vec![9.0; 10];
+        set.get_all_into(2.5, &mut values);
+        assert_eq!(values, vec![1.0, 1.0, 0.5]);
+
+        set.remove_finished(2.0);
+        assert_eq!(set.start_times(), &[2.0]);
+        assert_eq!(set.remove(0), 2.0);
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn infinite_animations_are_kept() {
+        let mut set = AnimationSet::new(keyframes::line::<f32, f32>(0.0, 1.0, 1.0).repeat());
+        set.insert(0.0);
+        set.remove_finished(100.0);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn fixed_slots() {
+        let mut set =
+            AnimationSet::from_start_times(keyframes::line::<f32, f32>(0.0, 1.0, 1.0), [0.0, 1.0]);
+        assert_eq!(set.len(), 2);
+
+        let mut values = [9.0; 2];
+        set.sample_into(1.5, &mut values);
+        assert_eq!(values, [1.0, 0.5]);
+        assert!(set.is_finished(0, 1.5));
+        assert!(!set.is_finished(1, 1.5));
+
+        set.restart(0, 1.5);
+        assert_eq!(set.get(0, 2.0), 0.5);
+        set.start_times_mut().fill(2.0);
+        assert_eq!(set.start_times(), &[2.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "AnimationSet values must have the same length as the set")]
+    fn sample_into_length_mismatch() {
+        let set = AnimationSet::from_start_times(keyframes::line::<f32, f32>(0.0, 1.0, 1.0), [0.0]);
+        set.sample_into(0.0, &mut [0.0; 2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn get_all_par() {
+        let mut set = AnimationSet::new(keyframes::line::<f32, f32>(0.0, 1.0, 1.0));
+        for i in 0..1000 {
+            set.insert(i as f32 * 0.001);
+        }
+        assert_eq!(set.get_all_par(0.5), set.get_all(0.5));
+    }
+}
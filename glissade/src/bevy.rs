@@ -0,0 +1,219 @@
+//! [Bevy](https://bevyengine.org/) integration.
+//!
+//! Add [`GlissadeAnimation`] to an entity, and implement [`AnimationTarget`] for a component
+//! the animated value should be written to. [`GlissadePlugin`] samples the animation every frame
+//! with the real time of the frame, and sends [`AnimationFinished`] when it's finished.
+//!
+//! # Example
+//!
+//! ```
+//! use bevy_app::App;
+//! use bevy_ecs::prelude::*;
+//! use bevy_platform::time::Instant;
+//! use bevy_time::TimePlugin;
+//! use glissade::bevy::{AnimationTarget, GlissadeAnimation, GlissadePlugin};
+//! use glissade::{keyframes, Keyframes};
+//! use std::time::Duration;
+//!
+//! #[derive(Component)]
+//! struct Opacity(f32);
+//!
+//! impl AnimationTarget<f32> for Opacity {
+//!     fn set_animated(&mut self, value: f32) {
+//!         self.0 = value;
+//!     }
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins((TimePlugin, GlissadePlugin::<f32, Opacity>::default()));
+//! app.world_mut().spawn((
+//!     Opacity(0.0),
+//!     GlissadeAnimation::<f32>::new(
+//!         keyframes::line(0.0, 1.0, Duration::from_millis(300)).run(Instant::now()),
+//!     ),
+//! ));
+//! app.update();
+//! ```
+
+use crate::Animated;
+use alloc::boxed::Box;
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::component::Mutable;
+use bevy_ecs::prelude::*;
+use bevy_platform::time::Instant;
+use bevy_time::{Real, Time};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A component with an animated value, that is written to the `AnimationTarget` component of the same entity.
+/// It's removed from the entity when the animation is finished.
+#[derive(Component)]
+pub struct GlissadeAnimation<T: Send + Sync + 'static> {
+    animated: Box<dyn Animated<T, Instant> + Send + Sync>,
+}
+
+impl<T: Send + Sync + 'static> GlissadeAnimation<T> {
+    pub fn new(animated: impl Animated<T, Instant> + Send + Sync + 'static) -> Self {
+        Self {
+            animated: Box::new(animated),
+        }
+    }
+
+    /// Get the animated value at a specific time.
+    pub fn get(&self, time: Instant) -> T {
+        self.animated.get(time)
+    }
+
+    /// Check if the animation is finished at a specific time.
+    pub fn is_finished(&self, time: Instant) -> bool {
+        self.animated.is_finished(time)
+    }
+}
+
+/// A component that receives values of `GlissadeAnimation<T>`.
+pub trait AnimationTarget<T>: Component<Mutability = Mutable> {
+    fn set_animated(&mut self, value: T);
+}
+
+/// The message sent when `GlissadeAnimation<T>` of the entity is finished.
+#[derive(Message)]
+pub struct AnimationFinished<T: Send + Sync + 'static> {
+    pub entity: Entity,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> AnimationFinished<T> {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Debug for AnimationFinished<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimationFinished")
+            .field("entity", &self.entity)
+            .finish()
+    }
+}
+
+impl<T: Send + Sync + 'static> Clone for AnimationFinished<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for AnimationFinished<T> {}
+
+impl<T: Send + Sync + 'static> PartialEq for AnimationFinished<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entity == other.entity
+    }
+}
+
+/// Write values of `GlissadeAnimation<T>` to `C` components,
+/// remove finished animations and send `AnimationFinished<T>` for them.
+pub fn animate<T, C>(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut query: Query<(Entity, &GlissadeAnimation<T>, &mut C)>,
+    mut finished: MessageWriter<AnimationFinished<T>>,
+) where
+    T: Send + Sync + 'static,
+    C: AnimationTarget<T>,
+{
+    let Some(now) = time.last_update() else {
+        return;
+    };
+
+    for (entity, animation, mut target) in &mut query {
+        target.set_animated(animation.get(now));
+        if animation.is_finished(now) {
+            commands.entity(entity).remove::<GlissadeAnimation<T>>();
+            finished.write(AnimationFinished::new(entity));
+        }
+    }
+}
+
+/// Adds the `animate::<T, C>` system to the `Update` schedule, and the `AnimationFinished<T>` message.
+/// It requires `TimePlugin`.
+pub struct GlissadePlugin<T, C> {
+    phantom: PhantomData<fn() -> (T, C)>,
+}
+
+impl<T, C> Default for GlissadePlugin<T, C> {
+    fn default() -> Self {
+        Self {
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T, C> Plugin for GlissadePlugin<T, C>
+where
+    T: Send + Sync + 'static,
+    C: AnimationTarget<T>,
+{
+    fn build(&self, app: &mut App) {
+        app.add_message::<AnimationFinished<T>>()
+            .add_systems(Update, animate::<T, C>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+    use bevy_ecs::message::Messages;
+    use bevy_time::{TimePlugin, TimeUpdateStrategy};
+    use std::time::Duration;
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Opacity(f32);
+
+    impl AnimationTarget<f32> for Opacity {
+        fn set_animated(&mut self, value: f32) {
+            self.0 = value;
+        }
+    }
+
+    #[test]
+    fn animate_component() {
+        let start = Instant::now();
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, GlissadePlugin::<f32, Opacity>::default()));
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Opacity(0.0),
+                GlissadeAnimation::<f32>::new(
+                    keyframes::line(0.0, 1.0, Duration::from_secs(1)).run(start),
+                ),
+            ))
+            .id();
+
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(
+            start + Duration::from_millis(500),
+        ));
+        app.update();
+        assert_eq!(app.world().get::<Opacity>(entity), Some(&Opacity(0.5)));
+        assert!(app
+            .world()
+            .resource::<Messages<AnimationFinished<f32>>>()
+            .is_empty());
+
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(
+            start + Duration::from_secs(1),
+        ));
+        app.update();
+        assert_eq!(app.world().get::<Opacity>(entity), Some(&Opacity(1.0)));
+        assert!(app.world().get::<GlissadeAnimation<f32>>(entity).is_none());
+
+        let messages = app.world().resource::<Messages<AnimationFinished<f32>>>();
+        let finished: Vec<_> = messages.iter_current_update_messages().copied().collect();
+        assert_eq!(finished, vec![AnimationFinished::new(entity)]);
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{Animated, Time, TimeDiff};
+use alloc::vec::Vec;
+
+/// A virtual clock that can be paused, resumed, slowed down, or sped up.
+/// It converts real time into virtual time, that can be passed to `Animated::get`,
+/// so a whole scene can be controlled without touching individual animations.
+///
+/// Like other types of the library, it receives the current real time as a parameter,
+/// usually `Instant::now()` taken once at the beginning of the frame.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Animated, Clock, Keyframes};
+///
+/// let mut clock = Clock::new(0.0);
+/// let animation = keyframes::line(0.0, 8.0, 8.0).run(clock.now(0.0));
+///
+/// clock.set_speed(0.5, 2.0);
+/// assert_eq!(animation.get(clock.now(4.0)), 3.0);
+///
+/// clock.pause(4.0);
+/// assert_eq!(animation.get(clock.now(8.0)), 3.0);
+///
+/// clock.resume(8.0);
+/// assert_eq!(animation.get(clock.now(10.0)), 4.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clock<X: Time> {
+    /// Real time of the last pause, resume, or speed change.
+    real_origin: X,
+    /// Virtual time at `real_origin`.
+    virtual_origin: X,
+    speed: f32,
+    paused: bool,
+}
+
+impl<X: Time> Clock<X> {
+    /// Create a new running clock. Virtual time is equal to real time at `start_time`.
+    /// * `start_time` - The current real time, usually `Instant::now()`.
+    pub fn new(start_time: X) -> Self {
+        Self {
+            real_origin: start_time,
+            virtual_origin: start_time,
+            speed: 1.0,
+            paused: false,
+        }
+    }
+
+    /// Get the virtual time corresponding to the real `time`.
+    /// Real time before the last clock change is treated as the time of that change.
+    pub fn now(&self, time: X) -> X {
+        if self.paused || time <= self.real_origin {
+            self.virtual_origin
+        } else {
+            let elapsed = time.since(self.real_origin);
+            let elapsed = if self.speed == 1.0 {
+                elapsed
+            } else {
+                elapsed.scale(self.speed)
+            };
+            self.virtual_origin.advance(elapsed)
+        }
+    }
+
+    /// Stop the virtual time at `time`.
+    pub fn pause(&mut self, time: X) {
+        self.rebase(time);
+        self.paused = true;
+    }
+
+    /// Continue the virtual time from the moment it was paused.
+    pub fn resume(&mut self, time: X) {
+        if self.paused {
+            self.real_origin = time;
+            self.paused = false;
+        }
+    }
+
+    /// Check if the clock is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Get the virtual time speed relative to the real time.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Change the virtual time speed starting from `time`.
+    /// Virtual time stays continuous, only its rate changes.
+    /// * `speed` - A non-negative factor, `1.0` is the real time speed, `0.5` is two times slower.
+    pub fn set_speed(&mut self, speed: f32, time: X) {
+        assert!(
+            speed >= 0.0 && speed.is_finite(),
+            "Clock::set_speed: speed must be finite and non-negative"
+        );
+        self.rebase(time);
+        self.speed = speed;
+    }
+
+    fn rebase(&mut self, time: X) {
+        if time > self.real_origin {
+            self.virtual_origin = self.now(time);
+            self.real_origin = time;
+        }
+    }
+}
+
+/// A clock with manually controlled time, for deterministic tests and offline rendering.
+/// It has the same pause, resume, and speed controls as `Clock`,
+/// but real time only moves when `advance` or `set` are called.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Keyframes, ManualClock};
+///
+/// let mut clock = ManualClock::new(0.0);
+/// let animation = keyframes::line(0.0, 4.0, 1.0).run(clock.now());
+///
+/// assert_eq!(clock.frames(&animation, 0.25, 6), vec![0.0, 1.0, 2.0, 3.0, 4.0, 4.0]);
+/// assert_eq!(clock.now(), 1.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ManualClock<X: Time> {
+    time: X,
+    clock: Clock<X>,
+}
+
+impl<X: Time> ManualClock<X> {
+    /// Create a new clock starting at `start_time`.
+    pub fn new(start_time: X) -> Self {
+        Self {
+            time: start_time,
+            clock: Clock::new(start_time),
+        }
+    }
+
+    /// Get the current virtual time.
+    pub fn now(&self) -> X {
+        self.clock.now(self.time)
+    }
+
+    /// Move the real time forward.
+    pub fn advance(&mut self, duration: X::Duration) {
+        self.time = self.time.advance(duration);
+    }
+
+    /// Set the real time.
+    pub fn set(&mut self, time: X) {
+        self.time = time;
+    }
+
+    /// Stop the virtual time.
+    pub fn pause(&mut self) {
+        self.clock.pause(self.time);
+    }
+
+    /// Continue the virtual time from the moment it was paused.
+    pub fn resume(&mut self) {
+        self.clock.resume(self.time);
+    }
+
+    /// Check if the clock is paused.
+    pub fn is_paused(&self) -> bool {
+        self.clock.is_paused()
+    }
+
+    /// Get the virtual time speed relative to the real time.
+    pub fn speed(&self) -> f32 {
+        self.clock.speed()
+    }
+
+    /// Change the virtual time speed, see `Clock::set_speed`.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.clock.set_speed(speed, self.time);
+    }
+
+    /// Sample `count` frames of an animated value.
+    /// Each frame is sampled at the current time, and then the clock is advanced by `frame`.
+    pub fn frames<T>(
+        &mut self,
+        animated: &impl Animated<T, X>,
+        frame: X::Duration,
+        count: usize,
+    ) -> Vec<T> {
+        (0..count)
+            .map(|_| {
+                let value = animated.get(self.now());
+                self.advance(frame);
+                value
+            })
+            .collect()
+    }
+
+    /// Sample frames until the animated value is finished, including the first finished frame.
+    /// At most `max_count` frames are sampled, so infinite animations don't hang a test.
+    pub fn frames_until_finished<T>(
+        &mut self,
+        animated: &impl Animated<T, X>,
+        frame: X::Duration,
+        max_count: usize,
+    ) -> Vec<T> {
+        let mut result = Vec::new();
+        while result.len() < max_count {
+            let now = self.now();
+            result.push(animated.get(now));
+            if animated.is_finished(now) {
+                break;
+            }
+            self.advance(frame);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn real_time_by_default() {
+        let clock = Clock::new(1.0);
+        assert_eq!(clock.now(1.0), 1.0);
+        assert_eq!(clock.now(3.5), 3.5);
+        assert_eq!(clock.now(0.0), 1.0);
+    }
+
+    #[test]
+    fn pause_and_resume() {
+        let mut clock = Clock::new(0.0);
+        clock.pause(2.0);
+        assert!(clock.is_paused());
+        assert_eq!(clock.now(5.0), 2.0);
+        clock.resume(5.0);
+        assert!(!clock.is_paused());
+        assert_eq!(clock.now(6.0), 3.0);
+    }
+
+    #[test]
+    fn speed_change_is_continuous() {
+        let mut clock = Clock::new(0.0);
+        clock.set_speed(2.0, 1.0);
+        assert_eq!(clock.now(1.0), 1.0);
+        assert_eq!(clock.now(2.0), 3.0);
+        clock.set_speed(0.25, 2.0);
+        assert_eq!(clock.now(2.0), 3.0);
+        assert_eq!(clock.now(6.0), 4.0);
+    }
+
+    #[test]
+    fn instant_clock() {
+        let start = Instant::now();
+        let mut clock = Clock::new(start);
+        clock.set_speed(0.5, start);
+        assert_eq!(
+            clock.now(start + Duration::from_secs(2)),
+            start + Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn manual_clock() {
+        let mut clock = ManualClock::new(0.0);
+        clock.advance(1.0);
+        assert_eq!(clock.now(), 1.0);
+        clock.set_speed(2.0);
+        clock.advance(1.0);
+        assert_eq!(clock.now(), 3.0);
+        clock.pause();
+        clock.advance(1.0);
+        assert_eq!(clock.now(), 3.0);
+        clock.resume();
+        clock.set(4.0);
+        assert_eq!(clock.now(), 5.0);
+    }
+
+    #[test]
+    fn manual_clock_frames_until_finished() {
+        let mut clock = ManualClock::new(0.0);
+        let animation = keyframes::line(0.0, 2.0, 1.0).run(0.0);
+        assert_eq!(
+            clock.frames_until_finished(&animation, 0.5, 100),
+            vec![0.0, 1.0, 2.0]
+        );
+        let infinite = keyframes::line(0.0, 2.0, 1.0).repeat().run(0.0);
+        assert_eq!(clock.frames_until_finished(&infinite, 0.5, 3).len(), 3);
+    }
+}
@@ -0,0 +1,516 @@
+//! Import CSS `@keyframes` rules and `transition` declarations as [`KeyframesSpec`],
+//! so existing motion specs can drive animations of any `Mix` type.
+//!
+//! Values are converted by a user-supplied function, and durations are in seconds,
+//! so specs should be compiled with a one-second unit, or with the animation duration for `@keyframes`.
+//!
+//! # Example
+//!
+//! ```
+//! use glissade::css::{parse_keyframes, parse_transition};
+//! use glissade::Keyframes;
+//!
+//! let rules = parse_keyframes(
+//!     "@keyframes fade { from { opacity: 0; } 50% { opacity: 1; animation-timing-function: linear; } to { opacity: 0.5; } }",
+//!     |declarations| declarations.iter()
+//!         .find(|(property, _)| *property == "opacity")
+//!         .and_then(|(_, value)| value.parse::<f32>().ok()),
+//! )
+//! .unwrap();
+//!
+//! let (name, spec) = &rules[0];
+//! assert_eq!(name, "fade");
+//! let keyframes = spec.compile::<f32>(2.0);
+//! assert_eq!(keyframes.get(0.0), 0.0);
+//! assert_eq!(keyframes.get(1.0), 1.0);
+//! assert_eq!(keyframes.get(1.5), 0.75);
+//!
+//! let transitions = parse_transition("transition: opacity 500ms ease-in 0.25s").unwrap();
+//! assert_eq!(transitions[0].property, "opacity");
+//! let keyframes = transitions[0].to_spec(0.0, 1.0).compile::<f32>(1.0);
+//! assert_eq!(keyframes.duration(), 0.75);
+//! assert_eq!(keyframes.get(0.25), 0.0);
+//! ```
+//!
+//! Colors can be parsed with [`CssColor`], e.g. to animate them with the keyframes above.
+
+mod color;
+
+pub use color::CssColor;
+
+use crate::{Easing, KeyframesSpec};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+/// An error that can occur while parsing CSS.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CssError {
+    /// The source can't be parsed.
+    Syntax(&'static str),
+    /// The value conversion function returned `None` for the keyframe with the selector.
+    Value(String),
+    /// The CSS feature is valid, but can't be represented with glissade keyframes.
+    Unsupported(String),
+}
+
+impl Display for CssError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CssError::Syntax(message) => write!(f, "CSS syntax error: {}", message),
+            CssError::Value(selector) => write!(f, "invalid value of keyframe \"{}\"", selector),
+            CssError::Unsupported(feature) => write!(f, "unsupported CSS: {}", feature),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CssError {}
+
+/// A single transition from a `transition` declaration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CssTransition {
+    /// Transitioned property name, `"all"` if it's not specified.
+    pub property: String,
+    /// Duration in seconds.
+    pub duration: f32,
+    pub easing: Easing,
+    /// Delay in seconds.
+    pub delay: f32,
+}
+
+impl CssTransition {
+    /// Describe the transition from one value to another, durations are in seconds.
+    pub fn to_spec<T: Clone>(&self, from: T, to: T) -> KeyframesSpec<T> {
+        let ease = KeyframesSpec::Ease {
+            from: from.clone(),
+            to,
+            duration: self.duration,
+            easing: self.easing.clone(),
+        };
+
+        if self.delay > 0.0 {
+            KeyframesSpec::Sequence(vec![
+                KeyframesSpec::Stay {
+                    value: from,
+                    duration: self.delay,
+                },
+                ease,
+            ])
+        } else {
+            ease
+        }
+    }
+}
+
+/// Parse a `transition` property value, or a whole `transition: ...` declaration.
+/// Each comma-separated transition is returned separately.
+pub fn parse_transition(source: &str) -> Result<Vec<CssTransition>, CssError> {
+    let source = source.trim().trim_end_matches(';');
+    let source = match source.split_once(':') {
+        Some((property, value)) if property.trim() == "transition" => value,
+        _ => source,
+    };
+
+    split_top_level(source, |c| c == ',')
+        .into_iter()
+        .map(|item| {
+            let mut property = None;
+            let mut duration = None;
+            let mut delay = None;
+            let mut easing = None;
+
+            for token in split_top_level(item, char::is_whitespace) {
+                if let Some(time) = parse_time(token) {
+                    if duration.is_none() {
+                        duration = Some(time?);
+                    } else if delay.is_none() {
+                        delay = Some(time?);
+                    } else {
+                        return Err(CssError::Syntax("too many times in transition"));
+                    }
+                } else if let Some(timing_function) = parse_easing(token) {
+                    if easing.replace(timing_function?).is_some() {
+                        return Err(CssError::Syntax("too many timing functions in transition"));
+                    }
+                } else if property.replace(token).is_some() {
+                    return Err(CssError::Syntax("too many properties in transition"));
+                }
+            }
+
+            let delay = delay.unwrap_or(0.0);
+            if delay < 0.0 {
+                return Err(CssError::Unsupported(
+                    "negative transition delay".to_string(),
+                ));
+            }
+
+            Ok(CssTransition {
+                property: property.unwrap_or("all").to_string(),
+                duration: duration.unwrap_or(0.0),
+                easing: easing.unwrap_or_else(ease),
+                delay,
+            })
+        })
+        .collect()
+}
+
+/// Parse a CSS `<easing-function>`, like `ease-in-out`, `cubic-bezier(0.2, 0, 0, 1)`, or `steps(4)`.
+pub fn parse_timing_function(source: &str) -> Result<Easing, CssError> {
+    parse_easing(source.trim()).unwrap_or(Err(CssError::Syntax("unknown timing function")))
+}
+
+/// Parse all `@keyframes` rules of a stylesheet.
+/// Rules are returned with their names, keyframe offsets are converted to durations,
+/// so a spec should be compiled with the animation duration as a unit.
+///
+/// * `value` - Converts keyframe declarations, `(property, value)` pairs, into a value.
+///   `animation-timing-function` declarations are applied by the parser and aren't passed to it.
+///
+/// If there is no `0%` or `100%` keyframe, the closest keyframe value is kept instead.
+pub fn parse_keyframes<T, F>(
+    source: &str,
+    value: F,
+) -> Result<Vec<(String, KeyframesSpec<T>)>, CssError>
+where
+    T: Clone,
+    F: Fn(&[(&str, &str)]) -> Option<T>,
+{
+    let source = remove_comments(source);
+    let mut rest = source.as_str();
+    let mut result = Vec::new();
+
+    while let Some(start) = rest.find("@keyframes") {
+        rest = &rest[start + "@keyframes".len()..];
+        let (name, body, tail) = block(rest)?;
+        let name = name.trim().trim_matches(|c| c == '"' || c == '\'');
+        if name.is_empty() {
+            return Err(CssError::Syntax("@keyframes without a name"));
+        }
+
+        result.push((name.to_string(), keyframes_rule(body, &value)?));
+        rest = tail;
+    }
+
+    Ok(result)
+}
+
+fn keyframes_rule<T, F>(source: &str, value: &F) -> Result<KeyframesSpec<T>, CssError>
+where
+    T: Clone,
+    F: Fn(&[(&str, &str)]) -> Option<T>,
+{
+    let mut frames: Vec<(f32, T, Option<Easing>)> = Vec::new();
+    let mut rest = source;
+
+    while !rest.trim().is_empty() {
+        let (selectors, body, tail) = block(rest)?;
+        rest = tail;
+
+        let mut declarations = Vec::new();
+        let mut easing = None;
+        for declaration in split_top_level(body, |c| c == ';') {
+            let (property, value) = declaration
+                .split_once(':')
+                .ok_or(CssError::Syntax("declaration without a value"))?;
+            let (property, value) = (property.trim(), value.trim());
+            if property == "animation-timing-function" {
+                easing = Some(parse_timing_function(value)?);
+            } else {
+                declarations.push((property, value));
+            }
+        }
+
+        let frame_value =
+            value(&declarations).ok_or_else(|| CssError::Value(selectors.trim().to_string()))?;
+
+        for selector in selectors.split(',') {
+            let offset = parse_offset(selector.trim())?;
+            let frame = (offset, frame_value.clone(), easing.clone());
+            match frames.iter_mut().find(|(o, _, _)| *o == offset) {
+                Some(existing) => *existing = frame,
+                None => frames.push(frame),
+            }
+        }
+    }
+
+    frames.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let (first, last) = match (frames.first(), frames.last()) {
+        (Some(first), Some(last)) => (first.clone(), last.clone()),
+        _ => return Err(CssError::Syntax("@keyframes without keyframes")),
+    };
+
+    let mut segments = Vec::new();
+    if first.0 > 0.0 {
+        segments.push(KeyframesSpec::Stay {
+            value: first.1,
+            duration: first.0,
+        });
+    }
+    for pair in frames.windows(2) {
+        let (offset, from, easing) = &pair[0];
+        let (next_offset, to, _) = &pair[1];
+        segments.push(KeyframesSpec::Ease {
+            from: from.clone(),
+            to: to.clone(),
+            duration: next_offset - offset,
+            easing: easing.clone().unwrap_or_else(ease),
+        });
+    }
+    if last.0 < 1.0 || frames.len() == 1 {
+        segments.push(KeyframesSpec::Stay {
+            value: last.1,
+            duration: 1.0 - last.0,
+        });
+    }
+
+    Ok(if segments.len() == 1 {
+        segments.remove(0)
+    } else {
+        KeyframesSpec::Sequence(segments)
+    })
+}
+
+/// CSS default timing function.
+fn ease() -> Easing {
+    Easing::bezier(0.25, 0.1, 0.25, 1.0)
+}
+
+/// Returns `None` if the source isn't a timing function at all.
+fn parse_easing(source: &str) -> Option<Result<Easing, CssError>> {
+    let easing = match source {
+        "linear" => Easing::Linear,
+        "ease" => ease(),
+        "ease-in" => Easing::bezier(0.42, 0.0, 1.0, 1.0),
+        "ease-out" => Easing::bezier(0.0, 0.0, 0.58, 1.0),
+        "ease-in-out" => Easing::bezier(0.42, 0.0, 0.58, 1.0),
+        "step-end" => Easing::Step(1.0),
+        "step-start" => return Some(Err(CssError::Unsupported(source.to_string()))),
+        _ => {
+            let (name, arguments) = source.strip_suffix(')')?.split_once('(')?;
+            let arguments: Vec<&str> = arguments.split(',').map(str::trim).collect();
+            return match name {
+                "cubic-bezier" => Some(
+                    parse_numbers::<4>(&arguments)
+                        .map(|[x1, y1, x2, y2]| Easing::bezier(x1, y1, x2, y2)),
+                ),
+                "steps" => Some(match arguments.as_slice() {
+                    [steps] | [steps, "end" | "jump-end"] => steps
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|steps| *steps > 0)
+                        .map(|steps| Easing::Step(steps as f32))
+                        .ok_or(CssError::Syntax("invalid steps count")),
+                    _ => Err(CssError::Unsupported(source.to_string())),
+                }),
+                "linear" => Some(Err(CssError::Unsupported(source.to_string()))),
+                _ => None,
+            };
+        }
+    };
+    Some(Ok(easing))
+}
+
+fn parse_numbers<const N: usize>(arguments: &[&str]) -> Result<[f32; N], CssError> {
+    let mut result = [0.0; N];
+    if arguments.len() != N {
+        return Err(CssError::Syntax("wrong number of arguments"));
+    }
+    for (item, argument) in result.iter_mut().zip(arguments) {
+        *item = argument
+            .parse()
+            .map_err(|_| CssError::Syntax("invalid number"))?;
+    }
+    Ok(result)
+}
+
+/// Returns time in seconds, or `None` if the source isn't a time at all.
+fn parse_time(source: &str) -> Option<Result<f32, CssError>> {
+    let (number, divisor) = if let Some(number) = source.strip_suffix("ms") {
+        (number, 1000.0)
+    } else {
+        (source.strip_suffix('s')?, 1.0)
+    };
+
+    if !number.starts_with(|c: char| c.is_ascii_digit() || c == '.' || c == '-' || c == '+') {
+        return None;
+    }
+
+    Some(
+        number
+            .parse::<f32>()
+            .map(|number| number / divisor)
+            .map_err(|_| CssError::Syntax("invalid time")),
+    )
+}
+
+fn parse_offset(selector: &str) -> Result<f32, CssError> {
+    match selector {
+        "from" => Ok(0.0),
+        "to" => Ok(1.0),
+        _ => selector
+            .strip_suffix('%')
+            .and_then(|percent| percent.trim().parse::<f32>().ok())
+            .filter(|percent| (0.0..=100.0).contains(percent))
+            .map(|percent| percent / 100.0)
+            .ok_or(CssError::Syntax("invalid keyframe selector")),
+    }
+}
+
+/// Split `prefix { body } tail` with nested braces in the body.
+fn block(source: &str) -> Result<(&str, &str, &str), CssError> {
+    let open = source.find('{').ok_or(CssError::Syntax("expected '{'"))?;
+    let mut depth = 0;
+    for (i, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let close = open + i;
+                    return Ok((
+                        &source[..open],
+                        &source[open + 1..close],
+                        &source[close + 1..],
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(CssError::Syntax("expected '}'"))
+}
+
+/// Split by separators outside of parentheses, empty items are skipped.
+fn split_top_level(source: &str, is_separator: impl Fn(char) -> bool) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in source.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if depth == 0 && is_separator(c) => {
+                result.push(&source[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    result.push(&source[start..]);
+    result
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn remove_comments(source: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Keyframes;
+
+    fn opacity(declarations: &[(&str, &str)]) -> Option<f32> {
+        declarations
+            .iter()
+            .find(|(property, _)| *property == "opacity")
+            .and_then(|(_, value)| value.parse().ok())
+    }
+
+    #[test]
+    fn transitions() {
+        let transitions = parse_transition(
+            "opacity 0.3s, transform 150ms cubic-bezier(0.2, 0, 0, 1) 50ms, 1s steps(4)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            transitions,
+            vec![
+                CssTransition {
+                    property: "opacity".into(),
+                    duration: 0.3,
+                    easing: ease(),
+                    delay: 0.0,
+                },
+                CssTransition {
+                    property: "transform".into(),
+                    duration: 0.15,
+                    easing: Easing::bezier(0.2, 0.0, 0.0, 1.0),
+                    delay: 0.05,
+                },
+                CssTransition {
+                    property: "all".into(),
+                    duration: 1.0,
+                    easing: Easing::Step(4.0),
+                    delay: 0.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn transition_errors() {
+        assert_eq!(
+            parse_transition("opacity 1s -1s"),
+            Err(CssError::Unsupported("negative transition delay".into()))
+        );
+        assert_eq!(
+            parse_transition("opacity 1s 1s 1s"),
+            Err(CssError::Syntax("too many times in transition"))
+        );
+        assert_eq!(
+            parse_timing_function("steps(2, start)"),
+            Err(CssError::Unsupported("steps(2, start)".into()))
+        );
+    }
+
+    #[test]
+    fn keyframes_without_ends() {
+        let rules = parse_keyframes(
+            "/* pulse */ @keyframes pulse { 25%, 75% { opacity: 1; animation-timing-function: linear } 50% { opacity: 0 } }",
+            opacity,
+        )
+        .unwrap();
+        assert_eq!(rules.len(), 1);
+
+        let keyframes = rules[0].1.compile::<f32>(4.0);
+        assert_eq!(keyframes.duration(), 4.0);
+        assert_eq!(keyframes.get(0.5), 1.0);
+        assert_eq!(keyframes.get(1.5), 0.5);
+        assert_eq!(keyframes.get(2.0), 0.0);
+        assert_eq!(keyframes.get(3.5), 1.0);
+    }
+
+    #[test]
+    fn keyframes_errors() {
+        assert_eq!(
+            parse_keyframes("@keyframes a { from { color: red } }", opacity),
+            Err(CssError::Value("from".into()))
+        );
+        assert_eq!(
+            parse_keyframes("@keyframes a { 120% { opacity: 1 } }", opacity),
+            Err(CssError::Syntax("invalid keyframe selector"))
+        );
+        assert_eq!(
+            parse_keyframes("@keyframes a { from { opacity: 1 }", opacity),
+            Err(CssError::Syntax("expected '}'"))
+        );
+    }
+}
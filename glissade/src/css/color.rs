@@ -0,0 +1,468 @@
+use super::CssError;
+use crate::math::round;
+use crate::{Distance, Mix};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// An sRGB color with alpha, parsed from a CSS color value and formatted back to CSS.
+/// Channels are in `[0, 1]`.
+///
+/// Colors are mixed with premultiplied alpha, the same way CSS transitions do it,
+/// so a transition to `transparent` doesn't fade through black.
+///
+/// # Example
+///
+/// ```
+/// use glissade::css::CssColor;
+/// use glissade::Mix;
+///
+/// let from: CssColor = "#ff0000".parse().unwrap();
+/// let to: CssColor = "rebeccapurple".parse().unwrap();
+/// assert_eq!(from.mix(to, 0.5).to_string(), "#b31a4d");
+///
+/// let faded = from.mix("transparent".parse().unwrap(), 0.5);
+/// assert_eq!(faded.to_string(), "rgba(255, 0, 0, 0.5)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct CssColor {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
+
+impl CssColor {
+    pub const fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Create an opaque color from 8-bit channels.
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba8([red, green, blue, 255])
+    }
+
+    /// Create a color from 8-bit channels.
+    pub fn rgba8([red, green, blue, alpha]: [u8; 4]) -> Self {
+        Self::new(
+            red as f32 / 255.0,
+            green as f32 / 255.0,
+            blue as f32 / 255.0,
+            alpha as f32 / 255.0,
+        )
+    }
+
+    /// Get 8-bit channels, the values are clamped to `[0, 1]` and rounded.
+    pub fn to_rgba8(self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+            .map(|c| round(c.clamp(0.0, 1.0) * 255.0) as u8)
+    }
+
+    /// Parse a CSS color: a hex color (`#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa`),
+    /// a named color like `rebeccapurple` or `transparent`, or `rgb()`/`rgba()`
+    /// in both the legacy comma-separated and the modern space-separated syntax.
+    pub fn parse(source: &str) -> Result<Self, CssError> {
+        let source = source.trim().to_ascii_lowercase();
+
+        if let Some(hex) = source.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+
+        if let Some((name, arguments)) = source
+            .strip_suffix(')')
+            .and_then(|function| function.split_once('('))
+        {
+            return match name.trim() {
+                "rgb" | "rgba" => parse_rgb(arguments),
+                _ => Err(CssError::Unsupported(source.to_string())),
+            };
+        }
+
+        if source == "transparent" {
+            return Ok(Self::default());
+        }
+
+        NAMED_COLORS
+            .binary_search_by(|(name, _)| (*name).cmp(source.as_str()))
+            .map(|index| {
+                let [_, red, green, blue] = NAMED_COLORS[index].1.to_be_bytes();
+                Self::rgb(red, green, blue)
+            })
+            .map_err(|_| CssError::Syntax("unknown color"))
+    }
+}
+
+impl FromStr for CssColor {
+    type Err = CssError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+/// Opaque colors are formatted as `#rrggbb`, and translucent ones as `rgba(r, g, b, a)`.
+impl Display for CssColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let [red, green, blue, alpha] = self.to_rgba8();
+        if alpha == 255 {
+            write!(f, "#{:02x}{:02x}{:02x}", red, green, blue)
+        } else {
+            let alpha = round(self.alpha.clamp(0.0, 1.0) * 1000.0) / 1000.0;
+            write!(f, "rgba({}, {}, {}, {})", red, green, blue, alpha)
+        }
+    }
+}
+
+impl Mix for CssColor {
+    fn mix(self, other: Self, t: f32) -> Self {
+        let alpha = self.alpha.mix(other.alpha, t);
+        if alpha <= 0.0 {
+            return Self::new(
+                self.red.mix(other.red, t),
+                self.green.mix(other.green, t),
+                self.blue.mix(other.blue, t),
+                alpha,
+            );
+        }
+
+        let channel = |a: f32, b: f32| (a * self.alpha).mix(b * other.alpha, t) / alpha;
+        Self::new(
+            channel(self.red, other.red),
+            channel(self.green, other.green),
+            channel(self.blue, other.blue),
+            alpha,
+        )
+    }
+}
+
+impl Distance for CssColor {
+    fn distance(self, other: Self) -> f32 {
+        [self.red, self.green, self.blue, self.alpha].distance([
+            other.red,
+            other.green,
+            other.blue,
+            other.alpha,
+        ])
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<CssColor, CssError> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(CssError::Syntax("invalid hex color"));
+    }
+
+    let digits: Vec<u8> = hex
+        .chars()
+        .filter_map(|c| c.to_digit(16))
+        .map(|digit| digit as u8)
+        .collect();
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 17).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| pair[0] * 16 + pair[1])
+            .collect(),
+        _ => return Err(CssError::Syntax("invalid hex color length")),
+    };
+
+    Ok(CssColor::rgba8([
+        channels[0],
+        channels[1],
+        channels[2],
+        channels.get(3).copied().unwrap_or(255),
+    ]))
+}
+
+fn parse_rgb(arguments: &str) -> Result<CssColor, CssError> {
+    let (channels, alpha): (Vec<&str>, Option<&str>) = if arguments.contains(',') {
+        let mut arguments: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let alpha = (arguments.len() == 4).then(|| arguments.remove(3));
+        (arguments, alpha)
+    } else {
+        let (channels, alpha) = match arguments.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        (channels.split_whitespace().collect(), alpha)
+    };
+
+    if channels.len() != 3 {
+        return Err(CssError::Syntax("rgb() must have three channels"));
+    }
+
+    let channel = |source: &str| parse_number(source, 255.0);
+    Ok(CssColor::new(
+        channel(channels[0])?,
+        channel(channels[1])?,
+        channel(channels[2])?,
+        alpha.map_or(Ok(1.0), |alpha| parse_number(alpha, 1.0))?,
+    ))
+}
+
+/// Parse a number or a percentage into `[0, 1]`, numbers are divided by `max`.
+fn parse_number(source: &str, max: f32) -> Result<f32, CssError> {
+    let value = match source.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().map(|percent| percent / 100.0),
+        None => source.parse::<f32>().map(|number| number / max),
+    };
+    value
+        .map(|value| value.clamp(0.0, 1.0))
+        .map_err(|_| CssError::Syntax("invalid color channel"))
+}
+
+/// CSS named colors, sorted by name for the binary search.
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx_eq;
+
+    #[test]
+    fn named_colors_are_sorted() {
+        assert!(
+            NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "Binary search needs sorted names"
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        let purple = CssColor::rgb(0x66, 0x33, 0x99);
+        assert_eq!(CssColor::parse("RebeccaPurple"), Ok(purple));
+        assert_eq!(CssColor::parse(" #663399 "), Ok(purple));
+        assert_eq!(CssColor::parse("#639"), Ok(purple));
+        assert_eq!(CssColor::parse("rgb(102, 51, 153)"), Ok(purple));
+        assert_eq!(CssColor::parse("rgb(102 51 153)"), Ok(purple));
+        assert_eq!(CssColor::parse("rgba(102, 51, 153, 1)"), Ok(purple));
+        assert_eq!(CssColor::parse("transparent"), Ok(CssColor::default()));
+
+        assert_eq!(
+            CssColor::parse("#66339980").map(CssColor::to_rgba8),
+            Ok([0x66, 0x33, 0x99, 0x80])
+        );
+        assert_eq!(
+            CssColor::parse("rgb(100% 0% 50% / 25%)"),
+            Ok(CssColor::new(1.0, 0.0, 0.5, 0.25))
+        );
+        assert_eq!(
+            CssColor::parse("rgba(300, -10, 0, 0.5)"),
+            Ok(CssColor::new(1.0, 0.0, 0.0, 0.5)),
+            "Channels are clamped"
+        );
+    }
+
+    #[test]
+    fn parsing_errors() {
+        assert_eq!(
+            CssColor::parse("#12345"),
+            Err(CssError::Syntax("invalid hex color length"))
+        );
+        assert_eq!(
+            CssColor::parse("#ggg"),
+            Err(CssError::Syntax("invalid hex color"))
+        );
+        assert_eq!(
+            CssColor::parse("rgb(1, 2)"),
+            Err(CssError::Syntax("rgb() must have three channels"))
+        );
+        assert_eq!(
+            CssColor::parse("rgb(1 2 x)"),
+            Err(CssError::Syntax("invalid color channel"))
+        );
+        assert_eq!(
+            CssColor::parse("hsl(0 100% 50%)"),
+            Err(CssError::Unsupported("hsl(0 100% 50%)".into()))
+        );
+        assert_eq!(
+            CssColor::parse("blurple"),
+            Err(CssError::Syntax("unknown color"))
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(CssColor::rgb(255, 128, 0).to_string(), "#ff8000");
+        assert_eq!(
+            CssColor::new(1.0, 0.0, 0.0, 0.3333).to_string(),
+            "rgba(255, 0, 0, 0.333)"
+        );
+        let color = CssColor::parse("#12345678").unwrap();
+        assert_eq!(
+            CssColor::parse(&color.to_string()).unwrap().to_rgba8()[..3],
+            [0x12, 0x34, 0x56]
+        );
+    }
+
+    #[test]
+    fn premultiplied_mix() {
+        let red = CssColor::parse("red").unwrap();
+        let transparent = CssColor::parse("transparent").unwrap();
+        assert_approx_eq(
+            red.mix(transparent, 0.5),
+            CssColor::new(1.0, 0.0, 0.0, 0.5),
+            1e-6,
+        );
+        assert_approx_eq(
+            red.mix(CssColor::parse("blue").unwrap(), 0.5),
+            CssColor::new(0.5, 0.0, 0.5, 1.0),
+            1e-6,
+        );
+        assert_eq!(transparent.mix(transparent, 0.5), transparent);
+    }
+}
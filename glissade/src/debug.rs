@@ -0,0 +1,301 @@
+//! Tools to look at what an animation actually does, e.g. to diff its shape against a golden file,
+//! or to plot it offline.
+
+use crate::math::ceil_f64;
+use crate::{Animated, Time, TimeDiff};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Sample `animated` from `start` to `end` inclusive, every `step`.
+/// The last sample is taken at `end` even if it's not a whole number of steps from `start`.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{debug, keyframes, Keyframes};
+///
+/// let animation = keyframes::line(0.0, 1.0, 1.0).run(0.0);
+/// let recording = debug::record(&animation, 0.0, 1.0, 0.5);
+/// assert_eq!(recording.to_csv(), "time,value\n0,0\n0.5,0.5\n1,1\n");
+/// ```
+pub fn record<T, X: Time>(
+    animated: &impl Animated<T, X>,
+    start: X,
+    end: X,
+    step: X::Duration,
+) -> Recording<T> {
+    assert!(step > Default::default(), "Recording step must be positive");
+    let mut times = Vec::new();
+    if end >= start {
+        let count = ceil_f64(end.since(start).as_f64() / step.as_f64()) as u64;
+        for i in 0..count {
+            let time = start.advance(step.scale(i as f32));
+            // Times that don't move forward are skipped, e.g. if `step` is below the time precision.
+            if time < end && times.last() < Some(&time) {
+                times.push(time);
+            }
+        }
+        times.push(end);
+    }
+
+    let offsets = times.iter().map(|time| time.since(start).as_f32());
+    Recording {
+        samples: offsets.zip(animated.get_many(&times)).collect(),
+    }
+}
+
+/// Bars of the sparkline from the lowest to the highest.
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Plot a scalar animation over the time range as a line of `width` Unicode bars,
+/// e.g. to eyeball an easing shape in console output or in a test failure message.
+/// Bars are scaled from the lowest to the highest value, a constant value is plotted in the middle,
+/// and NaN values are spaces. The first and the last bars are taken at the ends of the range.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{debug, keyframes, Easing, Keyframes};
+///
+/// let animation = keyframes::ease(0.0, 1.0, 1.0, Easing::QuadraticIn).run(0.0);
+/// assert_eq!(debug::sparkline(&animation, 0.0..1.0, 8), "▁▁▂▂▃▅▆█");
+/// ```
+pub fn sparkline<T: Into<f64>, X: Time>(
+    animated: &impl Animated<T, X>,
+    range: Range<X>,
+    width: usize,
+) -> String {
+    assert!(width > 0, "Sparkline width must be positive");
+    let duration = range.end.saturating_since(range.start);
+    let times: Vec<X> = (0..width)
+        .map(|i| {
+            let t = if width == 1 {
+                0.0
+            } else {
+                i as f32 / (width - 1) as f32
+            };
+            range.start.advance(duration.scale(t))
+        })
+        .collect();
+    let values: Vec<f64> = animated
+        .get_many(&times)
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let finite = values.iter().copied().filter(|value| !value.is_nan());
+    let min = finite.clone().fold(f64::INFINITY, f64::min);
+    let max = finite.fold(f64::NEG_INFINITY, f64::max);
+    values
+        .into_iter()
+        .map(|value| {
+            if value.is_nan() {
+                ' '
+            } else if max > min {
+                let level = (value - min) / (max - min) * (BARS.len() - 1) as f64;
+                BARS[crate::math::round_f64(level) as usize]
+            } else {
+                BARS[BARS.len() / 2 - 1]
+            }
+        })
+        .collect()
+}
+
+/// Values of an animation sampled by `record`.
+/// Times are offsets from the start of the recording in units of `TimeDiff::as_f32`, i.e. seconds for `Duration`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recording<T> {
+    samples: Vec<(f32, T)>,
+}
+
+impl<T> Recording<T> {
+    /// Get time offsets and values of the samples.
+    pub fn samples(&self) -> &[(f32, T)] {
+        &self.samples
+    }
+
+    /// Get values of the samples.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.samples.iter().map(|(_, value)| value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Export samples as CSV with a `time` column, and a column per component of the value.
+    /// A single component is named `value`, several components are named `value0`, `value1`, etc.
+    pub fn to_csv(&self) -> String
+    where
+        T: CsvValue,
+    {
+        let mut columns = Vec::new();
+        let rows: Vec<String> = self
+            .samples
+            .iter()
+            .map(|(time, value)| {
+                columns.clear();
+                value.push_columns(&mut columns);
+                format!("{},{}\n", time, columns.join(","))
+            })
+            .collect();
+
+        let header = match columns.len() {
+            1 => String::from("value"),
+            n => (0..n)
+                .map(|i| format!("value{}", i))
+                .collect::<Vec<_>>()
+                .join(","),
+        };
+        format!("time,{}\n{}", header, rows.concat())
+    }
+
+    /// Export samples as a JSON array of `{ "time": ..., "value": ... }` objects.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String
+    where
+        T: serde::Serialize,
+    {
+        let samples: Vec<_> = self
+            .samples
+            .iter()
+            .map(|(time, value)| serde_json::json!({ "time": time, "value": value }))
+            .collect();
+        serde_json::Value::Array(samples).to_string()
+    }
+}
+
+/// A value that can be written as CSV columns, see `Recording::to_csv`.
+pub trait CsvValue {
+    /// Append a formatted column per component of the value.
+    fn push_columns(&self, columns: &mut Vec<String>);
+}
+
+macro_rules! impl_csv_value {
+    ($($t:ty),*) => {
+        $(
+            impl CsvValue for $t {
+                fn push_columns(&self, columns: &mut Vec<String>) {
+                    columns.push(format!("{}", self));
+                }
+            }
+        )*
+    };
+}
+
+impl_csv_value!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool);
+
+impl<T1: CsvValue, T2: CsvValue> CsvValue for (T1, T2) {
+    fn push_columns(&self, columns: &mut Vec<String>) {
+        self.0.push_columns(columns);
+        self.1.push_columns(columns);
+    }
+}
+
+impl<T1: CsvValue, T2: CsvValue, T3: CsvValue> CsvValue for (T1, T2, T3) {
+    fn push_columns(&self, columns: &mut Vec<String>) {
+        self.0.push_columns(columns);
+        self.1.push_columns(columns);
+        self.2.push_columns(columns);
+    }
+}
+
+impl<T1: CsvValue, T2: CsvValue, T3: CsvValue, T4: CsvValue> CsvValue for (T1, T2, T3, T4) {
+    fn push_columns(&self, columns: &mut Vec<String>) {
+        self.0.push_columns(columns);
+        self.1.push_columns(columns);
+        self.2.push_columns(columns);
+        self.3.push_columns(columns);
+    }
+}
+
+impl<T: CsvValue, const N: usize> CsvValue for [T; N] {
+    fn push_columns(&self, columns: &mut Vec<String>) {
+        for value in self {
+            value.push_columns(columns);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+
+    #[test]
+    fn record_samples() {
+        let animation = keyframes::line::<f32, f32>(0.0, 4.0, 1.0).run(1.0);
+        let recording = record(&animation, 1.0, 2.0, 0.375);
+        assert_eq!(recording.len(), 4, "The end is always recorded");
+        assert_eq!(recording.samples()[1], (0.375, 1.5));
+        assert_eq!(recording.values().last(), Some(&4.0));
+
+        let empty = record(&animation, 2.0, 1.0, 0.5);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn record_below_time_precision() {
+        let start = 1e8_f32;
+        let animation = keyframes::line::<f32, f32>(0.0, 1.0, 32.0).run(start);
+        let recording = record(&animation, start, start + 32.0, 1.0);
+        let offsets: Vec<f32> = recording.samples().iter().map(|(time, _)| *time).collect();
+        assert_eq!(
+            offsets,
+            [0.0, 8.0, 16.0, 24.0, 32.0],
+            "Times are spaced by the f32 precision"
+        );
+    }
+
+    #[test]
+    fn csv_columns() {
+        let animation =
+            keyframes::line::<(f32, [u8; 2]), f32>((0.0, [0, 10]), (1.0, [2, 20]), 1.0).run(0.0);
+        let recording = record(&animation, 0.0, 1.0, 1.0);
+        assert_eq!(
+            recording.to_csv(),
+            "time,value0,value1,value2\n0,0,0,10\n1,1,2,20\n"
+        );
+    }
+
+    #[test]
+    fn sparkline_shapes() {
+        let line = keyframes::line::<f32, f32>(0.0, 7.0, 7.0).run(0.0);
+        assert_eq!(sparkline(&line, 0.0..7.0, 8), "▁▂▃▄▅▆▇█");
+        assert_eq!(
+            sparkline(&line, 7.0..14.0, 3),
+            "▄▄▄",
+            "Constant values are in the middle"
+        );
+        assert_eq!(sparkline(&line, 0.0..7.0, 1), "▄");
+
+        let back = keyframes::line::<f32, f32>(1.0, 0.0, 1.0)
+            .go_to(1.0, 1.0)
+            .run(0.0);
+        assert_eq!(sparkline(&back, 0.0..2.0, 5), "█▅▁▅█");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_export() {
+        let animation = keyframes::line::<f32, f32>(0.0, 1.0, 1.0).run(0.0);
+        let recording = record(&animation, 0.0, 1.0, 1.0);
+        assert_eq!(
+            recording.to_json(),
+            r#"[{"time":0.0,"value":0.0},{"time":1.0,"value":1.0}]"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Recording step must be positive")]
+    fn zero_step() {
+        let animation = keyframes::stay::<f32, f32>(0.0, 1.0).run(0.0);
+        record(&animation, 0.0, 1.0, 0.0);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::math::{exp, ln};
+use crate::{Animated, Distance, Spring, Time, TimeDiff, VectorSpace};
+
+/// A value thrown with a velocity that exponentially slows down, e.g. kinetic scrolling after a flick.
+/// The velocity decays as `velocity * exp(-t / time_constant)`,
+/// so the value comes to rest at `value + velocity * time_constant`.
+///
+/// With bounds, the value that crosses a bound (or starts outside) is pulled back to it with a spring,
+/// like a rubber band. Bounds are meant for scalar values, e.g. a scroll offset.
+///
+/// Parameters are in units of `TimeDiff::as_f32`, i.e. seconds for `Duration`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Decay<T, X: Time> {
+    start_time: X,
+    value: T,
+    velocity: T,
+    time_constant: f32,
+    rest_delta: f32,
+    bounds: Option<(T, T)>,
+    bounce_response: f32,
+}
+
+impl<T: VectorSpace + Distance, X: Time> Decay<T, X> {
+    /// Start decaying from `value` with `velocity` at `start_time`.
+    /// The default time constant is `0.5`, close to the normal scroll deceleration on touch screens.
+    pub fn new(value: T, velocity: T, start_time: X) -> Self {
+        Self {
+            start_time,
+            value,
+            velocity,
+            time_constant: 0.5,
+            rest_delta: 0.001,
+            bounds: None,
+            bounce_response: 0.4,
+        }
+    }
+
+    /// Set the time it takes the velocity to drop `e` times, longer time constants glide further.
+    pub fn with_time_constant(mut self, time_constant: f32) -> Self {
+        assert!(
+            time_constant > 0.0 && time_constant.is_finite(),
+            "Decay time constant must be positive"
+        );
+        self.time_constant = time_constant;
+        self
+    }
+
+    /// Set the distance from the rest value at which the motion stops, `0.001` by default.
+    pub fn with_rest_delta(mut self, rest_delta: f32) -> Self {
+        assert!(rest_delta > 0.0, "Decay rest delta must be positive");
+        self.rest_delta = rest_delta;
+        self
+    }
+
+    /// Keep the value within `min..=max`, crossing a bound starts a critically damped spring back to it.
+    pub fn with_bounds(mut self, min: T, max: T) -> Self {
+        self.bounds = Some((min, max));
+        self
+    }
+
+    /// Set the response of the spring that pulls the value back to a bound, `0.4` by default.
+    /// See `Spring::with_response`.
+    pub fn with_bounce_response(mut self, response: f32) -> Self {
+        assert!(
+            response > 0.0 && response.is_finite(),
+            "Decay bounce response must be positive"
+        );
+        self.bounce_response = response;
+        self
+    }
+
+    /// Get the value where the motion comes to rest.
+    pub fn target(&self) -> T {
+        match self.bounce() {
+            Some((_, spring)) => spring.target(),
+            None => self.rest_value(),
+        }
+    }
+
+    /// Get the velocity at a specific time, per unit of `TimeDiff::as_f32`.
+    pub fn velocity(&self, current_time: X) -> T {
+        let elapsed = self.elapsed(current_time);
+        match self.bounce() {
+            Some((start, spring)) if elapsed >= start => spring.velocity(elapsed),
+            _ if self.is_finished(current_time) => T::zero(),
+            _ => self
+                .velocity
+                .clone()
+                .scale(exp(-elapsed / self.time_constant)),
+        }
+    }
+
+    fn elapsed(&self, current_time: X) -> f32 {
+        current_time.saturating_since(self.start_time).as_f32()
+    }
+
+    /// The value where the decay would stop without bounds.
+    fn rest_value(&self) -> T {
+        self.value
+            .clone()
+            .add(self.velocity.clone().scale(self.time_constant))
+    }
+
+    /// The elapsed time when the value hits a bound, and the spring pulling it back,
+    /// the spring time is the elapsed time since the start.
+    fn bounce(&self) -> Option<(f32, Spring<T, f32>)> {
+        let (min, max) = self.bounds.clone()?;
+        let spring = |value: T, velocity: T, start: f32, bound: T| {
+            let mut spring = Spring::new(value)
+                .with_response(self.bounce_response, 1.0)
+                .with_rest_delta(self.rest_delta);
+            spring.set_velocity(velocity, start);
+            spring.set_target(bound, start);
+            (start, spring)
+        };
+
+        if let Some(bound) = outside(&self.value, &min, &max) {
+            return Some(spring(
+                self.value.clone(),
+                self.velocity.clone(),
+                0.0,
+                bound,
+            ));
+        }
+
+        let bound = outside(&self.rest_value(), &min, &max)?;
+        let travel = self.velocity.clone().distance(T::zero()) * self.time_constant;
+        let ratio = bound.clone().distance(self.value.clone()) / travel;
+        let start = -self.time_constant * ln(1.0 - ratio.min(1.0));
+        let velocity = self
+            .velocity
+            .clone()
+            .scale(exp(-start / self.time_constant));
+        Some(spring(bound.clone(), velocity, start, bound))
+    }
+}
+
+/// Get the nearest bound if the value is out of bounds.
+fn outside<T: Distance + Clone>(value: &T, min: &T, max: &T) -> Option<T> {
+    let to_min = value.clone().distance(min.clone());
+    let to_max = value.clone().distance(max.clone());
+    if to_min + to_max <= min.clone().distance(max.clone()) * (1.0 + f32::EPSILON) {
+        None
+    } else if to_min < to_max {
+        Some(min.clone())
+    } else {
+        Some(max.clone())
+    }
+}
+
+impl<T: VectorSpace + Distance, X: Time> Animated<T, X> for Decay<T, X> {
+    fn get(&self, current_time: X) -> T {
+        let elapsed = self.elapsed(current_time);
+        match self.bounce() {
+            Some((start, spring)) if elapsed >= start => spring.get(elapsed),
+            _ if self.is_finished(current_time) => self.rest_value(),
+            _ => {
+                let moved = 1.0 - exp(-elapsed / self.time_constant);
+                self.value
+                    .clone()
+                    .add(self.velocity.clone().scale(self.time_constant * moved))
+            }
+        }
+    }
+
+    fn is_finished(&self, current_time: X) -> bool {
+        let elapsed = self.elapsed(current_time);
+        match self.bounce() {
+            Some((start, spring)) => elapsed >= start && spring.is_finished(elapsed),
+            None => {
+                let remaining = self.velocity.clone().distance(T::zero())
+                    * self.time_constant
+                    * exp(-elapsed / self.time_constant);
+                remaining <= self.rest_delta
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::abs;
+
+    #[test]
+    fn decays_to_rest_value() {
+        let decay = Decay::new(10.0f32, 100.0, 0.0f32).with_time_constant(0.5);
+        assert_eq!(decay.get(0.0), 10.0);
+        assert_eq!(decay.target(), 60.0);
+        assert!(abs(decay.get(0.5) - (10.0 + 50.0 * (1.0 - exp(-1.0)))) < 1e-3);
+        assert!(abs(decay.velocity(0.5) - 100.0 * exp(-1.0)) < 1e-3);
+        assert!(!decay.is_finished(1.0));
+        assert!(decay.is_finished(10.0));
+        assert_eq!(decay.get(10.0), 60.0);
+        assert_eq!(decay.velocity(10.0), 0.0);
+    }
+
+    #[test]
+    fn bounces_back_from_bound() {
+        let decay = Decay::new(10.0f32, 100.0, 0.0f32).with_bounds(0.0, 40.0);
+        assert_eq!(decay.target(), 40.0);
+
+        let crossing = -0.5 * ln(1.0 - 30.0 / 50.0);
+        assert!(decay.get(crossing - 0.01) < 40.0);
+        assert!(
+            decay.get(crossing + 0.05) > 40.0,
+            "The value overshoots the bound"
+        );
+        assert!(!decay.is_finished(crossing + 0.05));
+        assert!(decay.is_finished(10.0));
+        assert_eq!(decay.get(10.0), 40.0);
+    }
+
+    #[test]
+    fn starts_out_of_bounds() {
+        let decay = Decay::new(-20.0f32, 0.0, 0.0f32).with_bounds(0.0, 40.0);
+        assert_eq!(decay.target(), 0.0);
+        assert_eq!(decay.get(0.0), -20.0);
+        assert!(decay.get(0.2) > -20.0);
+        assert_eq!(decay.get(10.0), 0.0);
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let decay = Decay::new(10.0f32, -10.0, 0.0f32).with_bounds(0.0, 40.0);
+        assert_eq!(decay.target(), 5.0);
+        assert_eq!(decay.get(10.0), 5.0);
+    }
+}
@@ -1,6 +1,9 @@
+use crate::math::{abs, abs_f64, sqrt};
+
 /// Distance trait for calculating the distance between two values.
 /// It's necessary for animation along a path in `Keyframes::poly_to`.
 /// The lib provides implementations for primitive types and tuples.
+/// NaN components give a NaN distance, infinite ones give an infinite distance.
 pub trait Distance {
     /// Calculate the distance between two values.
     fn distance(self, other: Self) -> f32;
@@ -8,13 +11,13 @@ pub trait Distance {
 
 impl Distance for f32 {
     fn distance(self, other: f32) -> f32 {
-        (self - other).abs()
+        abs(self - other)
     }
 }
 
 impl Distance for f64 {
     fn distance(self, other: f64) -> f32 {
-        (self - other).abs() as f32
+        abs_f64(self - other) as f32
     }
 }
 
@@ -62,7 +65,7 @@ where
     fn distance(self, other: Self) -> f32 {
         let v1 = self.0.distance(other.0);
         let v2 = self.1.distance(other.1);
-        (v1 * v1 + v2 * v2).sqrt()
+        sqrt(v1 * v1 + v2 * v2)
     }
 }
 
@@ -76,7 +79,7 @@ where
         let v1 = self.0.distance(other.0);
         let v2 = self.1.distance(other.1);
         let v3 = self.2.distance(other.2);
-        (v1 * v1 + v2 * v2 + v3 * v3).sqrt()
+        sqrt(v1 * v1 + v2 * v2 + v3 * v3)
     }
 }
 
@@ -92,16 +95,17 @@ where
         let v2 = self.1.distance(other.1);
         let v3 = self.2.distance(other.2);
         let v4 = self.3.distance(other.3);
-        (v1 * v1 + v2 * v2 + v3 * v3 + v4 * v4).sqrt()
+        sqrt(v1 * v1 + v2 * v2 + v3 * v3 + v4 * v4)
     }
 }
 
 impl<T: Distance + Clone, const N: usize> Distance for [T; N] {
     fn distance(self, other: Self) -> f32 {
-        self.into_iter()
-            .zip(other)
-            .map(|(a, b)| a.distance(b))
-            .fold(0.0, |acc, x| acc + x * x)
-            .sqrt()
+        sqrt(
+            self.into_iter()
+                .zip(other)
+                .map(|(a, b)| a.distance(b))
+                .fold(0.0, |acc, x| acc + x * x),
+        )
     }
 }
@@ -0,0 +1,58 @@
+use crate::Time;
+
+/// DOM high-resolution timestamp in milliseconds,
+/// as returned by `performance.now()` and passed to `requestAnimationFrame` callbacks.
+/// Its duration is also a number of milliseconds.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Animated, DomTime, Keyframes};
+///
+/// let animation = keyframes::line(0.0, 10.0, 1000.0).run(DomTime(500.0));
+/// assert_eq!(animation.get(DomTime(1000.0)), 5.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct DomTime(pub f64);
+
+impl DomTime {
+    /// Get the current time with `performance.now()`.
+    /// Panics if it's called outside a browser window.
+    pub fn now() -> Self {
+        let performance = web_sys::window()
+            .and_then(|window| window.performance())
+            .expect("DomTime::now: performance is not available");
+        DomTime(performance.now())
+    }
+}
+
+impl From<f64> for DomTime {
+    fn from(milliseconds: f64) -> Self {
+        DomTime(milliseconds)
+    }
+}
+
+impl From<DomTime> for f64 {
+    fn from(time: DomTime) -> Self {
+        time.0
+    }
+}
+
+impl Time for DomTime {
+    type Duration = f64;
+    fn since(self, earlier: Self) -> f64 {
+        self.0.since(earlier.0)
+    }
+
+    fn checked_since(self, earlier: Self) -> Option<f64> {
+        self.0.checked_since(earlier.0)
+    }
+
+    fn advance(self, duration: f64) -> Self {
+        DomTime(self.0.advance(duration))
+    }
+
+    fn checked_advance(self, duration: f64) -> Option<Self> {
+        self.0.checked_advance(duration).map(DomTime)
+    }
+}
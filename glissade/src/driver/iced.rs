@@ -0,0 +1,40 @@
+use crate::Animated;
+use iced_runtime::core::time::Instant;
+use iced_runtime::futures::Subscription;
+use iced_runtime::window;
+
+/// Subscribe to window frames while the animation is running, to redraw it on every frame.
+/// When the animation is finished, no frames are requested, so an idle application doesn't redraw.
+/// Several animations can be combined into a tuple or with `Animated::join`.
+/// * `time` - The current time, usually the last frame time or `Instant::now()`.
+///
+/// # Example
+///
+/// ```no_run
+/// use glissade::driver::iced::frames;
+/// use glissade::{keyframes, Animated, Animation, Keyframes};
+/// use iced_runtime::core::time::Instant;
+/// use iced_runtime::futures::Subscription;
+///
+/// enum Message {
+///     Frame(Instant),
+/// }
+///
+/// struct App<K: Keyframes<f32, Instant>> {
+///     opacity: Animation<f32, Instant, K>,
+///     now: Instant,
+/// }
+///
+/// impl<K: Keyframes<f32, Instant>> App<K> {
+///     fn subscription(&self) -> Subscription<Message> {
+///         frames(&self.opacity, self.now).map(Message::Frame)
+///     }
+/// }
+/// ```
+pub fn frames<T>(animated: &impl Animated<T, Instant>, time: Instant) -> Subscription<Instant> {
+    if animated.is_finished(time) {
+        Subscription::none()
+    } else {
+        window::frames()
+    }
+}
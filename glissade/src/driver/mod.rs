@@ -0,0 +1,11 @@
+//! Frame drivers, utilities that call an animation update on every frame.
+
+#[cfg(feature = "iced")]
+pub mod iced;
+mod ticker;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+#[cfg(feature = "web")]
+pub mod web;
+
+pub use ticker::Ticker;
@@ -0,0 +1,156 @@
+use crate::{Animated, Time};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Frame ticker for redraw loops, for example, winit `RedrawRequested` events.
+/// It converts real frame times into animation timestamps, clamping long frames,
+/// so after a hitch animations continue from where they were instead of jumping forward.
+/// It also tracks registered animations to tell if another frame should be requested.
+///
+/// Animations should be started and sampled with the timestamps returned by `tick`,
+/// not with the real time, because the two drift apart after clamped frames.
+///
+/// # Example
+///
+/// ```
+/// use glissade::driver::Ticker;
+/// use glissade::{keyframes, Animated, Keyframes};
+///
+/// let mut ticker = Ticker::new(0.0, 0.5);
+/// let animation = keyframes::line(0.0, 1.0, 1.0).run(ticker.time());
+/// ticker.watch(animation);
+///
+/// // A regular frame
+/// let time = ticker.tick(0.25);
+/// assert_eq!(time, 0.25);
+///
+/// // A hitch, the frame is clamped to 0.5
+/// let time = ticker.tick(2.0);
+/// assert_eq!(time, 0.75);
+/// assert_eq!(ticker.delta(), 0.5);
+///
+/// // The animation isn't finished yet, so another frame should be requested
+/// assert!(ticker.needs_frame());
+/// ```
+pub struct Ticker<X: Time + 'static> {
+    max_delta: X::Duration,
+    /// Real time of the last tick.
+    last_tick: X,
+    /// Animation timestamp of the last tick.
+    time: X,
+    delta: X::Duration,
+    watched: Vec<Box<dyn Fn(X) -> bool>>,
+}
+
+impl<X: Time + 'static> Ticker<X> {
+    /// Create a new ticker.
+    /// * `start_time` - The current real time, it's also the first animation timestamp.
+    /// * `max_delta` - The maximum time between two frames, longer frames are clamped to it.
+    pub fn new(start_time: X, max_delta: X::Duration) -> Self {
+        Self {
+            max_delta,
+            last_tick: start_time,
+            time: start_time,
+            delta: Default::default(),
+            watched: Vec::new(),
+        }
+    }
+
+    /// Start a new frame at the real `time`, and get its animation timestamp.
+    pub fn tick(&mut self, time: X) -> X {
+        let delta = time.saturating_since(self.last_tick);
+        self.delta = if delta > self.max_delta {
+            self.max_delta
+        } else {
+            delta
+        };
+        self.last_tick = time;
+        self.time = self.time.advance(self.delta);
+        self.time
+    }
+
+    /// Get the animation timestamp of the last frame.
+    pub fn time(&self) -> X {
+        self.time
+    }
+
+    /// Get the clamped time between the last two frames.
+    pub fn delta(&self) -> X::Duration {
+        self.delta
+    }
+
+    /// Get the maximum time between two frames.
+    pub fn max_delta(&self) -> X::Duration {
+        self.max_delta
+    }
+
+    /// Keep requesting frames until the animation is finished.
+    pub fn watch<T>(&mut self, animation: impl Animated<T, X> + 'static) {
+        self.watched
+            .push(Box::new(move |time| animation.is_finished(time)));
+    }
+
+    /// Check if any watched animation is unfinished at the last frame time.
+    /// Finished animations are forgotten.
+    pub fn needs_frame(&mut self) -> bool {
+        let time = self.time;
+        self.watched.retain(|is_finished| !is_finished(time));
+        !self.watched.is_empty()
+    }
+}
+
+impl<X: Time + Debug + 'static> Debug for Ticker<X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ticker")
+            .field("max_delta", &self.max_delta)
+            .field("last_tick", &self.last_tick)
+            .field("time", &self.time)
+            .field("delta", &self.delta)
+            .field("watched", &self.watched.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+
+    #[test]
+    fn clamps_long_frames() {
+        let mut ticker = Ticker::new(10.0, 0.5);
+        assert_eq!(ticker.tick(10.25), 10.25);
+        assert_eq!(ticker.delta(), 0.25);
+        assert_eq!(ticker.tick(15.0), 10.75);
+        assert_eq!(ticker.delta(), 0.5);
+        assert_eq!(ticker.tick(15.25), 11.0);
+    }
+
+    #[test]
+    fn time_going_backwards() {
+        let mut ticker = Ticker::new(10.0, 0.5);
+        assert_eq!(ticker.tick(9.0), 10.0);
+        assert_eq!(ticker.delta(), 0.0);
+        assert_eq!(ticker.tick(9.25), 10.25);
+    }
+
+    #[test]
+    fn needs_frame_while_unfinished() {
+        let mut ticker = Ticker::new(0.0, 1.0);
+        assert!(!ticker.needs_frame());
+
+        ticker.watch(keyframes::line(0.0, 1.0, 1.0).run(0.0));
+        ticker.watch(keyframes::line(0.0, 1.0, 2.0).run(0.0));
+        assert!(ticker.needs_frame());
+
+        ticker.tick(1.0);
+        assert!(ticker.needs_frame());
+
+        ticker.tick(2.0);
+        assert!(!ticker.needs_frame());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Animated, Timer};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, Sleep};
+
+/// A tokio timer that ticks every `period`, so `Animated::finished` can be awaited in tokio tasks.
+/// The time is `Instant::now()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TokioTimer {
+    period: Duration,
+}
+
+impl TokioTimer {
+    /// Create a timer that checks animations every `period`, e.g. a frame duration.
+    pub fn new(period: Duration) -> Self {
+        assert!(!period.is_zero(), "TokioTimer period must be positive");
+        Self { period }
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+}
+
+impl Timer<Instant> for TokioTimer {
+    type Tick = Sleep;
+
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn tick(&self) -> Sleep {
+        tokio::time::sleep(self.period)
+    }
+}
+
+/// A destination of animation values, see `spawn_animation`.
+/// It's implemented for callbacks, and for std and tokio channel senders.
+pub trait AnimationSink<T>: Send + 'static {
+    /// Pass the value, returns `false` to stop the animation, e.g. if the receiver is dropped.
+    fn send_value(&mut self, value: T) -> bool;
+}
+
+impl<T, F: FnMut(T) + Send + 'static> AnimationSink<T> for F {
+    fn send_value(&mut self, value: T) -> bool {
+        self(value);
+        true
+    }
+}
+
+impl<T: Send + 'static> AnimationSink<T> for std::sync::mpsc::Sender<T> {
+    fn send_value(&mut self, value: T) -> bool {
+        self.send(value).is_ok()
+    }
+}
+
+impl<T: Send + 'static> AnimationSink<T> for mpsc::UnboundedSender<T> {
+    fn send_value(&mut self, value: T) -> bool {
+        self.send(value).is_ok()
+    }
+}
+
+/// Values are dropped while the channel is full, so a slow receiver skips frames.
+impl<T: Send + 'static> AnimationSink<T> for mpsc::Sender<T> {
+    fn send_value(&mut self, value: T) -> bool {
+        !matches!(
+            self.try_send(value),
+            Err(mpsc::error::TrySendError::Closed(_))
+        )
+    }
+}
+
+/// Spawn a tokio task that samples the animation every `rate` and passes the values to the sink,
+/// e.g. for server-side or terminal UI animation without a render loop.
+/// The task stops after passing the value of the finished animation, or when the sink returns `false`,
+/// and it can be stopped earlier with `JoinHandle::abort`. Late ticks are skipped, not bunched up.
+///
+/// Panics if it's called outside a tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example() {
+/// use glissade::driver::tokio::spawn_animation;
+/// use glissade::{keyframes, Keyframes};
+/// use std::time::{Duration, Instant};
+///
+/// let animation = keyframes::line(0.0, 100.0, Duration::from_secs(1)).run(Instant::now());
+/// let task = spawn_animation(animation, Duration::from_millis(50), |progress: f32| {
+///     println!("{:.0}%", progress);
+/// });
+/// task.await.unwrap();
+/// # }
+/// ```
+pub fn spawn_animation<T, A, S>(animated: A, rate: Duration, mut sink: S) -> JoinHandle<()>
+where
+    T: Send + 'static,
+    A: Animated<T, Instant> + Send + 'static,
+    S: AnimationSink<T>,
+{
+    assert!(!rate.is_zero(), "spawn_animation: rate must be positive");
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(rate);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            let time = Instant::now();
+            if !sink.send_value(animated.get(time)) || animated.is_finished(time) {
+                break;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+    use std::sync::{Arc, Mutex};
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn awaits_animation() {
+        let runtime = runtime();
+        let timer = TokioTimer::new(Duration::from_millis(5));
+        let start = Instant::now();
+        let animation = keyframes::line(0.0, 1.0, Duration::from_millis(30)).run(start);
+
+        runtime.block_on(animation.finished(timer));
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert!(animation.is_finished(Instant::now()));
+    }
+
+    #[test]
+    fn spawned_animation() {
+        let runtime = runtime();
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let sink = {
+            let values = values.clone();
+            move |value: f32| values.lock().unwrap().push(value)
+        };
+
+        runtime.block_on(async {
+            let animation =
+                keyframes::line(0.0, 1.0, Duration::from_millis(30)).run(Instant::now());
+            spawn_animation(animation, Duration::from_millis(5), sink)
+                .await
+                .unwrap();
+        });
+
+        let values = values.lock().unwrap();
+        assert!(values.len() >= 2, "Sampled several times: {:?}", values);
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(values.last(), Some(&1.0), "Ends with the final value");
+    }
+
+    #[test]
+    fn stops_without_receiver() {
+        let runtime = runtime();
+        runtime.block_on(async {
+            let (sender, mut receiver) = mpsc::unbounded_channel::<f32>();
+            let animation = keyframes::line(0.0, 1.0, Duration::from_millis(10))
+                .repeat()
+                .run(Instant::now());
+            let task = spawn_animation(animation, Duration::from_millis(1), sender);
+
+            assert!(receiver.recv().await.is_some());
+            drop(receiver);
+            task.await.unwrap();
+        });
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{DomTime, Timer};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen(
+    inline_js = "export function animation_loop(callback) { let request_id = -1; let stopped = false; function loop(time) { callback(time); if (!stopped) { request_id = requestAnimationFrame(loop); } }; loop(performance.now()); return () => { stopped = true; cancelAnimationFrame(request_id); }; }"
+)]
+extern "C" {
+    fn animation_loop(callback: &Closure<dyn FnMut(f64)>) -> Function;
+}
+
+/// `requestAnimationFrame` loop. The callback is called immediately,
+/// and then once per frame with the frame timestamp, until the loop is stopped or dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use glissade::driver::web::AnimationLoop;
+/// use glissade::{keyframes, Animated, DomTime, Keyframes};
+///
+/// let animation = keyframes::line(0.0, 1.0, 1000.0).run(DomTime::now());
+/// let animation_loop = AnimationLoop::new(move |time| {
+///     let opacity: f64 = animation.get(time);
+///     // Render the frame
+/// });
+/// // The loop is stopped when `animation_loop` is dropped
+/// std::mem::forget(animation_loop);
+/// ```
+pub struct AnimationLoop {
+    callback: Box<Closure<dyn FnMut(f64)>>,
+    stop: Function,
+}
+
+impl AnimationLoop {
+    pub fn new<F>(mut callback: F) -> Self
+    where
+        F: FnMut(DomTime) + 'static,
+    {
+        let callback: Box<Closure<dyn FnMut(f64)>> =
+            Box::new(Closure::new(move |time: f64| callback(DomTime(time))));
+        AnimationLoop {
+            stop: animation_loop(callback.as_ref()),
+            callback,
+        }
+    }
+
+    /// Stop requesting new frames. It's safe to call it from the callback and more than once.
+    pub fn stop(&self) {
+        self.stop.call0(&JsValue::NULL).unwrap();
+    }
+}
+
+impl core::fmt::Debug for AnimationLoop {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimationLoop").finish_non_exhaustive()
+    }
+}
+
+impl Drop for AnimationLoop {
+    fn drop(&mut self) {
+        self.stop();
+        *self.callback = Closure::new(|_: f64| {});
+    }
+}
+
+/// A timer that ticks on every animation frame, so `Animated::finished`
+/// can be awaited in browser tasks, e.g. spawned with `wasm-bindgen-futures`.
+/// The time is `DomTime::now()`.
+///
+/// # Example
+///
+/// ```no_run
+/// use glissade::driver::web::FrameTimer;
+/// use glissade::{keyframes, Animated, DomTime, Keyframes};
+///
+/// async fn fade_in() {
+///     let animation = keyframes::line(0.0, 1.0, 300.0).run(DomTime::now());
+///     animation.finished(FrameTimer).await;
+///     // Continue after the fade in
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimer;
+
+impl Timer<DomTime> for FrameTimer {
+    type Tick = NextFrame;
+
+    fn now(&self) -> DomTime {
+        DomTime::now()
+    }
+
+    fn tick(&self) -> NextFrame {
+        NextFrame::default()
+    }
+}
+
+#[derive(Default)]
+struct NextFrameState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+/// A future that resolves on the next `requestAnimationFrame` callback.
+/// The frame request is sent on the first poll, and canceled if the future is dropped before it.
+#[derive(Default)]
+pub struct NextFrame {
+    state: Rc<RefCell<NextFrameState>>,
+    request_id: Option<i32>,
+    callback: Option<Closure<dyn FnMut(f64)>>,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.borrow_mut();
+        if state.done {
+            return Poll::Ready(());
+        }
+        state.waker = Some(cx.waker().clone());
+        drop(state);
+
+        if self.request_id.is_none() {
+            let state = self.state.clone();
+            let callback: Closure<dyn FnMut(f64)> = Closure::new(move |_: f64| {
+                let mut state = state.borrow_mut();
+                state.done = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+            let request_id = web_sys::window()
+                .expect("NextFrame: window is not available")
+                .request_animation_frame(callback.as_ref().unchecked_ref())
+                .expect("NextFrame: requestAnimationFrame failed");
+            self.request_id = Some(request_id);
+            self.callback = Some(callback);
+        }
+        Poll::Pending
+    }
+}
+
+impl core::fmt::Debug for NextFrame {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NextFrame")
+            .field("done", &self.state.borrow().done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for NextFrame {
+    fn drop(&mut self) {
+        if let Some(request_id) = self.request_id {
+            if !self.state.borrow().done {
+                if let Some(window) = web_sys::window() {
+                    let _ = window.cancel_animation_frame(request_id);
+                }
+            }
+        }
+    }
+}
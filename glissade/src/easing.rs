@@ -1,10 +1,15 @@
+use crate::math::{abs, floor, floor_f64};
 use crate::smooth_array::SmoothArray;
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Sub};
 
 const BEZIER_POINTS_COUNT: usize = 128;
+const BEZIER_FIT_SAMPLES: usize = 32;
 
 /// The easing functions are used to provide a smooth transition between two values over time.
 /// See: [https://easings.net/](https://easings.net/) for more information.
 #[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Easing {
     /// <div>
     ///     <img style="width: 102px; height: 102px;" src="data:image/svg+xml;base64,PHN2ZyBoZWlnaHQ9IjEwMCIgd2lkdGg9IjEwMCIgeG1sbnM9Imh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnIj4KICA8cmVjdCB3aWR0aD0iMTAwIiBoZWlnaHQ9IjEwMCIgZmlsbD0icmdiYSgwLCAwLCAwLCAwLjEyKSIvPgogIDxwb2x5Z29uIHBvaW50cz0iMCwgMTAwIDEwMCwgMCIgc3R5bGU9InN0cm9rZTogYmxhY2s7IHN0cm9rZS13aWR0aDogMTsgZmlsbDogbm9uZTsiIC8+Cjwvc3ZnPg=="/>
@@ -60,7 +65,9 @@ pub enum Easing {
     Step(f32),
 
     /// Easing described by a table of values. Values in between are interpolated.
-    /// For example, `Easing::Tabular(vec![0.0, 0.1, 0.2, 0.4, 0.8, 1.0].into())`
+    /// For example, `Easing::Tabular(vec![0.0, 0.1, 0.2, 0.4, 0.8, 1.0].into())`.
+    /// Values at uneven positions are passed as `(t, value)` pairs,
+    /// e.g. `Easing::Tabular(vec![(0.0, 0.0), (0.1, 0.5), (1.0, 1.0)].into())`.
     Tabular(SmoothArray),
 
     /// <div>
@@ -69,54 +76,120 @@ pub enum Easing {
     None,
 }
 
+/// Float types the easing functions can be computed in.
+trait EaseFloat:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    fn from_f32(v: f32) -> Self;
+    fn to_f32(self) -> f32;
+    fn floor(self) -> Self;
+    fn clamp_unit(self) -> Self;
+}
+
+impl EaseFloat for f32 {
+    fn from_f32(v: f32) -> f32 {
+        v
+    }
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    fn floor(self) -> f32 {
+        floor(self)
+    }
+
+    fn clamp_unit(self) -> f32 {
+        self.clamp(0.0, 1.0)
+    }
+}
+
+impl EaseFloat for f64 {
+    fn from_f32(v: f32) -> f64 {
+        v as f64
+    }
+
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    fn floor(self) -> f64 {
+        floor_f64(self)
+    }
+
+    fn clamp_unit(self) -> f64 {
+        self.clamp(0.0, 1.0)
+    }
+}
+
 impl Easing {
+    /// Map the linear progress `t` to the eased one.
+    /// `t` is clamped to [0, 1], so infinite values give the start or the end.
+    /// NaN is propagated, and panics in debug builds.
     pub fn ease(&self, t: f32) -> f32 {
-        let t = t.clamp(0.0, 1.0);
+        self.ease_in(t)
+    }
+
+    /// Same as `ease`, but computed in `f64`, for long or precise animations
+    /// where `f32` quantization of `t` is visible. `Tabular` easing is still computed in `f32`.
+    pub fn ease_f64(&self, t: f64) -> f64 {
+        self.ease_in(t)
+    }
+
+    fn ease_in<F: EaseFloat>(&self, t: F) -> F {
+        debug_assert!(t.partial_cmp(&t).is_some(), "Easing::ease: t is NaN");
+        let c = F::from_f32;
+        let t = t.clamp_unit();
 
         match self {
             Easing::Linear => t,
             Easing::QuadraticIn => t * t,
-            Easing::QuadraticOut => t * (2.0 - t),
+            Easing::QuadraticOut => t * (c(2.0) - t),
             Easing::QuadraticInOut => {
-                if t < 0.5 {
-                    2.0 * t * t
+                if t < c(0.5) {
+                    c(2.0) * t * t
                 } else {
-                    let t = -2.0 * t + 2.0;
-                    1.0 - t * t * 0.5
+                    let t = c(-2.0) * t + c(2.0);
+                    c(1.0) - t * t * c(0.5)
                 }
             }
             Easing::CubicIn => t * t * t,
             Easing::CubicOut => {
-                let t = 1.0 - t;
-                1.0 - t * t * t
+                let t = c(1.0) - t;
+                c(1.0) - t * t * t
             }
             Easing::CubicInOut => {
-                if t < 0.5 {
-                    4.0 * t * t * t
+                if t < c(0.5) {
+                    c(4.0) * t * t * t
                 } else {
-                    let t = -2.0 * t + 2.0;
-                    1.0 - t * t * t / 2.0
+                    let t = c(-2.0) * t + c(2.0);
+                    c(1.0) - t * t * t / c(2.0)
                 }
             }
             Easing::QuarticIn => t * t * t * t,
             Easing::QuarticOut => {
-                let t = t - 1.0;
+                let t = t - c(1.0);
                 let t = t * t;
-                1.0 - t * t
+                c(1.0) - t * t
             }
             Easing::QuarticInOut => {
-                if t < 0.5 {
+                if t < c(0.5) {
                     let t = t * t;
-                    8.0 * t * t
+                    c(8.0) * t * t
                 } else {
-                    let t = -2.0 * t + 2.0;
+                    let t = c(-2.0) * t + c(2.0);
                     let t = t * t;
-                    1.0 - t * t / 2.0
+                    c(1.0) - t * t / c(2.0)
                 }
             }
-            Easing::Tabular(data) => data.value_at(t),
-            Easing::Step(steps) => (t * steps).floor() / steps,
-            Easing::None => 1.0,
+            Easing::Tabular(data) => c(data.value_at(t.to_f32())),
+            Easing::Step(steps) => (t * c(*steps)).floor() / c(*steps),
+            Easing::None => c(1.0),
         }
     }
 
@@ -135,24 +208,170 @@ impl Easing {
         let x1 = x1.clamp(0.0, 1.0);
         let x2 = x2.clamp(0.0, 1.0);
 
-        let mut data = SmoothArray::new(BEZIER_POINTS_COUNT);
+        // Points are placed where the curve is, so they are dense where it's steep.
+        let points = (0..=BEZIER_POINTS_COUNT)
+            .map(|i| {
+                let t = i as f32 / BEZIER_POINTS_COUNT as f32;
+                let nt = 1.0 - t;
+                let t2 = t * t;
+                let nt2 = nt * nt;
+
+                let x = (3.0 * nt2 * t * x1 + 3.0 * nt * t2 * x2 + t2 * t).clamp(0.0, 1.0);
+                let y = 3.0 * nt2 * t * y1 + 3.0 * nt * t2 * y2 + t2 * t;
+                (x, y)
+            })
+            .collect::<Vec<_>>();
+
+        Easing::Tabular(points.into())
+    }
+}
 
-        let mut previous = (0.0, 0.0);
-        for i in 1..=BEZIER_POINTS_COUNT {
-            let t = i as f32 / BEZIER_POINTS_COUNT as f32;
-            let nt = 1.0 - t;
-            let t2 = t * t;
-            let nt2 = nt * nt;
+impl Easing {
+    /// Sample the easing into a lookup table of `width` values for `t` from 0 to 1,
+    /// e.g. to upload it to the GPU as a 1D texture.
+    ///
+    /// ```
+    /// use glissade::Easing;
+    ///
+    /// assert_eq!(Easing::QuadraticIn.bake_texture(5), vec![0.0, 0.0625, 0.25, 0.5625, 1.0]);
+    /// ```
+    pub fn bake_texture(&self, width: usize) -> Vec<f32> {
+        let last = width.saturating_sub(1).max(1) as f32;
+        (0..width).map(|i| self.ease(i as f32 / last)).collect()
+    }
 
-            let x = (3.0 * nt2 * t * x1 + 3.0 * nt * t2 * x2 + t2 * t).clamp(0.0, 1.0);
-            let y = 3.0 * nt2 * t * y1 + 3.0 * nt * t2 * y2 + t2 * t;
+    /// Convert the easing to a `Tabular` one with `resolution` points (at least 2),
+    /// so evaluating it is a single lookup. It's useful for hot loops easing thousands of values.
+    /// Values between the points are interpolated, so `Step` easing loses its sharp edges.
+    ///
+    /// ```
+    /// use glissade::Easing;
+    ///
+    /// let easing = Easing::CubicInOut.precomputed(256);
+    /// assert!((easing.ease(0.3) - Easing::CubicInOut.ease(0.3)).abs() < 1e-3);
+    /// ```
+    pub fn precomputed(&self, resolution: usize) -> Easing {
+        Easing::Tabular(self.bake_texture(resolution.max(2)).into())
+    }
 
-            data.line(previous, (x, y));
-            previous = (x, y);
+    /// Find the CSS `cubic-bezier(x1, y1, x2, y2)` closest to the easing,
+    /// so animations defined in Rust can be handed off to CSS transitions or compositor animations.
+    /// Returns `None` if the curve can't be approximated: it isn't continuous,
+    /// doesn't go from 0 to 1, or isn't monotonic.
+    ///
+    /// ```
+    /// use glissade::Easing;
+    ///
+    /// let (x1, y1, x2, y2) = Easing::CubicIn.to_css_bezier().unwrap();
+    /// assert!((x1 - 1.0 / 3.0).abs() < 0.01 && y1.abs() < 0.01);
+    /// assert!((x2 - 2.0 / 3.0).abs() < 0.01 && y2.abs() < 0.01);
+    /// assert_eq!(Easing::Step(4.0).to_css_bezier(), None);
+    /// ```
+    pub fn to_css_bezier(&self) -> Option<(f32, f32, f32, f32)> {
+        self.to_css_bezier_with_error().map(|(bezier, _)| bezier)
+    }
+
+    /// The same as `to_css_bezier`, but also returns the maximum difference
+    /// between the easing and the approximation, to decide if it's good enough.
+    pub fn to_css_bezier_with_error(&self) -> Option<((f32, f32, f32, f32), f32)> {
+        match self {
+            Easing::Linear => return Some(((0.0, 0.0, 1.0, 1.0), 0.0)),
+            Easing::Step(_) | Easing::None => return None,
+            _ => {}
+        }
+
+        let samples: [f32; BEZIER_FIT_SAMPLES + 1] =
+            core::array::from_fn(|i| self.ease(i as f32 / BEZIER_FIT_SAMPLES as f32));
+        let is_monotonic = samples.windows(2).all(|pair| pair[0] <= pair[1]);
+        if !is_monotonic || abs(samples[0]) > 1e-3 || abs(samples[BEZIER_FIT_SAMPLES] - 1.0) > 1e-3
+        {
+            return None;
+        }
+
+        let mut bezier = initial_bezier(&samples);
+        let mut error = bezier_squared_error(bezier, &samples);
+        let mut step = 0.1;
+        while step > 1e-4 {
+            let mut improved = false;
+            for i in 0..4 {
+                for delta in [step, -step] {
+                    let mut candidate = bezier;
+                    candidate[i] += delta;
+                    if i % 2 == 0 {
+                        candidate[i] = candidate[i].clamp(0.0, 1.0);
+                    }
+                    let candidate_error = bezier_squared_error(candidate, &samples);
+                    if candidate_error < error {
+                        bezier = candidate;
+                        error = candidate_error;
+                        improved = true;
+                    }
+                }
+            }
+            if !improved {
+                step *= 0.5;
+            }
         }
 
-        Easing::Tabular(data)
+        let max_error = samples
+            .iter()
+            .enumerate()
+            .map(|(i, &y)| abs(bezier_y(bezier, i as f32 / BEZIER_FIT_SAMPLES as f32) - y))
+            .fold(0.0, f32::max);
+        let [x1, y1, x2, y2] = bezier;
+        Some(((x1, y1, x2, y2), max_error))
+    }
+}
+
+/// Least squares fit of `y1` and `y2` with `x1 = 1/3` and `x2 = 2/3`, where the curve parameter is equal to `x`.
+fn initial_bezier(samples: &[f32]) -> [f32; 4] {
+    let (mut a11, mut a12, mut a22, mut b1, mut b2) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for (i, &y) in samples.iter().enumerate() {
+        let t = i as f32 / (samples.len() - 1) as f32;
+        let nt = 1.0 - t;
+        let p = 3.0 * nt * nt * t;
+        let q = 3.0 * nt * t * t;
+        let r = y - t * t * t;
+        a11 += p * p;
+        a12 += p * q;
+        a22 += q * q;
+        b1 += p * r;
+        b2 += q * r;
+    }
+    let det = a11 * a22 - a12 * a12;
+    let y1 = (b1 * a22 - b2 * a12) / det;
+    let y2 = (a11 * b2 - a12 * b1) / det;
+    [1.0 / 3.0, y1, 2.0 / 3.0, y2]
+}
+
+fn bezier_squared_error(bezier: [f32; 4], samples: &[f32]) -> f32 {
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &y)| {
+            let d = bezier_y(bezier, i as f32 / (samples.len() - 1) as f32) - y;
+            d * d
+        })
+        .sum()
+}
+
+/// Evaluate the CSS cubic bezier at `x`, the curve parameter is found by bisection.
+fn bezier_y([x1, y1, x2, y2]: [f32; 4], x: f32) -> f32 {
+    let cubic = |a: f32, b: f32, t: f32| {
+        let nt = 1.0 - t;
+        3.0 * nt * nt * t * a + 3.0 * nt * t * t * b + t * t * t
+    };
+
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..24 {
+        let middle = (low + high) * 0.5;
+        if cubic(x1, x2, middle) < x {
+            low = middle;
+        } else {
+            high = middle;
+        }
     }
+    cubic(y1, y2, (low + high) * 0.5)
 }
 
 #[cfg(test)]
@@ -230,4 +449,96 @@ mod tests {
         assert_eq!(easing.ease(0.5), 0.5);
         assert_eq!(easing.ease(1.0), 1.0);
     }
+
+    #[test]
+    fn bezier_steep_start() {
+        // x = t^3 and y = 1 - (1 - t)^3, the curve is vertical at the start.
+        let easing = Easing::bezier(0.0, 1.0, 0.0, 1.0);
+        for x in [0.001f32, 0.01, 0.3, 0.9] {
+            let exact = 1.0 - (1.0 - x.cbrt()).powi(3);
+            let error = (easing.ease(x) - exact).abs();
+            assert!(error < 2e-3, "At {}: {} != {}", x, easing.ease(x), exact);
+        }
+    }
+
+    #[test]
+    fn css_bezier_exact() {
+        for easing in [Easing::QuadraticIn, Easing::QuadraticOut, Easing::CubicOut] {
+            let (_, error) = easing.to_css_bezier_with_error().unwrap();
+            assert!(error < 1e-3, "{:?}: {}", easing, error);
+        }
+        assert_eq!(Easing::Linear.to_css_bezier(), Some((0.0, 0.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn css_bezier_round_trip() {
+        let (_, error) = Easing::bezier(0.25, 0.1, 0.25, 1.0)
+            .to_css_bezier_with_error()
+            .unwrap();
+        assert!(error < 0.01, "{}", error);
+
+        let (_, error) = Easing::QuarticInOut.to_css_bezier_with_error().unwrap();
+        assert!(error < 0.05, "{}", error);
+    }
+
+    #[test]
+    fn css_bezier_unsupported() {
+        assert_eq!(Easing::Step(4.0).to_css_bezier(), None);
+        assert_eq!(Easing::None.to_css_bezier(), None);
+        let wave = Easing::Tabular(alloc::vec![0.0, 0.8, 0.2, 1.0].into());
+        assert_eq!(wave.to_css_bezier(), None);
+    }
+
+    #[test]
+    fn bake_texture() {
+        assert_eq!(Easing::Linear.bake_texture(3), alloc::vec![0.0, 0.5, 1.0]);
+        assert_eq!(Easing::Linear.bake_texture(1), alloc::vec![0.0]);
+        assert!(Easing::Linear.bake_texture(0).is_empty());
+    }
+
+    #[test]
+    fn ease_f64() {
+        let easings = [
+            Easing::QuadraticInOut,
+            Easing::CubicInOut,
+            Easing::QuarticOut,
+            Easing::Step(4.0),
+            Easing::Tabular(alloc::vec![0.0, 0.3, 1.0].into()),
+        ];
+        for easing in easings {
+            for i in -1..=11 {
+                let t = i as f32 * 0.1;
+                assert!((easing.ease_f64(t as f64) - easing.ease(t) as f64).abs() < 1e-6);
+            }
+        }
+
+        let t = 1.0 - 1e-12;
+        assert!(Easing::Linear.ease_f64(t) < 1.0);
+        assert_eq!(Easing::Linear.ease(t as f32), 1.0);
+    }
+
+    #[test]
+    fn non_finite_input() {
+        assert_eq!(Easing::QuadraticInOut.ease(f32::INFINITY), 1.0);
+        assert_eq!(Easing::QuadraticInOut.ease(f32::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Easing::ease: t is NaN")]
+    fn nan_input() {
+        Easing::Linear.ease(f32::NAN);
+    }
+
+    #[test]
+    fn precomputed() {
+        let easing = Easing::QuarticInOut.precomputed(1024);
+        for i in 0..=100 {
+            let t = i as f32 * 0.01;
+            assert!((easing.ease(t) - Easing::QuarticInOut.ease(t)).abs() < 1e-4);
+        }
+
+        let easing = Easing::QuadraticIn.precomputed(0);
+        assert_eq!(easing, Easing::Tabular(alloc::vec![0.0, 1.0].into()));
+    }
 }
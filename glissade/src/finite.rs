@@ -0,0 +1,75 @@
+/// Check that a value has no NaN or infinite components.
+/// It's used by `Keyframes::finite_or` to replace corrupted values.
+/// Integers and other exact types are always finite.
+pub trait Finite {
+    fn is_finite(&self) -> bool;
+}
+
+impl Finite for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl Finite for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+macro_rules! impl_finite_for_exact {
+    ($($t:ty),*) => {
+        $(impl Finite for $t {
+            fn is_finite(&self) -> bool {
+                true
+            }
+        })*
+    };
+}
+
+impl_finite_for_exact!(bool, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+impl<T: Finite> Finite for Option<T> {
+    fn is_finite(&self) -> bool {
+        self.as_ref().is_none_or(T::is_finite)
+    }
+}
+
+impl<T1: Finite, T2: Finite> Finite for (T1, T2) {
+    fn is_finite(&self) -> bool {
+        self.0.is_finite() && self.1.is_finite()
+    }
+}
+
+impl<T1: Finite, T2: Finite, T3: Finite> Finite for (T1, T2, T3) {
+    fn is_finite(&self) -> bool {
+        self.0.is_finite() && self.1.is_finite() && self.2.is_finite()
+    }
+}
+
+impl<T1: Finite, T2: Finite, T3: Finite, T4: Finite> Finite for (T1, T2, T3, T4) {
+    fn is_finite(&self) -> bool {
+        self.0.is_finite() && self.1.is_finite() && self.2.is_finite() && self.3.is_finite()
+    }
+}
+
+impl<T: Finite, const N: usize> Finite for [T; N] {
+    fn is_finite(&self) -> bool {
+        self.iter().all(T::is_finite)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finite_values() {
+        assert!(Finite::is_finite(&1.0f32));
+        assert!(!Finite::is_finite(&f64::NAN));
+        assert!(Finite::is_finite(&(1, 2.0)));
+        assert!(!Finite::is_finite(&[0.0, f32::INFINITY]));
+        assert!(Finite::is_finite(&None::<f32>));
+        assert!(!Finite::is_finite(&Some(f32::NAN)));
+    }
+}
@@ -0,0 +1,93 @@
+//! Paths of common figures for `keyframes::poly`, e.g. for demos and ambient motion.
+//! Figures are centered at the origin, and closed: the last point is the same as the first one.
+//! Points can be of any type that can be created from `(x, y)`, like `(f32, f32)` or `glam::Vec2`.
+//!
+//! # Example
+//!
+//! ```
+//! use glissade::{generators, keyframes, Easing, Keyframes};
+//!
+//! let square: Vec<(f32, f32)> = generators::ngon(1.0, 4);
+//! let motion = keyframes::poly::<_, f32>(square, 4.0, Easing::Linear);
+//! assert!((motion.get(1.0).0 - 1.0).abs() < 1e-6);
+//! ```
+
+use crate::math::{cos, sin};
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+
+/// A regular polygon with `n` vertices at `radius` from the center, the first vertex is at the top,
+/// i.e. at `(0, -radius)`, and the rest go clockwise on screens with the y-axis pointing down.
+pub fn ngon<T: From<(f32, f32)>>(radius: f32, n: usize) -> Vec<T> {
+    assert!(n > 0, "Polygon must have at least one vertex");
+    (0..=n)
+        .map(|i| point(radius, 2.0 * PI * i as f32 / n as f32))
+        .collect()
+}
+
+/// A star with `n` rays, vertices alternate between `outer_radius` and `inner_radius`.
+/// The first vertex is at the top, the same as for `ngon`.
+pub fn star<T: From<(f32, f32)>>(outer_radius: f32, inner_radius: f32, n: usize) -> Vec<T> {
+    assert!(n > 0, "Star must have at least one ray");
+    (0..=2 * n)
+        .map(|i| {
+            let radius = if i % 2 == 0 {
+                outer_radius
+            } else {
+                inner_radius
+            };
+            point(radius, PI * i as f32 / n as f32)
+        })
+        .collect()
+}
+
+/// A Lissajous curve `(cos(a * t), sin(b * t)) * size` sampled at `steps` segments,
+/// e.g. `a = 3` and `b = 4` for the classic knot-like figure.
+pub fn lissajous<T: From<(f32, f32)>>(size: f32, steps: usize, a: usize, b: usize) -> Vec<T> {
+    assert!(steps > 0, "Lissajous curve must have at least one step");
+    (0..=steps)
+        .map(|i| {
+            let t = PI / 2.0 - 2.0 * PI * i as f32 / steps as f32;
+            T::from((size * cos(t * a as f32), size * sin(t * b as f32)))
+        })
+        .collect()
+}
+
+/// Get a point at `radius` from the origin, at `angle` clockwise from the top.
+fn point<T: From<(f32, f32)>>(radius: f32, angle: f32) -> T {
+    let angle = angle - PI / 2.0;
+    T::from((radius * cos(angle), radius * sin(angle)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx_eq;
+
+    #[test]
+    fn figures() {
+        let square: Vec<(f32, f32)> = ngon(2.0, 4);
+        assert_eq!(square.len(), 5, "Closed path");
+        assert_approx_eq(square[0], (0.0, -2.0), 1e-6);
+        assert_approx_eq(square[1], (2.0, 0.0), 1e-6);
+        assert_approx_eq(square[4], square[0], 1e-6);
+
+        let star: Vec<(f32, f32)> = star(2.0, 1.0, 5);
+        assert_eq!(star.len(), 11);
+        assert_approx_eq(star[5], (0.0, 1.0), 1e-6);
+
+        let curve: Vec<[f32; 2]> = lissajous(1.0, 4, 1, 2)
+            .into_iter()
+            .map(|(x, y): (f32, f32)| [x, y])
+            .collect();
+        assert_approx_eq(curve[0], [0.0, 0.0], 1e-6);
+        assert_approx_eq(curve[1], [1.0, 0.0], 1e-6);
+        assert_approx_eq(curve[2], [0.0, 0.0], 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Polygon must have at least one vertex")]
+    fn empty_polygon() {
+        ngon::<(f32, f32)>(1.0, 0);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{Distance, Mix};
 use cgmath::num_traits::Float;
 use cgmath::{
-    BaseFloat, Deg, Euler, Matrix2, Matrix3, Matrix4, Point1, Point2, Point3, Quaternion, Rad,
-    Vector1, Vector2, Vector3, Vector4,
+    BaseFloat, Basis2, Basis3, Decomposed, Deg, Euler, Matrix2, Matrix3, Matrix4, Point1, Point2,
+    Point3, Quaternion, Rad, Rotation, Rotation2, Vector1, Vector2, Vector3, Vector4, VectorSpace,
 };
 
 macro_rules! impl_stationary {
@@ -12,10 +12,15 @@ macro_rules! impl_stationary {
 }
 
 impl_stationary!(
-    Deg, Euler, Matrix2, Matrix3, Matrix4, Point1, Point2, Point3, Quaternion, Rad, Vector1,
-    Vector2, Vector3, Vector4
+    Basis2, Basis3, Deg, Euler, Matrix2, Matrix3, Matrix4, Point1, Point2, Point3, Quaternion, Rad,
+    Vector1, Vector2, Vector3, Vector4
 );
 
+impl<V: VectorSpace + Mix, R: Mix + Clone> crate::Stationary for Decomposed<V, R> where
+    V::Scalar: Mix
+{
+}
+
 impl<S: Mix> Mix for Vector1<S> {
     fn mix(self, other: Self, t: f32) -> Self {
         Vector1 {
@@ -77,6 +82,19 @@ impl<S: Float> Distance for Vector3<S> {
     }
 }
 
+impl<S: Float> Distance for Vector4<S> {
+    fn distance(self, other: Self) -> f32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        let dw = self.w - other.w;
+        (dx * dx + dy * dy + dz * dz + dw * dw)
+            .sqrt()
+            .to_f32()
+            .unwrap()
+    }
+}
+
 impl<S: From<f32> + BaseFloat> Mix for Quaternion<S> {
     fn mix(self, other: Self, t: f32) -> Self {
         self.slerp(other, t.into())
@@ -185,12 +203,55 @@ impl<S: Mix> Mix for Matrix4<S> {
     }
 }
 
+/// Get the angle from `a` to `b` in `[-PI, PI]`.
+fn basis2_angle_to<S: BaseFloat>(a: Basis2<S>, b: Basis2<S>) -> S {
+    let delta = Matrix2::from(a.invert() * b);
+    delta.x.y.atan2(delta.x.x)
+}
+
+/// Rotations are interpolated by the shortest route.
+impl<S: From<f32> + BaseFloat> Mix for Basis2<S> {
+    fn mix(self, other: Self, t: f32) -> Self {
+        let angle = basis2_angle_to(self, other);
+        self * Basis2::from_angle(Rad(angle * t.into()))
+    }
+}
+
+impl<S: BaseFloat> Distance for Basis2<S> {
+    fn distance(self, other: Self) -> f32 {
+        basis2_angle_to(self, other).abs().to_f32().unwrap()
+    }
+}
+
+impl<S: From<f32> + BaseFloat> Mix for Basis3<S> {
+    fn mix(self, other: Self, t: f32) -> Self {
+        Quaternion::from(self)
+            .mix(Quaternion::from(other), t)
+            .into()
+    }
+}
+
+/// Scales and displacements are interpolated linearly, and rotations are mixed with their own `Mix`,
+/// i.e. `Quaternion` and `Basis3` are slerped.
+impl<V: VectorSpace + Mix, R: Mix> Mix for Decomposed<V, R>
+where
+    V::Scalar: Mix,
+{
+    fn mix(self, other: Self, t: f32) -> Self {
+        Decomposed {
+            scale: self.scale.mix(other.scale, t),
+            rot: self.rot.mix(other.rot, t),
+            disp: self.disp.mix(other.disp, t),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Mix;
+    use crate::{Distance, Mix};
     use cgmath::{
-        assert_relative_eq, Deg, Euler, Point1, Point2, Point3, Quaternion, Rad, Rotation3,
-        Vector1, Vector2, Vector3, Vector4,
+        assert_relative_eq, Basis2, Basis3, Decomposed, Deg, Euler, Point1, Point2, Point3,
+        Quaternion, Rad, Rotation2, Rotation3, Transform, Vector1, Vector2, Vector3, Vector4,
     };
 
     #[test]
@@ -328,4 +389,69 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_vector_distance() {
+        let v1 = Vector4::new(1.0f32, 1.0, 1.0, 1.0);
+        let v2 = Vector4::new(2.0f32, 2.0, 2.0, 2.0);
+        assert_eq!(v1.distance(v2), 2.0);
+        assert_eq!(
+            Vector2::new(0.0f64, 0.0).distance(Vector2::new(3.0, 4.0)),
+            5.0
+        );
+    }
+
+    #[test]
+    fn test_basis2() {
+        let b1 = Basis2::from_angle(Deg(170.0f32));
+        let b2 = Basis2::from_angle(Deg(-170.0f32));
+        let b3 = b1.mix(b2, 0.5);
+        assert_relative_eq!(b3, Basis2::from_angle(Deg(180.0)), epsilon = 1e-6);
+        assert_relative_eq!(b1.distance(b2), 20f32.to_radians(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_basis3() {
+        let b1 = Basis3::from_angle_z(Deg(0.0f32));
+        let b2 = Basis3::from_angle_z(Deg(90.0f32));
+        let b3 = b1.mix(b2, 0.5);
+        assert_relative_eq!(b3, Basis3::from_angle_z(Deg(45.0)), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_decomposed() {
+        let d1 = Decomposed {
+            scale: 1.0f32,
+            rot: Quaternion::from_angle_z(Deg(0.0)),
+            disp: Vector3::new(0.0, 0.0, 0.0),
+        };
+        let d2 = Decomposed {
+            scale: 3.0f32,
+            rot: Quaternion::from_angle_z(Deg(90.0)),
+            disp: Vector3::new(2.0, 4.0, 0.0),
+        };
+        let d3 = d1.mix(d2, 0.5);
+        assert_eq!(d3.scale, 2.0);
+        assert_eq!(d3.disp, Vector3::new(1.0, 2.0, 0.0));
+        assert_relative_eq!(d3.rot, Quaternion::from_angle_z(Deg(45.0)), epsilon = 1e-6);
+        assert_relative_eq!(
+            d3.transform_vector(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(2f32.sqrt(), 2f32.sqrt(), 0.0),
+            epsilon = 1e-6
+        );
+
+        let d1 = Decomposed {
+            scale: 1.0f64,
+            rot: Basis2::from_angle(Rad(0.0)),
+            disp: Vector2::new(0.0, 0.0),
+        };
+        let d2 = Decomposed {
+            scale: 1.0f64,
+            rot: Basis2::from_angle(Rad(1.0)),
+            disp: Vector2::new(0.0, 2.0),
+        };
+        let d3 = d1.mix(d2, 0.5);
+        assert_relative_eq!(d3.rot, Basis2::from_angle(Rad(0.5)), epsilon = 1e-9);
+        assert_eq!(d3.disp, Vector2::new(0.0, 1.0));
+    }
 }
@@ -0,0 +1,125 @@
+use crate::math::{floor_f64, round_f64};
+use crate::{Time, TimeDiff};
+use chrono::{DateTime, Duration, Utc};
+
+fn as_secs_f64(duration: Duration) -> f64 {
+    duration.num_seconds() as f64 + duration.subsec_nanos() as f64 * 1e-9
+}
+
+impl Time for DateTime<Utc> {
+    type Duration = Duration;
+    fn since(self, earlier: Self) -> Self::Duration {
+        if self < earlier {
+            panic!("Time::since: self < earlier");
+        }
+        self - earlier
+    }
+
+    fn checked_since(self, earlier: Self) -> Option<Self::Duration> {
+        if self < earlier {
+            None
+        } else {
+            Some(self - earlier)
+        }
+    }
+
+    fn advance(self, duration: Self::Duration) -> Self {
+        self + duration
+    }
+
+    fn checked_advance(self, duration: Self::Duration) -> Option<Self> {
+        self.checked_add_signed(duration)
+    }
+}
+
+impl TimeDiff for Duration {
+    fn as_f32(self) -> f32 {
+        as_secs_f64(self) as f32
+    }
+
+    fn as_f64(self) -> f64 {
+        as_secs_f64(self)
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        if !(value >= 0.0 && value < i64::MAX as f64) {
+            return None;
+        }
+        let seconds = floor_f64(value);
+        let nanoseconds = round_f64((value - seconds) * 1e9) as i64;
+        Duration::try_seconds(seconds as i64)?.checked_add(&Duration::nanoseconds(nanoseconds))
+    }
+
+    fn scale(self, scale: f32) -> Self {
+        if scale < 0.0 || scale.is_nan() {
+            panic!("TimeDiff::scale: scale is negative or NaN");
+        }
+        self.checked_scale(scale).unwrap_or(Duration::MAX)
+    }
+
+    /// Whole seconds and nanoseconds are scaled separately,
+    /// so long durations keep nanosecond precision.
+    fn checked_scale(self, scale: f32) -> Option<Self> {
+        if !(scale >= 0.0 && scale.is_finite()) {
+            return None;
+        }
+        let seconds = self.num_seconds() as f64 * scale as f64;
+        let whole_seconds = floor_f64(seconds);
+        let nanoseconds =
+            round_f64((seconds - whole_seconds) * 1e9 + self.subsec_nanos() as f64 * scale as f64);
+        Duration::try_seconds(whole_seconds as i64)?
+            .checked_add(&Duration::nanoseconds(nanoseconds as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{keyframes, Animated, Keyframes, Time, TimeDiff};
+    use chrono::{DateTime, Duration, Utc};
+
+    #[test]
+    fn chrono_animation() {
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let animation = keyframes::from::<f32, DateTime<Utc>>(0.0)
+            .go_to(10.0, Duration::seconds(2))
+            .scale(2.0)
+            .run(start);
+
+        assert_eq!(animation.get(start), 0.0);
+        assert_eq!(animation.get(start + Duration::seconds(1)), 2.5);
+        assert_eq!(animation.get(start + Duration::seconds(2)), 5.0);
+        assert_eq!(animation.get(start + Duration::seconds(4)), 10.0);
+        assert_eq!(animation.end_time(), start + Duration::seconds(4));
+        assert!(animation.is_finished(start + Duration::seconds(4)));
+    }
+
+    #[test]
+    fn chrono_checked_arithmetic() {
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let later = start + Duration::seconds(1);
+        assert_eq!(start.checked_since(later), None);
+        assert_eq!(later.checked_since(start), Some(Duration::seconds(1)));
+        assert_eq!(
+            DateTime::<Utc>::MAX_UTC.checked_advance(Duration::days(1)),
+            None
+        );
+        assert_eq!(Duration::MAX.checked_scale(2.0), None);
+        assert_eq!(Duration::from_f64(1.25), Some(Duration::milliseconds(1250)));
+        assert_eq!(Duration::from_f64(-1.0), None);
+    }
+
+    #[test]
+    fn chrono_long_scale() {
+        let century = Duration::days(365 * 100) + Duration::nanoseconds(1);
+        assert_eq!(century.scale(2.0), century * 2);
+        assert_eq!(
+            Duration::milliseconds(1500).scale(0.5),
+            Duration::milliseconds(750)
+        );
+        assert_eq!(Duration::days(365 * 200).scale(1e9), Duration::MAX);
+        assert_eq!(
+            Duration::seconds(2).checked_scale(0.5),
+            Some(Duration::seconds(1))
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Distance, Mix};
+use crate::math::sqrt;
+use crate::{Distance, Mix, Wrapped};
+use core::f32::consts::PI;
 use glam::{
     Affine2, Affine3A, BVec2, BVec3, BVec4, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2,
     DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, IVec2, IVec3, IVec4, Mat2,
@@ -154,3 +156,180 @@ impl Mix for DAffine3 {
         )
     }
 }
+
+macro_rules! impl_distance_for_matrix {
+    ($($type:ident),*) => {
+        $(
+            /// The Frobenius norm of the difference, i.e. the Euclidean distance between the elements.
+            impl Distance for $type {
+                fn distance(self, other: Self) -> f32 {
+                    let difference = (self - other).to_cols_array();
+                    sqrt(difference.iter().map(|v| (v * v) as f32).sum())
+                }
+            }
+        )*
+    };
+}
+
+impl_distance_for_matrix!(Mat2, Mat3, Mat3A, Mat4, DMat2, DMat3, DMat4);
+
+/// The angle of the rotation from one quaternion to the other, in radians.
+impl Distance for Quat {
+    fn distance(self, other: Self) -> f32 {
+        self.angle_between(other)
+    }
+}
+
+/// The angle of the rotation from one quaternion to the other, in radians.
+impl Distance for DQuat {
+    fn distance(self, other: Self) -> f32 {
+        self.angle_between(other) as f32
+    }
+}
+
+/// A transform mixed as separate translation, rotation, and scale, instead of component-wise.
+/// Component-wise mixing of matrices shears and shrinks objects when their rotations differ,
+/// e.g. a half turn collapses the object at the middle. Here rotations are slerped,
+/// and translations and scales are interpolated linearly. Transforms with shear or negative scale
+/// aren't decomposed exactly, like in `Mat4::to_scale_rotation_translation`.
+///
+/// # Example
+///
+/// ```
+/// use glam::{Mat4, Quat, Vec3};
+/// use glissade::{Mix, Trs};
+///
+/// let start = Trs(Mat4::IDENTITY);
+/// let end = Trs(Mat4::from_rotation_z(std::f32::consts::PI));
+/// let middle = start.mix(end, 0.5).0;
+/// // The quarter turn keeps the unit scale, a component-wise mix would collapse to zero.
+/// assert!(middle.transform_vector3(Vec3::X).length() > 0.999);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Trs<T>(pub T);
+
+impl<T> From<T> for Trs<T> {
+    fn from(transform: T) -> Self {
+        Self(transform)
+    }
+}
+
+macro_rules! impl_trs_3d {
+    ($type:ident, $factor:ty) => {
+        impl Mix for Trs<$type> {
+            fn mix(self, other: Self, t: f32) -> Self {
+                let (scale1, rotation1, translation1) = self.0.to_scale_rotation_translation();
+                let (scale2, rotation2, translation2) = other.0.to_scale_rotation_translation();
+                let t = t as $factor;
+                Trs($type::from_scale_rotation_translation(
+                    scale1.lerp(scale2, t),
+                    rotation1.slerp(rotation2, t),
+                    translation1.lerp(translation2, t),
+                ))
+            }
+        }
+
+        /// The sum of the distances between translations, scales, and rotations (in radians).
+        impl Distance for Trs<$type> {
+            fn distance(self, other: Self) -> f32 {
+                let (scale1, rotation1, translation1) = self.0.to_scale_rotation_translation();
+                let (scale2, rotation2, translation2) = other.0.to_scale_rotation_translation();
+                (translation1.distance(translation2)
+                    + scale1.distance(scale2)
+                    + rotation1.angle_between(rotation2)) as f32
+            }
+        }
+    };
+}
+
+impl_trs_3d!(Mat4, f32);
+impl_trs_3d!(Affine3A, f32);
+impl_trs_3d!(DMat4, f64);
+impl_trs_3d!(DAffine3, f64);
+
+macro_rules! impl_trs_2d {
+    ($type:ident, $factor:ty) => {
+        impl Mix for Trs<$type> {
+            fn mix(self, other: Self, t: f32) -> Self {
+                let (scale1, angle1, translation1) = self.0.to_scale_angle_translation();
+                let (scale2, angle2, translation2) = other.0.to_scale_angle_translation();
+                let range = (-PI as $factor, PI as $factor);
+                let angle = Wrapped::new(angle1, range.0, range.1)
+                    .mix(Wrapped::new(angle2, range.0, range.1), t)
+                    .value;
+                let t = t as $factor;
+                Trs($type::from_scale_angle_translation(
+                    scale1.lerp(scale2, t),
+                    angle,
+                    translation1.lerp(translation2, t),
+                ))
+            }
+        }
+
+        /// The sum of the distances between translations, scales, and angles (in radians).
+        impl Distance for Trs<$type> {
+            fn distance(self, other: Self) -> f32 {
+                let (scale1, angle1, translation1) = self.0.to_scale_angle_translation();
+                let (scale2, angle2, translation2) = other.0.to_scale_angle_translation();
+                let range = (-PI as $factor, PI as $factor);
+                translation1.distance(translation2) as f32
+                    + scale1.distance(scale2) as f32
+                    + Wrapped::new(angle1, range.0, range.1)
+                        .distance(Wrapped::new(angle2, range.0, range.1))
+            }
+        }
+    };
+}
+
+impl_trs_2d!(Affine2, f32);
+impl_trs_2d!(DAffine2, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx_eq;
+    use glam::{Vec3, Vec4};
+
+    #[test]
+    fn trs_mix() {
+        let start = Trs(Mat4::from_scale_rotation_translation(
+            Vec3::ONE,
+            Quat::IDENTITY,
+            Vec3::ZERO,
+        ));
+        let end = Trs(Mat4::from_scale_rotation_translation(
+            Vec3::splat(3.0),
+            Quat::from_rotation_y(PI / 2.0),
+            Vec3::new(2.0, 0.0, 0.0),
+        ));
+        let (scale, rotation, translation) = start.mix(end, 0.5).0.to_scale_rotation_translation();
+        assert_approx_eq(scale, Vec3::splat(2.0), 1e-5);
+        assert!(rotation.angle_between(Quat::from_rotation_y(PI / 4.0)) < 1e-3);
+        assert_approx_eq(translation, Vec3::new(1.0, 0.0, 0.0), 1e-5);
+
+        let flat = Trs(Affine2::from_angle(PI * 0.9)).mix(Trs(Affine2::from_angle(-PI * 0.9)), 0.5);
+        let (scale, angle, _) = flat.0.to_scale_angle_translation();
+        assert_approx_eq(scale, Vec2::ONE, 1e-5);
+        assert!(
+            angle.abs() > PI * 0.99,
+            "The shortest turn goes through the half turn, got {}",
+            angle
+        );
+    }
+
+    #[test]
+    fn distances() {
+        assert_approx_eq(
+            Quat::IDENTITY.distance(Quat::from_rotation_x(0.5)),
+            0.5,
+            1e-5,
+        );
+        assert_eq!(Mat2::IDENTITY.distance(Mat2::ZERO), 2f32.sqrt());
+        assert_approx_eq(
+            Trs(Affine3A::from_translation(Vec3::X)).distance(Trs(Affine3A::IDENTITY)),
+            1.0,
+            1e-6,
+        );
+        assert_approx_eq(Vec4::ZERO.distance(Vec4::ONE), 2.0, 1e-6);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{Distance, Mix, Stationary};
+use iced_runtime::core::{Color, Padding, Point, Size, Vector};
+
+impl Stationary for Color {}
+impl Stationary for Padding {}
+impl Stationary for Point {}
+impl Stationary for Size {}
+impl Stationary for Vector {}
+
+impl Mix for Color {
+    fn mix(self, other: Self, t: f32) -> Self {
+        Color {
+            r: self.r.mix(other.r, t),
+            g: self.g.mix(other.g, t),
+            b: self.b.mix(other.b, t),
+            a: self.a.mix(other.a, t),
+        }
+    }
+}
+
+impl Mix for Padding {
+    fn mix(self, other: Self, t: f32) -> Self {
+        Padding {
+            top: self.top.mix(other.top, t),
+            right: self.right.mix(other.right, t),
+            bottom: self.bottom.mix(other.bottom, t),
+            left: self.left.mix(other.left, t),
+        }
+    }
+}
+
+impl Mix for Point {
+    fn mix(self, other: Self, t: f32) -> Self {
+        Point::new(self.x.mix(other.x, t), self.y.mix(other.y, t))
+    }
+}
+
+impl Mix for Size {
+    fn mix(self, other: Self, t: f32) -> Self {
+        Size::new(
+            self.width.mix(other.width, t),
+            self.height.mix(other.height, t),
+        )
+    }
+}
+
+impl Mix for Vector {
+    fn mix(self, other: Self, t: f32) -> Self {
+        Vector::new(self.x.mix(other.x, t), self.y.mix(other.y, t))
+    }
+}
+
+impl Distance for Point {
+    fn distance(self, other: Self) -> f32 {
+        Point::distance(&self, other)
+    }
+}
+
+impl Distance for Vector {
+    fn distance(self, other: Self) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
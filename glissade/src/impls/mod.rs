@@ -1,14 +1,20 @@
 #[cfg(feature = "cgmath")]
 mod cgmath;
+#[cfg(feature = "chrono")]
+mod chrono;
 #[cfg(feature = "euclid")]
 mod euclid;
 #[cfg(feature = "glam")]
-mod glam;
+pub(crate) mod glam;
+#[cfg(feature = "iced")]
+mod iced;
 #[cfg(feature = "nalgebra")]
 mod nalgebra;
 #[cfg(feature = "palette")]
 mod palette;
-#[cfg(not(feature = "web-time"))]
+#[cfg(all(feature = "std", not(feature = "web-time")))]
 mod std_time;
+#[cfg(feature = "time")]
+mod time;
 #[cfg(feature = "web-time")]
 mod web_time;
@@ -3,14 +3,19 @@ use nalgebra::{
     Matrix1x5, Matrix1x6, Matrix2, Matrix2x3, Matrix2x4, Matrix2x5, Matrix2x6, Matrix3, Matrix3x2,
     Matrix3x4, Matrix3x5, Matrix3x6, Matrix4, Matrix4x2, Matrix4x3, Matrix4x5, Matrix4x6, Matrix5,
     Matrix5x2, Matrix5x3, Matrix5x4, Matrix5x6, Matrix6, Matrix6x2, Matrix6x3, Matrix6x4,
-    Matrix6x5, Point, Quaternion, RealField, Rotation, Scalar, Scale, Translation, Vector1,
-    Vector2, Vector3, Vector4, Vector5, Vector6,
+    Matrix6x5, Point, Quaternion, RealField, Rotation, Scalar, Scale, Similarity, Translation,
+    UnitComplex, UnitDualQuaternion, UnitQuaternion, Vector1, Vector2, Vector3, Vector4, Vector5,
+    Vector6,
 };
 use num_traits::{One, Zero};
 
 use crate::mix::Mix;
 use crate::{Distance, Stationary};
 
+fn to_f32<T: RealField>(value: T) -> f32 {
+    nalgebra::convert_unchecked(value)
+}
+
 macro_rules! impl_traits_for_vector {
     ($vector:ident) => {
         impl<T> Mix for $vector<T>
@@ -26,22 +31,17 @@ macro_rules! impl_traits_for_vector {
             fn mix(self, other: Self, t: f32) -> Self {
                 self.lerp(&other, T::from(t))
             }
+
+            fn mix_ref(&self, other: &Self, t: f32) -> Self {
+                self.lerp(other, T::from(t))
+            }
         }
 
         impl<T: Clone> Stationary for $vector<T> {}
 
-        impl<T> Distance for $vector<T>
-        where
-            T: Scalar
-                + Zero
-                + One
-                + ClosedAddAssign
-                + ClosedSubAssign
-                + ClosedMulAssign
-                + From<f32>,
-        {
+        impl<T: RealField> Distance for $vector<T> {
             fn distance(self, other: Self) -> f32 {
-                (self - other).len() as f32
+                to_f32((self - other).norm())
             }
         }
     };
@@ -63,6 +63,10 @@ macro_rules! impl_mix_for_matrix {
             fn mix(self, other: Self, t: f32) -> Self {
                 self.zip_map(&other, |a, b| a.mix(b, t))
             }
+
+            fn mix_ref(&self, other: &Self, t: f32) -> Self {
+                self.zip_map(other, |a, b| a.mix(b, t))
+            }
         }
 
         impl<T: Clone> Stationary for $matrix<T> {}
@@ -230,12 +234,118 @@ where
 
 impl<T> Stationary for Isometry<T, Rotation<T, 3>, 3> where T: Clone {}
 
+impl<T> Mix for UnitQuaternion<T>
+where
+    T: RealField + From<f32>,
+{
+    fn mix(self, other: Self, t: f32) -> Self {
+        self.slerp(&other, T::from(t))
+    }
+}
+
+impl<T: Scalar> Stationary for UnitQuaternion<T> {}
+
+impl<T: RealField> Distance for UnitQuaternion<T> {
+    fn distance(self, other: Self) -> f32 {
+        to_f32(self.angle_to(&other))
+    }
+}
+
+impl<T> Mix for UnitComplex<T>
+where
+    T: RealField + From<f32>,
+{
+    fn mix(self, other: Self, t: f32) -> Self {
+        self.slerp(&other, T::from(t))
+    }
+}
+
+impl<T: Scalar> Stationary for UnitComplex<T> {}
+
+impl<T: RealField> Distance for UnitComplex<T> {
+    fn distance(self, other: Self) -> f32 {
+        to_f32(self.angle_to(&other))
+    }
+}
+
+macro_rules! impl_traits_for_unit_transforms {
+    ($rotation:ident, $dim:literal) => {
+        impl<T> Mix for Isometry<T, $rotation<T>, $dim>
+        where
+            T: RealField + From<f32>,
+        {
+            fn mix(self, other: Self, t: f32) -> Self {
+                self.lerp_slerp(&other, T::from(t))
+            }
+        }
+
+        impl<T: Scalar> Stationary for Isometry<T, $rotation<T>, $dim> {}
+
+        /// The sum of the translation distance and the rotation angle.
+        impl<T: RealField> Distance for Isometry<T, $rotation<T>, $dim> {
+            fn distance(self, other: Self) -> f32 {
+                to_f32((self.translation.vector - other.translation.vector).norm())
+                    + self.rotation.distance(other.rotation)
+            }
+        }
+
+        /// Translations and scalings are interpolated linearly, and rotations are slerped.
+        impl<T> Mix for Similarity<T, $rotation<T>, $dim>
+        where
+            T: RealField + From<f32>,
+        {
+            fn mix(self, other: Self, t: f32) -> Self {
+                let t = T::from(t);
+                let scaling = self.scaling() + (other.scaling() - self.scaling()) * t.clone();
+                Similarity::from_isometry(self.isometry.lerp_slerp(&other.isometry, t), scaling)
+            }
+        }
+
+        impl<T: Scalar> Stationary for Similarity<T, $rotation<T>, $dim> {}
+
+        /// The sum of the isometry distance and the scaling difference.
+        impl<T: RealField> Distance for Similarity<T, $rotation<T>, $dim> {
+            fn distance(self, other: Self) -> f32 {
+                to_f32((self.scaling() - other.scaling()).abs())
+                    + self.isometry.distance(other.isometry)
+            }
+        }
+    };
+}
+
+impl_traits_for_unit_transforms!(UnitComplex, 2);
+impl_traits_for_unit_transforms!(UnitQuaternion, 3);
+
+/// Screw linear interpolation, the rotation and the translation change together along a helix.
+/// Falls back to the normalized linear interpolation if the rotations are 180° apart,
+/// and the screw axis is ambiguous.
+impl<T> Mix for UnitDualQuaternion<T>
+where
+    T: RealField + From<f32>,
+{
+    fn mix(self, other: Self, t: f32) -> Self {
+        let t = T::from(t);
+        self.try_sclerp(&other, t.clone(), T::default_epsilon())
+            .unwrap_or_else(|| self.nlerp(&other, t))
+    }
+}
+
+impl<T: Scalar> Stationary for UnitDualQuaternion<T> {}
+
+impl<T: RealField> Distance for UnitDualQuaternion<T> {
+    fn distance(self, other: Self) -> f32 {
+        self.to_isometry().distance(other.to_isometry())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Mix;
+    use crate::testing::approx_eq;
+    use crate::{Distance, Mix};
+    use core::f32::consts::PI;
     use nalgebra::{
-        Point2, Point3, Quaternion, Rotation2, Translation2, Translation3, Vector2, Vector3,
-        Vector4,
+        Isometry3, Point2, Point3, Quaternion, Rotation2, Similarity2, Similarity3, Translation2,
+        Translation3, UnitComplex, UnitDualQuaternion, UnitQuaternion, Vector2, Vector3, Vector4,
     };
 
     #[test]
@@ -341,4 +451,91 @@ mod tests {
         let v3 = v1.mix(v2, 0.5);
         assert_eq!(v3, Vector4::new(3.0, 4.0, 5.0, 6.0));
     }
+
+    #[test]
+    fn test_vector_distance() {
+        let v1 = Vector3::new(1.0f32, 2.0, 3.0);
+        let v2 = Vector3::new(1.0f32, 5.0, 7.0);
+        assert_eq!(v1.distance(v2), 5.0);
+        assert_eq!(
+            Vector2::new(1.0f64, 1.0).distance(Vector2::new(1.0, 1.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_unit_quaternion_mix() {
+        let q1 = UnitQuaternion::from_euler_angles(0.0f32, 0.0, 0.0);
+        let q2 = UnitQuaternion::from_euler_angles(0.0f32, 0.0, 3.0);
+        let q3 = q1.mix(q2, 0.25);
+        assert!((q3.norm() - 1.0).abs() < 1e-6, "Keeps the unit length");
+        assert!(
+            (q3.angle() - 0.75).abs() < 1e-6,
+            "Rotates at a constant speed"
+        );
+        assert!((q1.distance(q3) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_unit_complex_mix() {
+        let c1 = UnitComplex::new(3.0f32);
+        let c2 = UnitComplex::new(-3.0f32);
+        let c3 = c1.mix(c2, 0.5);
+        assert!(
+            (c3.angle().abs() - PI).abs() < 1e-5,
+            "Goes the shortest way, through PI"
+        );
+        assert!((c1.distance(c2) - (2.0 * PI - 6.0)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_similarity_mix() {
+        let s1 = Similarity2::new(Vector2::new(0.0f32, 0.0), 0.0, 1.0);
+        let s2 = Similarity2::new(Vector2::new(2.0f32, 4.0), 1.0, 3.0);
+        let s3 = s1.mix(s2, 0.5);
+        assert!((s3.scaling() - 2.0).abs() < 1e-6);
+        assert!((s3.isometry.rotation.angle() - 0.5).abs() < 1e-6);
+        assert_eq!(s3.isometry.translation.vector, Vector2::new(1.0, 2.0));
+        assert!((s1.distance(s2) - (2.0 + 1.0 + 20f32.sqrt())).abs() < 1e-5);
+
+        let s1 = Similarity3::new(Vector3::zeros(), Vector3::zeros(), 2.0f32);
+        let s2 = Similarity3::new(Vector3::x(), Vector3::z() * 2.0, 4.0f32);
+        let s3 = s1.mix(s2, 0.5);
+        assert!((s3.scaling() - 3.0).abs() < 1e-6);
+        assert!((s3.isometry.rotation.angle() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_isometry3_mix() {
+        let i1 = Isometry3::new(Vector3::zeros(), Vector3::zeros());
+        let i2 = Isometry3::new(Vector3::new(2.0f32, 0.0, 0.0), Vector3::y() * 1.0);
+        let i3 = i1.mix(i2, 0.5);
+        assert_eq!(i3.translation.vector, Vector3::new(1.0, 0.0, 0.0));
+        assert!((i3.rotation.angle() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_unit_dual_quaternion_mix() {
+        let d1 = UnitDualQuaternion::from_isometry(&Isometry3::identity());
+        let d2 = UnitDualQuaternion::from_isometry(&Isometry3::new(
+            Vector3::new(0.0f32, 0.0, 2.0),
+            Vector3::z() * PI / 2.0,
+        ));
+        let d3 = d1.mix(d2, 0.5).to_isometry();
+        assert!(
+            approx_eq(d3.translation.vector, Vector3::new(0.0, 0.0, 1.0), 1e-5),
+            "Moves along the screw axis, got {}",
+            d3.translation.vector
+        );
+        assert!((d3.rotation.angle() - PI / 4.0).abs() < 1e-5);
+        assert!((d1.distance(d2) - (2.0 + PI / 2.0)).abs() < 1e-5);
+
+        let flipped =
+            UnitDualQuaternion::from_isometry(&Isometry3::new(Vector3::zeros(), Vector3::x() * PI));
+        let halfway = d1.mix(flipped, 0.5);
+        assert!(
+            (halfway.rotation().angle() - PI / 2.0).abs() < 1e-5,
+            "The ambiguous half turn is still interpolated"
+        );
+    }
 }
@@ -6,24 +6,16 @@ impl Time for std::time::Instant {
         self.duration_since(earlier)
     }
 
-    fn advance(self, duration: Self::Duration) -> Self {
-        self + duration
-    }
-
-    fn duration_as_f32(duration: Self::Duration) -> f32 {
-        duration.as_secs_f32()
+    fn checked_since(self, earlier: Self) -> Option<Self::Duration> {
+        self.checked_duration_since(earlier)
     }
 
-    fn duration_sum(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration + other
-    }
-
-    fn duration_diff(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration - other
+    fn advance(self, duration: Self::Duration) -> Self {
+        self + duration
     }
 
-    fn duration_scale(duration: Self::Duration, scale: f32) -> Self::Duration {
-        duration.mul_f32(scale)
+    fn checked_advance(self, duration: Self::Duration) -> Option<Self> {
+        self.checked_add(duration)
     }
 }
 
@@ -33,23 +25,15 @@ impl Time for std::time::SystemTime {
         self.duration_since(earlier).unwrap()
     }
 
-    fn advance(self, duration: Self::Duration) -> Self {
-        self + duration
-    }
-
-    fn duration_as_f32(duration: Self::Duration) -> f32 {
-        duration.as_secs_f32()
+    fn checked_since(self, earlier: Self) -> Option<Self::Duration> {
+        self.duration_since(earlier).ok()
     }
 
-    fn duration_sum(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration + other
-    }
-
-    fn duration_diff(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration - other
+    fn advance(self, duration: Self::Duration) -> Self {
+        self + duration
     }
 
-    fn duration_scale(duration: Self::Duration, scale: f32) -> Self::Duration {
-        duration.mul_f32(scale)
+    fn checked_advance(self, duration: Self::Duration) -> Option<Self> {
+        self.checked_add(duration)
     }
 }
@@ -0,0 +1,105 @@
+use crate::{Time, TimeDiff};
+use ::time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+macro_rules! impl_time_for_date_time {
+    ($($t:ty),*) => {
+        $(
+            impl Time for $t {
+                type Duration = Duration;
+                fn since(self, earlier: Self) -> Self::Duration {
+                    if self < earlier {
+                        panic!("Time::since: self < earlier");
+                    }
+                    self - earlier
+                }
+
+                fn checked_since(self, earlier: Self) -> Option<Self::Duration> {
+                    if self < earlier {
+                        None
+                    } else {
+                        Some(self - earlier)
+                    }
+                }
+
+                fn advance(self, duration: Self::Duration) -> Self {
+                    self + duration
+                }
+
+                fn checked_advance(self, duration: Self::Duration) -> Option<Self> {
+                    self.checked_add(duration)
+                }
+            }
+        )*
+    };
+}
+
+impl_time_for_date_time!(OffsetDateTime, PrimitiveDateTime);
+
+impl TimeDiff for Duration {
+    fn as_f32(self) -> f32 {
+        self.as_seconds_f32()
+    }
+
+    fn as_f64(self) -> f64 {
+        self.as_seconds_f64()
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        if value >= 0.0 {
+            Duration::checked_seconds_f64(value)
+        } else {
+            None
+        }
+    }
+
+    fn scale(self, scale: f32) -> Self {
+        if scale < 0.0 || scale.is_nan() {
+            panic!("TimeDiff::scale: scale is negative or NaN");
+        }
+        self.checked_scale(scale).unwrap_or(Duration::MAX)
+    }
+
+    fn checked_scale(self, scale: f32) -> Option<Self> {
+        if scale >= 0.0 {
+            Duration::checked_seconds_f64(self.as_seconds_f64() * scale as f64)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{keyframes, Animated, Keyframes, Time, TimeDiff};
+    use ::time::{Duration, OffsetDateTime};
+
+    #[test]
+    fn offset_date_time_animation() {
+        let start = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let animation = keyframes::from::<f32, OffsetDateTime>(0.0)
+            .go_to(10.0, Duration::seconds(2))
+            .scale(2.0)
+            .run(start);
+
+        assert_eq!(animation.get(start), 0.0);
+        assert_eq!(animation.get(start + Duration::seconds(1)), 2.5);
+        assert_eq!(animation.get(start + Duration::seconds(2)), 5.0);
+        assert_eq!(animation.get(start + Duration::seconds(4)), 10.0);
+        assert_eq!(animation.end_time(), start + Duration::seconds(4));
+        assert!(animation.is_finished(start + Duration::seconds(4)));
+    }
+
+    #[test]
+    fn offset_date_time_checked_arithmetic() {
+        let start = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let later = start + Duration::seconds(1);
+        assert_eq!(start.checked_since(later), None);
+        assert_eq!(later.checked_since(start), Some(Duration::seconds(1)));
+        assert_eq!(start.checked_advance(Duration::MAX), None);
+        assert_eq!(Duration::MAX.checked_scale(2.0), None);
+        assert_eq!(
+            Duration::seconds(2).checked_scale(0.5),
+            Some(Duration::seconds(1))
+        );
+    }
+}
@@ -6,24 +6,16 @@ impl Time for web_time::Instant {
         self.duration_since(earlier)
     }
 
-    fn advance(self, duration: Self::Duration) -> Self {
-        self + duration
-    }
-
-    fn duration_as_f32(duration: Self::Duration) -> f32 {
-        duration.as_secs_f32()
+    fn checked_since(self, earlier: Self) -> Option<Self::Duration> {
+        self.checked_duration_since(earlier)
     }
 
-    fn duration_sum(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration + other
-    }
-
-    fn duration_diff(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration - other
+    fn advance(self, duration: Self::Duration) -> Self {
+        self + duration
     }
 
-    fn duration_scale(duration: Self::Duration, scale: f32) -> Self::Duration {
-        duration.mul_f32(scale)
+    fn checked_advance(self, duration: Self::Duration) -> Option<Self> {
+        self.checked_add(duration)
     }
 }
 
@@ -33,23 +25,58 @@ impl Time for web_time::SystemTime {
         self.duration_since(earlier).unwrap()
     }
 
+    fn checked_since(self, earlier: Self) -> Option<Self::Duration> {
+        self.duration_since(earlier).ok()
+    }
+
     fn advance(self, duration: Self::Duration) -> Self {
         self + duration
     }
 
-    fn duration_as_f32(duration: Self::Duration) -> f32 {
-        duration.as_secs_f32()
+    fn checked_advance(self, duration: Self::Duration) -> Option<Self> {
+        self.checked_add(duration)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{keyframes, Animated, Keyframes};
+    use web_time::{Duration, Instant, SystemTime};
+
+    const DAY: Duration = Duration::from_secs(86_400);
+
+    #[test]
+    fn multi_day_instant() {
+        let start = Instant::now();
+        let animation = keyframes::line::<f64, Instant>(0.0, 10.0, DAY * 10)
+            .scale(3.0)
+            .run(start);
+        assert_eq!(animation.end_time(), start + DAY * 30);
+        assert!((animation.get(start + DAY * 15) - 5.0).abs() < 1e-6);
+        assert_eq!(animation.get(start + DAY * 40), 10.0);
 
-    fn duration_sum(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration + other
+        let animation = keyframes::line::<f64, Instant>(0.0, 10.0, DAY * 2)
+            .repeat_n(3.0)
+            .run(start);
+        assert_eq!(animation.end_time(), start + DAY * 6);
+        assert!((animation.get(start + DAY * 5) - 5.0).abs() < 1e-6);
+        assert!(animation.get(start + DAY * 6 - Duration::from_secs(1)) < 10.0);
     }
 
-    fn duration_diff(duration: Self::Duration, other: Self::Duration) -> Self::Duration {
-        duration - other
+    #[test]
+    fn multi_day_system_time() {
+        let start = SystemTime::UNIX_EPOCH + DAY * 20_000;
+        let animation = keyframes::line::<f64, SystemTime>(0.0, 10.0, DAY)
+            .scale_to(DAY * 365)
+            .run(start);
+        assert_eq!(animation.end_time(), start + DAY * 365);
+        assert!((animation.get(start + DAY * 73) - 2.0).abs() < 1e-6);
     }
 
-    fn duration_scale(duration: Self::Duration, scale: f32) -> Self::Duration {
-        duration.mul_f32(scale)
+    #[test]
+    fn saturating_scale() {
+        let keyframes = keyframes::line::<f64, Instant>(0.0, 10.0, Duration::MAX / 2).scale(4.0);
+        assert_eq!(keyframes.duration(), Duration::MAX);
+        assert!(keyframes.try_run(Instant::now()).is_none());
     }
 }
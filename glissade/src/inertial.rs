@@ -1,7 +1,8 @@
 use crate::animated::Animated;
 use crate::Easing;
-use crate::{Mix, Time};
-use std::fmt::Debug;
+use crate::{Mix, Time, TimeDiff};
+use alloc::boxed::Box;
+use core::fmt::Debug;
 
 /// A value that smoothly goes to the target during a specific time.
 /// The target can be changed at any time. No jumps will occur.
@@ -31,7 +32,7 @@ impl<Item: Mix + Clone + PartialEq, X: Time> Animated<Item, X> for Inertial<Item
             } else if let Some(parent) = &self.parent {
                 let elapsed = current_time.since(start_time);
 
-                let t = X::duration_as_f32(elapsed) / X::duration_as_f32(self.duration);
+                let t = elapsed.as_f32() / self.duration.as_f32();
                 let t = self.easing.ease(t);
 
                 parent.get(current_time).mix(self.target.clone(), t)
@@ -55,7 +56,7 @@ impl<Item: Mix + Clone + PartialEq + Debug, X: Time + Debug> Debug for Inertial<
 where
     X::Duration: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Inertial")
             .field("target", &self.target)
             .field("start_time", &self.start_time)
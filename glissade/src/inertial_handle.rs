@@ -0,0 +1,155 @@
+use crate::{Animated, Easing, Inertial, Mix, Time};
+use core::fmt::Debug;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// A shared `Inertial` value, so one thread can change the target while another one samples it,
+/// e.g. a UI thread and a render thread. Clones of the handle refer to the same value.
+///
+/// Unlike `Inertial::go_to`, retargeting methods take `&self`, the value is replaced under a lock.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{Animated, InertialHandle};
+///
+/// let handle = InertialHandle::<f32, f32>::new(0.0);
+/// let render_handle = handle.clone();
+///
+/// std::thread::spawn(move || handle.go_to(10.0, 0.0, 1.0))
+///     .join()
+///     .unwrap();
+///
+/// assert_eq!(render_handle.target(), 10.0);
+/// assert!(!render_handle.is_finished(0.5));
+/// ```
+pub struct InertialHandle<T: Mix + Clone + PartialEq, X: Time> {
+    inertial: Arc<RwLock<Inertial<T, X>>>,
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> InertialHandle<T, X> {
+    /// Create a handle of a new inertial value resting at `value`.
+    pub fn new(value: T) -> Self {
+        Inertial::new(value).into()
+    }
+
+    /// Start a transition to a new target, see `Inertial::go_to`.
+    pub fn go_to(&self, target: T, current_time: X, duration: X::Duration) {
+        let mut inertial = self.write();
+        let current = core::mem::replace(&mut *inertial, Inertial::new(target.clone()));
+        *inertial = current.go_to(target, current_time, duration);
+    }
+
+    /// Start a transition to a new target with the easing, see `Inertial::ease_to`.
+    pub fn ease_to(&self, target: T, current_time: X, duration: X::Duration, easing: Easing) {
+        let mut inertial = self.write();
+        let current = core::mem::replace(&mut *inertial, Inertial::new(target.clone()));
+        *inertial = current.ease_to(target, current_time, duration, easing);
+    }
+
+    /// Get the target value.
+    pub fn target(&self) -> T {
+        self.read().target()
+    }
+
+    /// Get a copy of the current inertial value, e.g. to sample it many times without locking.
+    pub fn snapshot(&self) -> Inertial<T, X> {
+        self.read().clone()
+    }
+
+    // The lock holds a valid value even after a panic, so poisoned locks are used as is.
+    fn read(&self) -> RwLockReadGuard<'_, Inertial<T, X>> {
+        self.inertial.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Inertial<T, X>> {
+        self.inertial
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> Animated<T, X> for InertialHandle<T, X> {
+    fn get(&self, time: X) -> T {
+        self.read().get(time)
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        self.read().is_finished(time)
+    }
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> From<Inertial<T, X>> for InertialHandle<T, X> {
+    fn from(inertial: Inertial<T, X>) -> Self {
+        Self {
+            inertial: Arc::new(RwLock::new(inertial)),
+        }
+    }
+}
+
+impl<T: Mix + Clone + PartialEq, X: Time> Clone for InertialHandle<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            inertial: self.inertial.clone(),
+        }
+    }
+}
+
+impl<T: Mix + Clone + PartialEq + Debug, X: Time + Debug> Debug for InertialHandle<T, X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("InertialHandle")
+            .field(&*self.read())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn shared_retargeting() {
+        let start = Instant::now();
+        let handle = InertialHandle::new(0.0);
+        let other = handle.clone();
+
+        handle.go_to(10.0, start, Duration::from_secs(1));
+        assert_eq!(other.get(start + Duration::from_millis(500)), 5.0);
+        assert_eq!(other.target(), 10.0);
+
+        let snapshot = other.snapshot();
+        other.ease_to(
+            20.0,
+            start + Duration::from_secs(1),
+            Duration::from_secs(1),
+            Easing::Linear,
+        );
+        assert_eq!(snapshot.target(), 10.0, "Snapshots don't change");
+        assert_eq!(handle.get(start + Duration::from_millis(1500)), 15.0);
+        assert!(handle.is_finished(start + Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn threads() {
+        let handle = InertialHandle::<f32, f32>::new(0.0);
+        let threads: Vec<_> = (1..=4)
+            .map(|i| {
+                let handle = handle.clone();
+                std::thread::spawn(move || {
+                    for step in 0..100 {
+                        handle.go_to(i as f32, step as f32, 1.0);
+                        let value = handle.get(step as f32 + 0.5);
+                        assert!((0.0..=4.0).contains(&value), "Got {}", value);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert!((1.0..=4.0).contains(&handle.target()));
+    }
+}
@@ -1,38 +1,99 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 mod animation;
 mod easing;
+mod finite;
 mod inertial;
+#[cfg(feature = "std")]
+mod inertial_handle;
+mod math;
 mod mix;
+mod mix_round;
 mod stationary;
 mod time;
 
 mod animated;
+#[cfg(feature = "json")]
+pub mod assets;
+pub mod batch;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+mod clock;
+#[cfg(feature = "css")]
+pub mod css;
+pub mod debug;
+mod decay;
 mod distance;
+#[cfg(feature = "wasm")]
+mod dom_time;
+pub mod driver;
+pub mod generators;
 mod impls;
 mod poly;
+mod property;
 mod smooth_array;
+mod spring;
+mod state_machine;
+mod stepper;
+pub mod testing;
+mod timer;
+mod tracks;
+mod tweens;
+mod vector_space;
+mod wrapped;
+#[cfg(feature = "yew")]
+pub mod yew;
 
-pub use animated::Animated;
-pub use animation::Animation;
+pub use animated::{Animated, HoldLast};
 pub use animation::{keyframes, Keyframes};
+pub use animation::{
+    Animation, AnimationGroup, AnticipateOvershootKeyframes, BakedAnimation, BeforeStart,
+    BoxedKeyframes, Discontinuity, DiscontinuityKind, FlatKeyframes, GroupKeyframes,
+    KeyframesDescription, KeyframesKind, KeyframesSpec, OrbitKeyframes, OscillatorKeyframes,
+    PlayableAnimation, RepeatError, ScaleError, Scrubbed, ShakeKeyframes, TypewriterKeyframes,
+    Waveform,
+};
+pub use clock::{Clock, ManualClock};
+pub use decay::Decay;
 pub use distance::Distance;
+#[cfg(feature = "wasm")]
+pub use dom_time::DomTime;
 pub use easing::Easing;
+pub use finite::Finite;
+#[cfg(feature = "glam")]
+pub use impls::glam::Trs;
 pub use inertial::Inertial;
+#[cfg(feature = "std")]
+pub use inertial_handle::InertialHandle;
 pub use mix::Mix;
-pub use stationary::Stationary;
-pub use time::Time;
+pub use mix_round::{ErrorDiffusion, MixInteger, MixRound, RoundMode};
+pub use property::{PropertyAccess, PropertyTrack};
+pub use spring::Spring;
+pub use state_machine::AnimationStateMachine;
+pub use stationary::{Frozen, Stationary};
+pub use stepper::{Step, Stepper};
+pub use time::{Time, TimeDiff};
+#[cfg(feature = "futures")]
+pub use timer::AnimatedStream;
+pub use timer::{Finished, Timer};
+pub use tracks::{TrackPose, Tracks};
+pub use tweens::{TweenHandle, Tweens};
+pub use vector_space::VectorSpace;
+pub use wrapped::{mix_wrapped, Wrapped};
 
 #[cfg(feature = "derive")]
-pub use glissade_macro::Mix;
+pub use glissade_macro::{keyframes, Animate, Animated, Distance, Mix, PropertyAccess, Stationary};
 
 #[cfg(test)]
 #[cfg(feature = "derive")]
 mod tests {
     use crate as glissade;
-    use crate::Mix;
+    use crate::{Animate, Animated, Distance, Mix, PropertyAccess, Stationary};
 
-    #[derive(Mix, PartialEq, Debug)]
+    #[derive(Mix, PropertyAccess, PartialEq, Debug)]
     struct Point {
         x: f32,
         y: f32,
@@ -46,7 +107,7 @@ mod tests {
         assert_eq!(p3, Point { x: 0.5, y: 0.5 });
     }
 
-    #[derive(Mix, PartialEq, Debug)]
+    #[derive(Mix, PropertyAccess, PartialEq, Debug)]
     struct Color(f32, f32, f32);
 
     #[test]
@@ -57,7 +118,7 @@ mod tests {
         assert_eq!(c3, Color(0.5, 0.5, 0.5));
     }
 
-    #[derive(Mix, PartialEq, Debug)]
+    #[derive(Mix, PropertyAccess, PartialEq, Debug)]
     struct Size<T: Mix>
     where
         T: Clone + Copy,
@@ -76,7 +137,9 @@ mod tests {
             width: 1.0,
             height: 1.0,
         };
-        let s3 = s1.mix(s2, 0.5);
+        let mut s3 = s1.mix(s2, 0.5);
+        assert_eq!(s3.get_property("width"), Some(0.5));
+        assert!(s3.set_property("height", 0.5));
         assert_eq!(
             s3,
             Size {
@@ -85,4 +148,253 @@ mod tests {
             }
         );
     }
+
+    #[derive(Mix, PartialEq, Debug)]
+    enum Layout {
+        Grid { cols: u8 },
+        List(f32),
+        Hidden,
+    }
+
+    #[test]
+    fn test_enum_derive() {
+        let grid = Layout::Grid { cols: 2 }.mix(Layout::Grid { cols: 4 }, 0.5);
+        assert_eq!(grid, Layout::Grid { cols: 3 });
+        assert_eq!(
+            Layout::List(1.0).mix(Layout::List(3.0), 0.25),
+            Layout::List(1.5)
+        );
+        assert_eq!(Layout::Hidden.mix(Layout::Hidden, 0.5), Layout::Hidden);
+
+        assert_eq!(
+            Layout::Grid { cols: 2 }.mix(Layout::List(1.0), 0.5),
+            Layout::Grid { cols: 2 }
+        );
+        assert_eq!(
+            Layout::Grid { cols: 2 }.mix(Layout::List(1.0), 0.75),
+            Layout::List(1.0)
+        );
+        assert_eq!(
+            Layout::Hidden.mix(Layout::List(1.0), 1.0),
+            Layout::List(1.0)
+        );
+    }
+
+    #[derive(Stationary, Clone, PartialEq, Debug)]
+    enum Mode<T> {
+        Idle,
+        Busy(T),
+    }
+
+    #[test]
+    fn test_stationary_derive() {
+        let mode = (Mode::Busy("loading"), 0.5f32);
+        assert_eq!(Animated::get(&mode, 1.0f32), (Mode::Busy("loading"), 0.5));
+        assert!(Animated::<Mode<u8>, f32>::is_finished(&Mode::Idle, 0.0));
+    }
+
+    #[derive(Mix, PartialEq, Debug)]
+    struct Pixels<U>(f32, core::marker::PhantomData<U>);
+
+    #[derive(Mix, PartialEq, Debug)]
+    #[mix(no_bounds)]
+    struct Length<U> {
+        value: f32,
+        unit: core::marker::PhantomData<U>,
+    }
+
+    #[derive(Mix, PartialEq, Debug)]
+    #[mix(bound = "T: Mix + Copy")]
+    struct Range<T: Copy> {
+        start: T,
+        end: T,
+    }
+
+    #[test]
+    fn test_derive_bounds() {
+        use core::marker::PhantomData;
+
+        struct Meters;
+
+        assert_eq!(
+            Pixels::<f32>(0.0, PhantomData).mix(Pixels(2.0, PhantomData), 0.5),
+            Pixels(1.0, PhantomData)
+        );
+
+        let length = Length::<Meters> {
+            value: 0.0,
+            unit: PhantomData,
+        }
+        .mix(
+            Length {
+                value: 2.0,
+                unit: PhantomData,
+            },
+            0.5,
+        );
+        assert_eq!(length.value, 1.0);
+
+        let range = Range {
+            start: 0.0,
+            end: 1.0,
+        }
+        .mix(
+            Range {
+                start: 1.0,
+                end: 3.0,
+            },
+            0.5,
+        );
+        assert_eq!(
+            range,
+            Range {
+                start: 0.5,
+                end: 2.0
+            }
+        );
+    }
+
+    #[derive(Animate, Clone, Copy, PartialEq, Debug)]
+    struct Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Distance)]
+    struct Weighted<T>(T, u8);
+
+    #[test]
+    fn test_animate_derive() {
+        use crate::{keyframes, Keyframes};
+
+        let a = Vec2 { x: 0.0, y: 0.0 };
+        let b = Vec2 { x: 3.0, y: 4.0 };
+        assert_eq!(a.distance(b), 5.0);
+        assert_eq!(a.mix(b, 0.5), Vec2 { x: 1.5, y: 2.0 });
+        assert_eq!(Animated::<Vec2, f32>::get(&a, 1.0), a);
+
+        let path = keyframes::from::<Vec2, f32>(a).poly_to(
+            [b, Vec2 { x: 3.0, y: 0.0 }],
+            9.0,
+            crate::Easing::Linear,
+        );
+        assert_eq!(path.get(5.0), Vec2 { x: 3.0, y: 4.0 });
+
+        assert_eq!(Weighted(1.0f32, 2).distance(Weighted(4.0, 6)), 5.0);
+    }
+
+    #[derive(PropertyAccess, PartialEq, Debug)]
+    struct Sprite {
+        position: Point,
+        color: Color,
+        #[property(skip)]
+        label: String,
+    }
+
+    #[test]
+    fn test_property_access_derive() {
+        let mut sprite = Sprite {
+            position: Point { x: 0.0, y: 0.0 },
+            color: Color(0.0, 0.0, 0.0),
+            label: "sprite".into(),
+        };
+
+        assert!(sprite.set_property("position.x", 1.0));
+        assert!(sprite.set_property("color.2", 0.5));
+        assert!(!sprite.set_property("position", 1.0));
+        assert!(!sprite.set_property("position.z", 1.0));
+        assert!(!sprite.set_property("label", 1.0));
+        sprite.label = "moved".into();
+
+        assert_eq!(sprite.get_property("position.x"), Some(1.0));
+        assert_eq!(sprite.get_property("color.2"), Some(0.5));
+        assert_eq!(sprite.get_property("color.3"), None::<f32>);
+        assert_eq!(
+            sprite,
+            Sprite {
+                position: Point { x: 1.0, y: 0.0 },
+                color: Color(0.0, 0.0, 0.5),
+                label: "moved".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_keyframes_macro() {
+        use crate::{Animated, Easing, Keyframes};
+        use core::time::Duration;
+
+        let track: crate::FlatKeyframes<f32, f32> = glissade::keyframes! {
+            0.0 => 0.0,
+            1.0 ease QuadraticOut => 1.0,
+            2.5 => 4.0,
+        }
+        .into();
+        assert_eq!(track.duration(), 2.5);
+        assert_eq!(track.get(0.5), Easing::QuadraticOut.ease(0.5));
+        assert_eq!(track.get(1.75), 2.5);
+
+        let start = std::time::Instant::now();
+        let delayed = glissade::keyframes! {
+            500ms => 0.0f32,
+            1s ease Easing::Step(2.0) => 1.0,
+        }
+        .run(start);
+        assert_eq!(delayed.duration(), Duration::from_secs(1));
+        assert_eq!(delayed.get(start + Duration::from_millis(250)), 0.0);
+        assert_eq!(delayed.get(start + Duration::from_millis(800)), 0.5);
+    }
+
+    #[test]
+    fn test_keyframes_macro_numbers() {
+        use crate::Keyframes;
+
+        let ticks = glissade::keyframes! {
+            10 => 0.0f32,
+            20 => 1.0,
+            50 => 4.0,
+        };
+        assert_eq!(Keyframes::<f32, u32>::duration(&ticks), 50);
+        assert_eq!(ticks.get(5), 0.0);
+        assert_eq!(ticks.get(15), 0.5);
+        assert_eq!(ticks.get(35), 2.5);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct ViewValue {
+        x: f32,
+        label: &'static str,
+    }
+
+    #[derive(Animated)]
+    #[animated(value = ViewValue, time = f32)]
+    struct View {
+        x: crate::Animation<f32, f32, crate::BoxedKeyframes<f32, f32>>,
+        label: &'static str,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Pair<T>(T, T);
+
+    #[derive(Animated)]
+    #[animated(value = Pair<f32>, time = X)]
+    struct AnimatedPair<X: crate::Time>(crate::Inertial<f32, X>, crate::Inertial<f32, X>);
+
+    #[test]
+    fn test_animated_derive() {
+        use crate::{keyframes, Inertial, Keyframes};
+
+        let view = View {
+            x: keyframes::line(0.0, 1.0, 1.0).boxed().run(0.0),
+            label: "x",
+        };
+        assert_eq!(view.get(0.5), ViewValue { x: 0.5, label: "x" });
+        assert!(!view.is_finished(0.5));
+        assert!(view.is_finished(1.0));
+
+        let pair = AnimatedPair(Inertial::new(0.0), Inertial::new(1.0).go_to(2.0, 0.0, 1.0));
+        assert_eq!(pair.get(0.5).0, 0.0);
+        assert!(!pair.is_finished(0.5));
+        assert_eq!(pair.get(1.0), Pair(0.0, 2.0));
+    }
 }
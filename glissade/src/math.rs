@@ -0,0 +1,42 @@
+//! Float functions that work both with and without `std`.
+//! Without `std` they are backed by `libm`.
+
+macro_rules! float_fn {
+    ($($name:ident($t:ty) => $method:ident, $libm:ident;)*) => {
+        $(
+            #[inline]
+            pub(crate) fn $name(v: $t) -> $t {
+                #[cfg(feature = "std")]
+                {
+                    v.$method()
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::$libm(v)
+                }
+            }
+        )*
+    };
+}
+
+float_fn! {
+    abs(f32) => abs, fabsf;
+    floor(f32) => floor, floorf;
+    ceil(f32) => ceil, ceilf;
+    round(f32) => round, roundf;
+    sqrt(f32) => sqrt, sqrtf;
+    trunc(f32) => trunc, truncf;
+    exp(f32) => exp, expf;
+    ln(f32) => ln, logf;
+    sin(f32) => sin, sinf;
+    cos(f32) => cos, cosf;
+    abs_f64(f64) => abs, fabs;
+    floor_f64(f64) => floor, floor;
+    ceil_f64(f64) => ceil, ceil;
+    round_f64(f64) => round, round;
+}
+
+#[inline]
+pub(crate) fn fract(v: f32) -> f32 {
+    v - trunc(v)
+}
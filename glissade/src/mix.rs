@@ -1,19 +1,52 @@
+use crate::math::{round, round_f64};
+use core::marker::PhantomData;
+
 /// Mix trait for linear interpolation between two values.
+///
+/// NaN and infinite components of values propagate to the result, as with float arithmetic.
+/// The factor is expected to be finite, a NaN factor panics in debug builds of the float implementations.
 pub trait Mix {
     /// Linearly interpolate between two values using a factor `t` in the range [0, 1].
     fn mix(self, other: Self, t: f32) -> Self;
+
+    /// Same as `mix`, but both values are borrowed. Keyframes use it to interpolate between stored values.
+    /// The default implementation clones both values,
+    /// types that are expensive to clone (e.g. heap-allocated) should override it.
+    fn mix_ref(&self, other: &Self, t: f32) -> Self
+    where
+        Self: Clone,
+    {
+        self.clone().mix(other.clone(), t)
+    }
+
+    /// Same as `mix_ref`, but the factor is `f64`, it's used by the `f64` feature.
+    /// The default implementation converts the factor to `f32`,
+    /// types with `f64` precision should override it.
+    fn mix_ref_f64(&self, other: &Self, t: f64) -> Self
+    where
+        Self: Clone,
+    {
+        self.mix_ref(other, t as f32)
+    }
 }
 
 impl Mix for f32 {
     fn mix(self, other: f32, t: f32) -> f32 {
+        debug_assert!(!t.is_nan(), "Mix::mix: t is NaN");
         self + (other - self) * t
     }
 }
 
 impl Mix for f64 {
     fn mix(self, other: f64, t: f32) -> f64 {
+        debug_assert!(!t.is_nan(), "Mix::mix: t is NaN");
         self + (other - self) * t as f64
     }
+
+    fn mix_ref_f64(&self, other: &f64, t: f64) -> f64 {
+        debug_assert!(!t.is_nan(), "Mix::mix_ref_f64: t is NaN");
+        self + (other - self) * t
+    }
 }
 
 impl<T> Mix for Option<T>
@@ -54,61 +87,69 @@ impl Mix for bool {
 
 impl Mix for i8 {
     fn mix(self, other: i8, t: f32) -> i8 {
-        (self as f32).mix(other as f32, t).round() as i8
+        round((self as f32).mix(other as f32, t)) as i8
     }
 }
 
 impl Mix for u8 {
     fn mix(self, other: u8, t: f32) -> u8 {
-        (self as f32).mix(other as f32, t).round() as u8
+        round((self as f32).mix(other as f32, t)) as u8
     }
 }
 
 impl Mix for i16 {
     fn mix(self, other: i16, t: f32) -> i16 {
-        (self as f32).mix(other as f32, t).round() as i16
+        round((self as f32).mix(other as f32, t)) as i16
     }
 }
 
 impl Mix for u16 {
     fn mix(self, other: u16, t: f32) -> u16 {
-        (self as f32).mix(other as f32, t).round() as u16
+        round((self as f32).mix(other as f32, t)) as u16
     }
 }
 
 impl Mix for i32 {
     fn mix(self, other: i32, t: f32) -> i32 {
-        (self as f32).mix(other as f32, t).round() as i32
+        round((self as f32).mix(other as f32, t)) as i32
     }
 }
 
 impl Mix for u32 {
     fn mix(self, other: u32, t: f32) -> u32 {
-        (self as f32).mix(other as f32, t).round() as u32
+        round((self as f32).mix(other as f32, t)) as u32
     }
 }
 
 impl Mix for i64 {
     fn mix(self, other: i64, t: f32) -> i64 {
-        (self as f64).mix(other as f64, t).round() as i64
+        round_f64((self as f64).mix(other as f64, t)) as i64
+    }
+
+    fn mix_ref_f64(&self, other: &i64, t: f64) -> i64 {
+        round_f64((*self as f64).mix_ref_f64(&(*other as f64), t)) as i64
     }
 }
 
 impl Mix for u64 {
     fn mix(self, other: u64, t: f32) -> u64 {
-        (self as f64).mix(other as f64, t).round() as u64
+        round_f64((self as f64).mix(other as f64, t)) as u64
+    }
+
+    fn mix_ref_f64(&self, other: &u64, t: f64) -> u64 {
+        round_f64((*self as f64).mix_ref_f64(&(*other as f64), t)) as u64
     }
 }
 
 impl Mix for isize {
     fn mix(self, other: isize, t: f32) -> isize {
-        (self as f64).mix(other as f64, t).round() as isize
+        round_f64((self as f64).mix(other as f64, t)) as isize
     }
 }
 
 impl Mix for usize {
     fn mix(self, other: usize, t: f32) -> usize {
-        (self as f64).mix(other as f64, t).round() as usize
+        round_f64((self as f64).mix(other as f64, t)) as usize
     }
 }
 
@@ -164,6 +205,12 @@ impl<T: Mix + Default + Copy, const N: usize> Mix for [T; N] {
     }
 }
 
+impl<T: ?Sized> Mix for PhantomData<T> {
+    fn mix(self, _other: Self, _t: f32) -> Self {
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,202 @@
+use crate::math::{ceil_f64, floor_f64, round_f64};
+use crate::{Distance, Mix};
+
+/// How `MixRound` turns an interpolated value into an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum RoundMode {
+    /// Round to the nearest integer, half away from zero, the same as the plain integer `Mix`.
+    #[default]
+    Nearest,
+    /// Round down, the value stays on the start pixel until the next one is reached.
+    Floor,
+    /// Round up.
+    Ceil,
+    /// Round up or down with a pseudo-random threshold derived from the mix factor,
+    /// so over consecutive frames the fractional part is shown on average, without a fixed bias.
+    Dither,
+}
+
+/// Integer types that can be wrapped into `MixRound`.
+pub trait MixInteger: Copy {
+    fn to_f64(self) -> f64;
+
+    /// Convert a rounded value, out of range values saturate.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_mix_integer {
+    ($($t:ty),*) => {
+        $(
+            impl MixInteger for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> $t {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_mix_integer!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+/// An integer with a choice of rounding for `Mix`, e.g. for pixel positions,
+/// where rounding to the nearest value causes uneven steps and biased midpoints.
+/// The mode of the first value is used when two values are mixed.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{Mix, MixRound};
+///
+/// assert_eq!(MixRound::floor(0).mix(MixRound::floor(10), 0.99).value, 9);
+/// assert_eq!(MixRound::ceil(0).mix(MixRound::ceil(10), 0.01).value, 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct MixRound<T> {
+    pub value: T,
+    pub mode: RoundMode,
+}
+
+impl<T> MixRound<T> {
+    pub fn new(value: T, mode: RoundMode) -> Self {
+        Self { value, mode }
+    }
+
+    pub fn floor(value: T) -> Self {
+        Self::new(value, RoundMode::Floor)
+    }
+
+    pub fn ceil(value: T) -> Self {
+        Self::new(value, RoundMode::Ceil)
+    }
+
+    pub fn dither(value: T) -> Self {
+        Self::new(value, RoundMode::Dither)
+    }
+}
+
+impl<T> From<T> for MixRound<T> {
+    fn from(value: T) -> Self {
+        Self::new(value, RoundMode::Nearest)
+    }
+}
+
+impl<T: MixInteger> Mix for MixRound<T> {
+    fn mix(self, other: Self, t: f32) -> Self {
+        self.mix_ref_f64(&other, t as f64)
+    }
+
+    fn mix_ref_f64(&self, other: &Self, t: f64) -> Self {
+        let value = self.value.to_f64().mix_ref_f64(&other.value.to_f64(), t);
+        let rounded = match self.mode {
+            RoundMode::Nearest => round_f64(value),
+            RoundMode::Floor => floor_f64(value),
+            RoundMode::Ceil => ceil_f64(value),
+            RoundMode::Dither => floor_f64(value + dither_threshold(t)),
+        };
+        Self::new(T::from_f64(rounded), self.mode)
+    }
+}
+
+impl<T: Distance> Distance for MixRound<T> {
+    fn distance(self, other: Self) -> f32 {
+        self.value.distance(other.value)
+    }
+}
+
+/// A threshold in `[0, 1)` that looks random across mix factors, but is the same for the same factor.
+fn dither_threshold(t: f64) -> f64 {
+    let hash = (t.to_bits() ^ (t.to_bits() >> 29)).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Error-diffusion quantizer for a single animated value, e.g. a sprite position on a low-resolution screen.
+/// The rounding error of each frame is carried into the next one,
+/// so slow motion moves in evenly spaced steps, and the average position is exact.
+///
+/// # Example
+///
+/// ```
+/// use glissade::ErrorDiffusion;
+///
+/// let mut position = ErrorDiffusion::new();
+/// let frames: Vec<i32> = [0.25, 0.5, 0.75, 1.0]
+///     .into_iter()
+///     .map(|x| position.quantize(x))
+///     .collect();
+/// assert_eq!(frames, vec![0, 1, 1, 1]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct ErrorDiffusion {
+    error: f64,
+}
+
+impl ErrorDiffusion {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round the value of the current frame, the error is kept for the next frame.
+    pub fn quantize<T: MixInteger>(&mut self, value: impl Into<f64>) -> T {
+        let value = value.into() + self.error;
+        let rounded = round_f64(value);
+        self.error = value - rounded;
+        T::from_f64(rounded)
+    }
+
+    /// Forget the accumulated error, e.g. when the value jumps.
+    pub fn reset(&mut self) {
+        self.error = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_modes() {
+        let mix = |mode, t| MixRound::new(0u8, mode).mix(MixRound::new(10, mode), t);
+        assert_eq!(mix(RoundMode::Nearest, 0.46).value, 5);
+        assert_eq!(mix(RoundMode::Floor, 0.46).value, 4);
+        assert_eq!(mix(RoundMode::Ceil, 0.41).value, 5);
+        assert_eq!(mix(RoundMode::Floor, 0.45).mode, RoundMode::Floor);
+        assert_eq!(MixRound::from(3i64).mode, RoundMode::Nearest);
+        assert_eq!(
+            MixRound::floor(-5i32).mix(MixRound::floor(0), 0.5).value,
+            -3
+        );
+    }
+
+    #[test]
+    fn dither_is_unbiased() {
+        let from = MixRound::dither(0i32);
+        let to = MixRound::dither(1i32);
+        let values: Vec<i32> = (0..1000)
+            .map(|i| from.mix(to, 0.3 + i as f32 * 1e-5).value)
+            .collect();
+        assert!(values.iter().all(|v| *v == 0 || *v == 1));
+        let ones = values.iter().filter(|v| **v == 1).count();
+        assert!(
+            (250..350).contains(&ones),
+            "About 30% of frames are rounded up, got {}",
+            ones
+        );
+        assert_eq!(from.mix(to, 0.5), from.mix(to, 0.5), "Deterministic");
+        assert_eq!(from.mix(to, 1.0).value, 1);
+    }
+
+    #[test]
+    fn error_diffusion() {
+        let mut diffusion = ErrorDiffusion::new();
+        let steps: Vec<u8> = (0..8).map(|_| diffusion.quantize(0.25)).collect();
+        assert_eq!(steps.iter().map(|v| *v as u32).sum::<u32>(), 2);
+
+        diffusion.reset();
+        assert_eq!(diffusion.quantize::<i32>(0.4), 0);
+        assert_eq!(diffusion.quantize::<i32>(0.4), 1);
+    }
+}
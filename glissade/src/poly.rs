@@ -1,14 +1,15 @@
 use crate::{Distance, Mix};
-use std::fmt::Debug;
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 #[derive(Clone)]
-pub(crate) struct Poly<T: Mix + Distance + Clone> {
+pub(crate) struct Poly<T> {
     points: Vec<T>,
     offsets: Vec<f32>,
 }
 
-impl<T: Mix + Distance + Clone + Debug> Debug for Poly<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: Debug> Debug for Poly<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Poly")
             .field("points", &self.points)
             .field("offsets", &self.offsets)
@@ -16,13 +17,13 @@ impl<T: Mix + Distance + Clone + Debug> Debug for Poly<T> {
     }
 }
 
-impl<T: Mix + Distance + Clone + PartialEq> PartialEq for Poly<T> {
+impl<T: PartialEq> PartialEq for Poly<T> {
     fn eq(&self, other: &Self) -> bool {
         self.points == other.points
     }
 }
 
-impl<T: Mix + Distance + Clone + Eq> Eq for Poly<T> {}
+impl<T: Eq> Eq for Poly<T> {}
 
 impl<T: Mix + Distance + Clone> Poly<T> {
     pub fn new(points: Vec<T>) -> Self {
@@ -45,7 +46,9 @@ impl<T: Mix + Distance + Clone> Poly<T> {
             points,
         }
     }
+}
 
+impl<T: Mix + Clone> Poly<T> {
     /// Returns the value at the given time `t` in the range [0.0, 1.0].
     pub fn value_at(&self, t: f32) -> T {
         let offset = self.length() * t.clamp(0.0, 1.0);
@@ -66,7 +69,7 @@ impl<T: Mix + Distance + Clone> Poly<T> {
 
         let f = (offset - o1) / (o2 - o1);
 
-        self.points[i1].clone().mix(self.points[i2].clone(), f)
+        self.points[i1].mix_ref(&self.points[i2], f)
     }
 
     pub(self) fn length(&self) -> f32 {
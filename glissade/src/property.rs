@@ -0,0 +1,192 @@
+use crate::{Animated, Time};
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt::Debug;
+
+/// Access to values of a type by property paths, like `"x"` or `"color.alpha"`.
+/// It allows binding animations to struct fields at runtime, e.g. by names from an animation file.
+///
+/// It can be derived for structs with the `derive` feature. The derived implementation matches
+/// the first path segment with a field name (or an index for tuple structs),
+/// and passes the rest of the path to the field. Primitive types accept only the empty path.
+///
+/// # Example
+///
+/// ```
+/// use glissade::PropertyAccess;
+///
+/// #[derive(PropertyAccess, Debug, PartialEq)]
+/// struct Color {
+///     red: f32,
+///     alpha: f32,
+/// }
+///
+/// #[derive(PropertyAccess, Debug, PartialEq)]
+/// struct Sprite {
+///     x: f32,
+///     color: Color,
+/// }
+///
+/// let mut sprite = Sprite { x: 0.0, color: Color { red: 1.0, alpha: 1.0 } };
+/// assert!(sprite.set_property("color.alpha", 0.5));
+/// assert!(!sprite.set_property("color.green", 0.5));
+/// assert_eq!(sprite.get_property("color.alpha"), Some(0.5));
+/// ```
+pub trait PropertyAccess<V> {
+    /// Get the value of the property, or `None` if there is no such property.
+    fn get_property(&self, path: &str) -> Option<V>;
+    /// Set the value of the property. Returns `false` if there is no such property.
+    fn set_property(&mut self, path: &str, value: V) -> bool;
+}
+
+macro_rules! impl_property_access {
+    ($($t:ty),*) => {
+        $(impl PropertyAccess<$t> for $t {
+            fn get_property(&self, path: &str) -> Option<$t> {
+                if path.is_empty() {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
+
+            fn set_property(&mut self, path: &str, value: $t) -> bool {
+                if path.is_empty() {
+                    *self = value;
+                    true
+                } else {
+                    false
+                }
+            }
+        })*
+    };
+}
+
+impl_property_access!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool, char, String
+);
+
+/// An animation bound to a property path.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Keyframes, PropertyAccess, PropertyTrack};
+///
+/// #[derive(PropertyAccess, Debug, PartialEq)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let track = PropertyTrack::new("y", keyframes::line(0.0, 10.0, 1.0).run(0.0));
+/// let mut point = Point { x: 0.0, y: 0.0 };
+///
+/// assert!(track.apply(&mut point, 0.5));
+/// assert_eq!(point, Point { x: 0.0, y: 5.0 });
+/// ```
+pub struct PropertyTrack<V, X: Time> {
+    path: String,
+    animated: Box<dyn Animated<V, X>>,
+}
+
+impl<V, X: Time> Debug for PropertyTrack<V, X> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PropertyTrack")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<V, X: Time> PropertyTrack<V, X> {
+    pub fn new(path: impl Into<String>, animated: impl Animated<V, X> + 'static) -> Self {
+        Self {
+            path: path.into(),
+            animated: Box::new(animated),
+        }
+    }
+
+    /// Get the path of the animated property.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Write the animated value at a specific time to the target property.
+    /// Returns `false` if the target has no such property.
+    pub fn apply(&self, target: &mut impl PropertyAccess<V>, time: X) -> bool {
+        target.set_property(&self.path, self.animated.get(time))
+    }
+}
+
+impl<V, X: Time> Animated<V, X> for PropertyTrack<V, X> {
+    fn get(&self, time: X) -> V {
+        self.animated.get(time)
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        self.animated.is_finished(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: f32,
+        y: f32,
+    }
+
+    impl PropertyAccess<f32> for Point {
+        fn get_property(&self, path: &str) -> Option<f32> {
+            match path {
+                "x" => Some(self.x),
+                "y" => Some(self.y),
+                _ => None,
+            }
+        }
+
+        fn set_property(&mut self, path: &str, value: f32) -> bool {
+            match path {
+                "x" => self.x = value,
+                "y" => self.y = value,
+                _ => return false,
+            }
+            true
+        }
+    }
+
+    #[test]
+    fn primitive_access() {
+        let mut value = 1.0;
+        assert_eq!(value.get_property(""), Some(1.0));
+        assert_eq!(value.get_property("x"), None);
+        assert!(value.set_property("", 2.0));
+        assert!(!value.set_property("x", 3.0));
+        assert_eq!(value, 2.0);
+    }
+
+    #[test]
+    fn apply_tracks() {
+        let tracks: Vec<PropertyTrack<f32, f32>> = vec![
+            PropertyTrack::new("x", keyframes::line(0.0, 2.0, 1.0).run(0.0)),
+            PropertyTrack::new("y", keyframes::stay(3.0, 2.0).run(0.0)),
+            PropertyTrack::new("z", keyframes::stay(4.0, 1.0).run(0.0)),
+        ];
+        let mut point = Point { x: 0.0, y: 0.0 };
+
+        let applied: Vec<bool> = tracks
+            .iter()
+            .map(|track| track.apply(&mut point, 0.5))
+            .collect();
+
+        assert_eq!(applied, vec![true, true, false]);
+        assert_eq!(point, Point { x: 1.0, y: 3.0 });
+        assert!(tracks[0].is_finished(1.0));
+        assert!(!tracks[1].is_finished(1.0));
+    }
+}
@@ -1,23 +1,52 @@
+use crate::math::{ceil, floor, fract};
+use alloc::vec;
+use alloc::vec::Vec;
+
 /// SmoothArray is a data structure that allows to interpolate values between data points.
-/// Indexes are in range 0.0..=1.0.
+/// Indexes are in range 0.0..=1.0. Values are either evenly spaced, or placed at their own positions,
+/// e.g. for measured data with uneven sampling.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "SmoothArrayRepr", into = "SmoothArrayRepr")
+)]
 pub struct SmoothArray {
     data: Vec<f32>,
+    /// Sorted positions of the values, `None` if the values are evenly spaced.
+    positions: Option<Vec<f32>>,
 }
 
 impl SmoothArray {
     pub fn new(steps_count: usize) -> Self {
         Self {
             data: vec![0.0; steps_count],
+            positions: None,
+        }
+    }
+
+    /// Create an array of values at the given positions, points are sorted by their positions.
+    /// Values before the first and after the last point are the values of these points,
+    /// and points at the same position make a step.
+    pub fn from_points(mut points: Vec<(f32, f32)>) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let (positions, data) = points.into_iter().unzip();
+        Self {
+            data,
+            positions: Some(positions),
         }
     }
 
     pub fn value_at(&self, i: f32) -> f32 {
+        if let Some(positions) = &self.positions {
+            return self.value_at_position(positions, i.clamp(0.0, 1.0));
+        }
+
         let i = i.clamp(0.0, 1.0) * (self.data.len() as f32 - 1.0);
 
-        let f = i.fract();
-        let i1 = i.floor() as usize;
-        let i2 = i.ceil() as usize;
+        let f = fract(i);
+        let i1 = floor(i) as usize;
+        let i2 = ceil(i) as usize;
 
         let v1 = self.data[i1];
         let v2 = self.data[i2];
@@ -25,14 +54,33 @@ impl SmoothArray {
         v1 + (v2 - v1) * f
     }
 
+    fn value_at_position(&self, positions: &[f32], x: f32) -> f32 {
+        let next = positions.partition_point(|position| *position <= x);
+        if next == 0 {
+            return self.data[0];
+        }
+        if next == positions.len() {
+            return self.data[next - 1];
+        }
+
+        let (x1, x2) = (positions[next - 1], positions[next]);
+        let (v1, v2) = (self.data[next - 1], self.data[next]);
+        v1 + (v2 - v1) * ((x - x1) / (x2 - x1))
+    }
+
+    /// Fill evenly spaced values between two points with a line.
     pub fn line(&mut self, (i1, v1): (f32, f32), (i2, v2): (f32, f32)) {
+        assert!(
+            self.positions.is_none(),
+            "SmoothArray::line needs evenly spaced values"
+        );
         let last_index = self.data.len() as f32 - 1.0;
         let i1 = i1 * last_index;
         let i2 = i2 * last_index;
 
         let idi = 1.0 / (i2 - i1);
 
-        let mut i = i1.ceil();
+        let mut i = ceil(i1);
         let max_i = i2.max(last_index);
         while i <= max_i {
             let f = (i - i1) * idi;
@@ -45,7 +93,48 @@ impl SmoothArray {
 
 impl From<Vec<f32>> for SmoothArray {
     fn from(data: Vec<f32>) -> Self {
-        Self { data }
+        Self {
+            data,
+            positions: None,
+        }
+    }
+}
+
+impl From<Vec<(f32, f32)>> for SmoothArray {
+    fn from(points: Vec<(f32, f32)>) -> Self {
+        Self::from_points(points)
+    }
+}
+
+/// Evenly spaced values are serialized as a list of values, as before positions were supported,
+/// other arrays are serialized as a list of `(position, value)` pairs.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum SmoothArrayRepr {
+    Values(Vec<f32>),
+    Points(Vec<(f32, f32)>),
+}
+
+#[cfg(feature = "serde")]
+impl From<SmoothArrayRepr> for SmoothArray {
+    fn from(repr: SmoothArrayRepr) -> Self {
+        match repr {
+            SmoothArrayRepr::Values(data) => data.into(),
+            SmoothArrayRepr::Points(points) => points.into(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SmoothArray> for SmoothArrayRepr {
+    fn from(array: SmoothArray) -> Self {
+        match array.positions {
+            None => SmoothArrayRepr::Values(array.data),
+            Some(positions) => {
+                SmoothArrayRepr::Points(positions.into_iter().zip(array.data).collect())
+            }
+        }
     }
 }
 
@@ -74,4 +163,41 @@ mod tests {
         assert_eq!(array.value_at(0.75), 1.0);
         assert_eq!(array.value_at(1.0), 1.0);
     }
+
+    #[test]
+    fn uneven_points() {
+        let array = SmoothArray::from(vec![(1.0, 1.0), (0.0, 0.0), (0.25, 0.75)]);
+        assert_eq!(array.value_at(0.125), 0.375);
+        assert_eq!(array.value_at(0.25), 0.75);
+        assert_eq!(array.value_at(0.625), 0.875);
+        assert_eq!(array.value_at(2.0), 1.0, "Clamped");
+
+        let step = SmoothArray::from(vec![(0.2, 0.0), (0.5, 0.0), (0.5, 1.0)]);
+        assert_eq!(
+            step.value_at(0.0),
+            0.0,
+            "The first value before the first point"
+        );
+        assert_eq!(step.value_at(0.499), 0.0);
+        assert_eq!(
+            step.value_at(0.5),
+            1.0,
+            "Points at the same position make a step"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn serialization() {
+        let values = SmoothArray::from(vec![0.0, 1.0]);
+        assert_eq!(serde_json::to_string(&values).unwrap(), "[0.0,1.0]");
+        let points = SmoothArray::from(vec![(0.0, 0.0), (0.5, 1.0)]);
+        let json = serde_json::to_string(&points).unwrap();
+        assert_eq!(json, "[[0.0,0.0],[0.5,1.0]]");
+        assert_eq!(serde_json::from_str::<SmoothArray>(&json).unwrap(), points);
+        assert_eq!(
+            serde_json::from_str::<SmoothArray>("[0.0,1.0]").unwrap(),
+            values
+        );
+    }
 }
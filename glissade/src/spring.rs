@@ -0,0 +1,270 @@
+use crate::math::{abs, cos, exp, sin, sqrt};
+use crate::{Animated, Distance, Time, TimeDiff, VectorSpace};
+use core::f32::consts::PI;
+
+/// A value attached to the target with a damped spring.
+/// Unlike `Inertial`, the transition has no fixed duration, it depends on the spring parameters,
+/// and the velocity is preserved when the target changes.
+/// Position and velocity are computed in a closed form, so the result doesn't depend on the frame rate.
+///
+/// Parameters are in units of `TimeDiff::as_f32`, i.e. seconds for `Duration`.
+/// The spring is finished when its energy drops below the rest threshold,
+/// after that the value is exactly the target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring<T, X: Time> {
+    target: T,
+    /// Time, offset from the target, and velocity at the last target change.
+    /// `None` if the spring is at rest.
+    motion: Option<(X, T, T)>,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    rest_delta: f32,
+}
+
+impl<T: VectorSpace + Distance, X: Time> Spring<T, X> {
+    /// Create a spring at rest with the default parameters:
+    /// stiffness 170, damping 26, mass 1, it settles in about half a second without noticeable bouncing.
+    pub fn new(value: T) -> Self {
+        Self {
+            target: value,
+            motion: None,
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            rest_delta: 0.001,
+        }
+    }
+
+    /// Set physical parameters of the spring.
+    /// Stiffness and mass must be positive, damping must not be negative, all of them must be finite.
+    pub fn with_params(mut self, stiffness: f32, damping: f32, mass: f32) -> Self {
+        assert!(
+            stiffness > 0.0
+                && mass > 0.0
+                && damping >= 0.0
+                && (stiffness + damping + mass).is_finite(),
+            "Spring stiffness and mass must be positive, damping must not be negative"
+        );
+        self.stiffness = stiffness;
+        self.damping = damping;
+        self.mass = mass;
+        self
+    }
+
+    /// Set parameters the way designers usually think about springs.
+    /// * `response` - The period of the undamped oscillation, it's roughly how long the transition takes.
+    /// * `damping_ratio` - `1.0` is the fastest motion without overshoot, lower values bounce.
+    pub fn with_response(self, response: f32, damping_ratio: f32) -> Self {
+        assert!(
+            response > 0.0 && response.is_finite(),
+            "Spring response must be positive"
+        );
+        let frequency = 2.0 * PI / response;
+        self.with_params(frequency * frequency, 2.0 * damping_ratio * frequency, 1.0)
+    }
+
+    /// Set the distance from the target (and the equivalent speed) at which the spring stops.
+    /// It's `0.001` by default, and it should be set according to the value units.
+    pub fn with_rest_delta(mut self, rest_delta: f32) -> Self {
+        assert!(rest_delta > 0.0, "Spring rest delta must be positive");
+        self.rest_delta = rest_delta;
+        self
+    }
+
+    /// Get the target value.
+    pub fn target(&self) -> T {
+        self.target.clone()
+    }
+
+    /// Change the target at a specific time, the current position and velocity are kept.
+    pub fn set_target(&mut self, target: T, current_time: X) {
+        let (position, velocity) = self.state(current_time);
+        self.target = target;
+        self.motion = Some((current_time, position.sub(self.target.clone()), velocity));
+    }
+
+    /// Put the spring at rest at the value immediately, e.g. when it's dragged directly.
+    pub fn reset(&mut self, value: T) {
+        self.target = value;
+        self.motion = None;
+    }
+
+    /// Push the spring at a specific time, e.g. with the velocity of a released gesture.
+    pub fn set_velocity(&mut self, velocity: T, current_time: X) {
+        let (position, _) = self.state(current_time);
+        self.motion = Some((current_time, position.sub(self.target.clone()), velocity));
+    }
+
+    /// Get the velocity at a specific time, per unit of `TimeDiff::as_f32`.
+    pub fn velocity(&self, current_time: X) -> T {
+        self.state(current_time).1
+    }
+
+    /// Position and velocity at a specific time.
+    fn state(&self, current_time: X) -> (T, T) {
+        match self.motion {
+            Some(_) if self.is_finished(current_time) => (self.target.clone(), T::zero()),
+            Some((start_time, ref offset, ref velocity)) => {
+                let (offset, velocity) =
+                    self.advance(offset, velocity, current_time.saturating_since(start_time));
+                (self.target.clone().add(offset), velocity)
+            }
+            None => (self.target.clone(), T::zero()),
+        }
+    }
+
+    /// Offset from the target and velocity after `elapsed` time.
+    fn advance(&self, offset: &T, velocity: &T, elapsed: X::Duration) -> (T, T) {
+        let [a, b, c, d] = self.coefficients(elapsed.as_f32());
+        (
+            offset.clone().scale(a).add(velocity.clone().scale(b)),
+            offset.clone().scale(c).add(velocity.clone().scale(d)),
+        )
+    }
+
+    /// The motion is linear in the initial offset `x0` and velocity `v0`,
+    /// so the offset is `a * x0 + b * v0` and the velocity is `c * x0 + d * v0`.
+    fn coefficients(&self, t: f32) -> [f32; 4] {
+        let frequency = sqrt(self.stiffness / self.mass);
+        let ratio = self.damping / (2.0 * sqrt(self.stiffness * self.mass));
+        let decay = ratio * frequency;
+
+        if abs(ratio - 1.0) < 1e-3 {
+            // Critically damped
+            let e = exp(-frequency * t);
+            [
+                e * (1.0 + frequency * t),
+                e * t,
+                -e * frequency * frequency * t,
+                e * (1.0 - frequency * t),
+            ]
+        } else if ratio < 1.0 {
+            // Underdamped, it oscillates around the target
+            let damped = frequency * sqrt(1.0 - ratio * ratio);
+            let e = exp(-decay * t);
+            let (sin, cos) = (sin(damped * t), cos(damped * t));
+            [
+                e * (cos + decay / damped * sin),
+                e * sin / damped,
+                -e * frequency * frequency * sin / damped,
+                e * (cos - decay / damped * sin),
+            ]
+        } else {
+            // Overdamped
+            let spread = frequency * sqrt(ratio * ratio - 1.0);
+            let (r1, r2) = (spread - decay, -spread - decay);
+            let (e1, e2) = (exp(r1 * t), exp(r2 * t));
+            let diff = r2 - r1;
+            [
+                e1 - r1 * (e2 - e1) / diff,
+                (e2 - e1) / diff,
+                r1 * e1 - r1 * (r2 * e2 - r1 * e1) / diff,
+                (r2 * e2 - r1 * e1) / diff,
+            ]
+        }
+    }
+}
+
+impl<T: VectorSpace + Distance, X: Time> Animated<T, X> for Spring<T, X> {
+    fn get(&self, current_time: X) -> T {
+        self.state(current_time).0
+    }
+
+    /// The energy of the spring never grows, so once it's below the threshold the spring stays at rest.
+    fn is_finished(&self, current_time: X) -> bool {
+        let Some((start_time, ref offset, ref velocity)) = self.motion else {
+            return true;
+        };
+        let (offset, velocity) =
+            self.advance(offset, velocity, current_time.saturating_since(start_time));
+        let frequency = sqrt(self.stiffness / self.mass);
+        let distance = offset.distance(T::zero());
+        let speed = velocity.distance(T::zero()) / frequency;
+        sqrt(distance * distance + speed * speed) <= self.rest_delta
+    }
+}
+
+impl<T: VectorSpace + Distance, X: Time> From<T> for Spring<T, X> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32, message: &str) {
+        assert!(abs(a - b) < 1e-2, "{}: {} != {}", message, a, b);
+    }
+
+    #[test]
+    fn at_rest() {
+        let spring: Spring<f32, f32> = Spring::new(1.0);
+        assert_eq!(spring.get(0.0), 1.0);
+        assert!(spring.is_finished(0.0));
+        assert_eq!(spring.velocity(0.0), 0.0);
+    }
+
+    #[test]
+    fn goes_to_target() {
+        for damping in [10.0, 26.08, 60.0] {
+            let mut spring: Spring<f32, f32> = Spring::new(0.0).with_params(170.0, damping, 1.0);
+            spring.set_target(1.0, 1.0);
+            assert_eq!(spring.get(1.0), 0.0, "The spring starts at the old value");
+            assert!(!spring.is_finished(1.0));
+            assert!(spring.get(1.1) > 0.0, "The spring moves to the target");
+            assert!(spring.is_finished(10.0), "The spring settles");
+            assert_eq!(spring.get(10.0), 1.0, "The settled spring is at the target");
+        }
+    }
+
+    #[test]
+    fn velocity_is_derivative() {
+        for damping in [10.0, 26.08, 60.0] {
+            let mut spring: Spring<f32, f32> = Spring::new(0.0).with_params(170.0, damping, 1.0);
+            spring.set_target(1.0, 0.0);
+            spring.set_velocity(-3.0, 0.0);
+            for t in [0.05, 0.1, 0.2, 0.4] {
+                let dt = 1e-3;
+                let derivative = (spring.get(t + dt) - spring.get(t - dt)) / (2.0 * dt);
+                assert_close(
+                    spring.velocity(t),
+                    derivative,
+                    "Velocity matches the motion",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_velocity_on_retarget() {
+        let mut spring: Spring<(f32, f32), f32> = Spring::new((0.0, 0.0)).with_response(0.5, 0.5);
+        spring.set_target((1.0, 1.0), 0.0);
+        let before = (spring.get(0.1), spring.velocity(0.1));
+        spring.set_target((-1.0, 0.0), 0.1);
+        assert_eq!(spring.get(0.1), before.0);
+        assert_eq!(spring.velocity(0.1), before.1);
+        assert!(spring.is_finished(20.0));
+        assert_eq!(spring.get(20.0), (-1.0, 0.0));
+    }
+
+    #[test]
+    fn underdamped_overshoots() {
+        let mut spring: Spring<f32, f32> = Spring::new(0.0).with_response(1.0, 0.2);
+        spring.set_target(1.0, 0.0);
+        let peak = (1..100)
+            .map(|i| spring.get(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+        assert!(peak > 1.0, "An underdamped spring overshoots");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Spring stiffness and mass must be positive, damping must not be negative"
+    )]
+    fn invalid_params() {
+        Spring::<f32, f32>::new(0.0).with_params(100.0, -1.0, 1.0);
+    }
+}
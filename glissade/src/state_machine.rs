@@ -0,0 +1,328 @@
+use crate::{Animated, Easing, Keyframes, Mix, Time, TimeDiff};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Animation of a value driven by a set of states, e.g. a character or a button.
+/// Each state has a keyframes template that runs from the moment the state is entered,
+/// it's usually looping for idle-like states. Transitions cross-fade from the current pose,
+/// including the pose of an unfinished transition, to the keyframes of the new state.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Animated, AnimationStateMachine, Easing, Keyframes};
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// enum Button {
+///     Idle,
+///     Hover,
+/// }
+///
+/// let mut lift = AnimationStateMachine::new(Button::Idle, keyframes::stay(0.0, 0.0), 0.0)
+///     .with_state(Button::Hover, keyframes::line(1.0, 2.0, 1.0).go_to(1.0, 1.0).repeat())
+///     .with_transition(Button::Idle, Button::Hover, 1.0, Easing::Linear);
+///
+/// lift.set_state(Button::Hover, 1.0);
+/// assert_eq!(lift.state(), &Button::Hover);
+/// assert_eq!(lift.get(1.0), 0.0);
+/// assert_eq!(lift.get(1.5), 0.75);
+/// assert_eq!(lift.get(2.5), 1.5);
+/// ```
+pub struct AnimationStateMachine<S, T, X: Time> {
+    states: Vec<(S, Rc<dyn Keyframes<T, X>>)>,
+    transitions: Vec<(S, S, X::Duration, Easing)>,
+    default_transition: (X::Duration, Easing),
+    state: S,
+    pose: Pose<T, X>,
+}
+
+impl<S: Clone + PartialEq, T: Mix, X: Time> AnimationStateMachine<S, T, X> {
+    /// Create a state machine in the `initial` state entered at `start_time`.
+    pub fn new(initial: S, keyframes: impl Keyframes<T, X> + 'static, start_time: X) -> Self {
+        let keyframes: Rc<dyn Keyframes<T, X>> = Rc::new(keyframes);
+        Self {
+            states: Vec::from([(initial.clone(), keyframes.clone())]),
+            transitions: Vec::new(),
+            default_transition: (Default::default(), Easing::Linear),
+            state: initial,
+            pose: Pose::State(keyframes, start_time),
+        }
+    }
+
+    /// Add a state, or replace keyframes of an existing one.
+    /// The new keyframes of the current state are used the next time it's entered.
+    pub fn with_state(mut self, state: S, keyframes: impl Keyframes<T, X> + 'static) -> Self {
+        let keyframes: Rc<dyn Keyframes<T, X>> = Rc::new(keyframes);
+        match self.states.iter_mut().find(|(s, _)| *s == state) {
+            Some((_, existing)) => *existing = keyframes,
+            None => self.states.push((state, keyframes)),
+        }
+        self
+    }
+
+    /// Cross-fade during `duration` with `easing` when the state changes from `from` to `to`.
+    pub fn with_transition(
+        mut self,
+        from: S,
+        to: S,
+        duration: X::Duration,
+        easing: Easing,
+    ) -> Self {
+        self.transitions
+            .retain(|(f, t, _, _)| !(*f == from && *t == to));
+        self.transitions.push((from, to, duration, easing));
+        self
+    }
+
+    /// Set the cross-fade for state changes without a specific transition, they are instant by default.
+    pub fn with_default_transition(mut self, duration: X::Duration, easing: Easing) -> Self {
+        self.default_transition = (duration, easing);
+        self
+    }
+
+    /// Get the current state, it's the target of a running transition.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Switch to another state at `time`, it does nothing if the state is already current.
+    /// It panics if the state wasn't added with `with_state`.
+    pub fn set_state(&mut self, state: S, time: X) {
+        if state == self.state {
+            return;
+        }
+        let keyframes = self
+            .states
+            .iter()
+            .find(|(s, _)| *s == state)
+            .map(|(_, keyframes)| keyframes.clone())
+            .expect("Unknown animation state");
+        let (duration, easing) = self
+            .transitions
+            .iter()
+            .find(|(from, to, _, _)| *from == self.state && *to == state)
+            .map(|(_, _, duration, easing)| (*duration, easing.clone()))
+            .unwrap_or_else(|| self.default_transition.clone());
+
+        let to = Pose::State(keyframes, time);
+        self.pose = if duration > Default::default() {
+            Pose::Blend {
+                from: Box::new(self.pose.clone().clean_up_at(time)),
+                to: Box::new(to),
+                start_time: time,
+                duration,
+                easing,
+            }
+        } else {
+            to
+        };
+        self.state = state;
+    }
+}
+
+impl<S, T: Mix, X: Time> Animated<T, X> for AnimationStateMachine<S, T, X> {
+    fn get(&self, time: X) -> T {
+        self.pose.get(time)
+    }
+
+    /// Looping states are never finished.
+    fn is_finished(&self, time: X) -> bool {
+        self.pose.is_finished(time)
+    }
+}
+
+impl<S: Clone, T, X: Time> Clone for AnimationStateMachine<S, T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            states: self.states.clone(),
+            transitions: self.transitions.clone(),
+            default_transition: self.default_transition.clone(),
+            state: self.state.clone(),
+            pose: self.pose.clone(),
+        }
+    }
+}
+
+impl<S: Debug, T, X: Time> Debug for AnimationStateMachine<S, T, X> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimationStateMachine")
+            .field("state", &self.state)
+            .field(
+                "states",
+                &self.states.iter().map(|(s, _)| s).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// The animated value of the state machine.
+enum Pose<T, X: Time> {
+    /// Keyframes of a state, and the time it was entered.
+    State(Rc<dyn Keyframes<T, X>>, X),
+    /// Cross-fade between two poses.
+    Blend {
+        from: Box<Pose<T, X>>,
+        to: Box<Pose<T, X>>,
+        start_time: X,
+        duration: X::Duration,
+        easing: Easing,
+    },
+}
+
+impl<T, X: Time> Clone for Pose<T, X> {
+    fn clone(&self) -> Self {
+        match self {
+            Pose::State(keyframes, start_time) => Pose::State(keyframes.clone(), *start_time),
+            Pose::Blend {
+                from,
+                to,
+                start_time,
+                duration,
+                easing,
+            } => Pose::Blend {
+                from: from.clone(),
+                to: to.clone(),
+                start_time: *start_time,
+                duration: *duration,
+                easing: easing.clone(),
+            },
+        }
+    }
+}
+
+impl<T: Mix, X: Time> Pose<T, X> {
+    fn get(&self, time: X) -> T {
+        match self {
+            Pose::State(keyframes, start_time) => keyframes.get(time.saturating_since(*start_time)),
+            Pose::Blend {
+                from,
+                to,
+                start_time,
+                duration,
+                easing,
+            } => {
+                let elapsed = time.saturating_since(*start_time);
+                if elapsed >= *duration {
+                    to.get(time)
+                } else {
+                    let t = easing.ease(elapsed.as_f32() / duration.as_f32());
+                    from.get(time).mix(to.get(time), t)
+                }
+            }
+        }
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        match self {
+            Pose::State(keyframes, start_time) => {
+                keyframes.is_finished(time.saturating_since(*start_time))
+            }
+            Pose::Blend {
+                to,
+                start_time,
+                duration,
+                ..
+            } => time.saturating_since(*start_time) >= *duration && to.is_finished(time),
+        }
+    }
+
+    /// Drop poses that are completely faded out.
+    fn clean_up_at(self, time: X) -> Self {
+        match self {
+            Pose::Blend {
+                from,
+                to,
+                start_time,
+                duration,
+                easing,
+            } => {
+                if time.saturating_since(start_time) >= duration {
+                    to.clean_up_at(time)
+                } else {
+                    Pose::Blend {
+                        from: Box::new(from.clean_up_at(time)),
+                        to,
+                        start_time,
+                        duration,
+                        easing,
+                    }
+                }
+            }
+            pose => pose,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    enum State {
+        Idle,
+        Walk,
+        Run,
+    }
+
+    fn machine() -> AnimationStateMachine<State, f32, f32> {
+        AnimationStateMachine::new(State::Idle, keyframes::stay(0.0, 0.0), 0.0)
+            .with_state(State::Walk, keyframes::stay(10.0, 0.0))
+            .with_state(State::Run, keyframes::stay(20.0, 0.0))
+            .with_default_transition(1.0, Easing::Linear)
+    }
+
+    #[test]
+    fn cross_fade() {
+        let mut machine = machine().with_transition(State::Idle, State::Run, 2.0, Easing::Linear);
+        assert_eq!(machine.get(0.0), 0.0);
+        assert!(machine.is_finished(0.0));
+
+        machine.set_state(State::Run, 1.0);
+        assert_eq!(machine.get(2.0), 10.0);
+        assert!(!machine.is_finished(2.0));
+        assert_eq!(machine.get(3.0), 20.0);
+        assert!(machine.is_finished(3.0));
+
+        machine.set_state(State::Run, 4.0);
+        assert_eq!(machine.get(4.0), 20.0, "Same state doesn't restart");
+    }
+
+    #[test]
+    fn interrupted_transition() {
+        let mut machine = machine();
+        machine.set_state(State::Walk, 0.0);
+        machine.set_state(State::Run, 0.5);
+        assert_eq!(
+            machine.get(0.5),
+            5.0,
+            "The new transition starts from the current pose"
+        );
+        assert_eq!(machine.get(1.0), 15.0);
+        assert_eq!(machine.get(1.5), 20.0);
+
+        machine.set_state(State::Idle, 2.0);
+        assert!(matches!(
+            &machine.pose,
+            Pose::Blend { from, .. } if matches!(**from, Pose::State(..))
+        ));
+    }
+
+    #[test]
+    fn instant_switch() {
+        let mut machine = AnimationStateMachine::new(State::Idle, keyframes::stay(0.0, 0.0), 0.0)
+            .with_state(State::Walk, keyframes::line(0.0, 1.0, 1.0));
+        machine.set_state(State::Walk, 1.0);
+        assert_eq!(machine.get(1.5), 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown animation state")]
+    fn unknown_state() {
+        let mut machine: AnimationStateMachine<State, f32, f32> =
+            AnimationStateMachine::new(State::Idle, keyframes::stay(0.0, 0.0), 0.0);
+        machine.set_state(State::Run, 1.0);
+    }
+}
@@ -1,7 +1,17 @@
 use crate::{Animated, Time};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::cmp::Ordering;
+use core::time::Duration;
 
 /// A value that doesn't change over time.
-/// It allows using a static value as an always finished animation.
+/// It allows using a static value as an always finished animation,
+/// e.g. to join a label with an animated position.
+/// It's implemented for primitives, strings, and `Option`s of stationary values,
+/// and can be derived for other types, like enums, with `#[derive(Stationary)]`.
 pub trait Stationary: Clone {}
 
 impl<T: Stationary, X: Time> Animated<T, X> for T {
@@ -14,6 +24,21 @@ impl<T: Stationary, X: Time> Animated<T, X> for T {
     }
 }
 
+/// A value captured from an animation, it's an always finished animation of any cloneable type.
+/// See `Animation::freeze_at`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Frozen<T>(pub T);
+
+impl<T: Clone, X: Time> Animated<T, X> for Frozen<T> {
+    fn get(&self, _time: X) -> T {
+        self.0.clone()
+    }
+
+    fn is_finished(&self, _time: X) -> bool {
+        true
+    }
+}
+
 macro_rules! impl_stationary {
     ($($t:ty),*) => {
         $(impl Stationary for $t {})*
@@ -21,6 +46,60 @@ macro_rules! impl_stationary {
 }
 
 impl_stationary!(
-    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool, char, String,
-    &str
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    bool,
+    char,
+    String,
+    &str,
+    Box<str>,
+    Rc<str>,
+    Arc<str>,
+    Cow<'_, str>,
+    Duration,
+    Ordering
 );
+
+impl<T: Stationary> Stationary for Option<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+
+    fn value_at<T, A: Animated<T, f32>>(animated: &A, time: f32) -> T {
+        animated.get(time)
+    }
+
+    #[test]
+    fn std_types() {
+        assert_eq!(value_at(&"label", 1.0), "label");
+        assert_eq!(
+            value_at(&Some(String::from("label")), 1.0).unwrap(),
+            "label"
+        );
+        assert_eq!(value_at(&Cow::Borrowed("label"), 1.0), "label");
+        assert_eq!(value_at(&Arc::<str>::from("label"), 1.0).as_ref(), "label");
+        assert!(Animated::<Duration, f32>::is_finished(&Duration::ZERO, 0.0));
+    }
+
+    #[test]
+    fn joined_with_animation() {
+        let position = keyframes::line::<f32, f32>(0.0, 10.0, 1.0).run(0.0);
+        let labeled = ("label", position);
+        assert_eq!(labeled.get(0.5), ("label", 5.0));
+        assert!(!labeled.is_finished(0.5));
+    }
+}
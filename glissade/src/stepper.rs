@@ -0,0 +1,277 @@
+use crate::{Animated, Decay, Distance, Mix, Spring, Time, TimeDiff, VectorSpace};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// A stateful animation advanced in fixed time steps, e.g. a hand-integrated physics body.
+pub trait Step<X: Time> {
+    type Value: Mix + Clone;
+
+    /// Advance the state by `timestep`, `time` is the time of the current state.
+    fn step(&mut self, time: X, timestep: X::Duration);
+
+    /// Get the value of the current state, `time` is the time of the state.
+    fn value(&self, time: X) -> Self::Value;
+}
+
+/// Springs are computed in a closed form, stepping only quantizes their time,
+/// so target changes made between updates happen at the step time.
+impl<T: VectorSpace + Distance + Mix, X: Time> Step<X> for Spring<T, X> {
+    type Value = T;
+
+    fn step(&mut self, _time: X, _timestep: X::Duration) {}
+
+    fn value(&self, time: X) -> T {
+        self.get(time)
+    }
+}
+
+/// Same as for `Spring`, the decay is computed in a closed form.
+impl<T: VectorSpace + Distance + Mix, X: Time> Step<X> for Decay<T, X> {
+    type Value = T;
+
+    fn step(&mut self, _time: X, _timestep: X::Duration) {}
+
+    fn value(&self, time: X) -> T {
+        self.get(time)
+    }
+}
+
+/// Advances a set of animations with a fixed timestep, independent of the frame rate,
+/// and interpolates their values between the last two steps for rendering.
+/// It's the usual game loop scheme where the simulation and the render rates are separate.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{Step, Stepper};
+///
+/// struct Falling {
+///     position: f32,
+///     velocity: f32,
+/// }
+///
+/// impl Step<f32> for Falling {
+///     type Value = f32;
+///
+///     fn step(&mut self, _time: f32, timestep: f32) {
+///         self.velocity += 10.0 * timestep;
+///         self.position += self.velocity * timestep;
+///     }
+///
+///     fn value(&self, _time: f32) -> f32 {
+///         self.position
+///     }
+/// }
+///
+/// let mut stepper = Stepper::new(0.1, 0.0);
+/// stepper.push(Falling { position: 0.0, velocity: 0.0 });
+///
+/// assert_eq!(stepper.update(0.25), 2);
+/// assert_eq!(stepper.time(), 0.2);
+/// // Halfway between the steps at 0.1 and 0.2.
+/// assert_eq!(stepper.get(0, 0.25), 0.2);
+/// ```
+pub struct Stepper<S: Step<X>, X: Time> {
+    timestep: X::Duration,
+    max_steps: usize,
+    time: X,
+    items: Vec<S>,
+    /// Values of the items before the last step.
+    previous: Vec<S::Value>,
+}
+
+impl<S: Step<X>, X: Time> Stepper<S, X> {
+    /// Create an empty stepper, the first step starts at `start_time`.
+    /// At most 8 steps are taken per update by default, see `with_max_steps`.
+    pub fn new(timestep: X::Duration, start_time: X) -> Self {
+        assert!(
+            timestep > Default::default(),
+            "Stepper timestep must be positive"
+        );
+        Self {
+            timestep,
+            max_steps: 8,
+            time: start_time,
+            items: Vec::new(),
+            previous: Vec::new(),
+        }
+    }
+
+    /// Limit the number of steps per update. If the simulation falls further behind,
+    /// e.g. after a long frame, the rest of the lag is skipped instead of catching up.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        assert!(max_steps > 0, "Stepper max steps must be positive");
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Add an animation, returns its index.
+    pub fn push(&mut self, item: S) -> usize {
+        self.previous.push(item.value(self.time));
+        self.items.push(item);
+        self.items.len() - 1
+    }
+
+    /// Remove the animation, indexes of the following animations are shifted.
+    pub fn remove(&mut self, index: usize) -> S {
+        self.previous.remove(index);
+        self.items.remove(index)
+    }
+
+    pub fn items(&self) -> &[S] {
+        &self.items
+    }
+
+    /// Get the animations to change them, changes happen at `time`.
+    pub fn items_mut(&mut self) -> &mut [S] {
+        &mut self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Get the time of the last step.
+    pub fn time(&self) -> X {
+        self.time
+    }
+
+    /// Take all whole steps up to `now`, returns the number of steps taken.
+    pub fn update(&mut self, now: X) -> usize {
+        let mut steps = 0;
+        while now.saturating_since(self.time) >= self.timestep {
+            if steps == self.max_steps {
+                self.time = now;
+                for (previous, item) in self.previous.iter_mut().zip(&self.items) {
+                    *previous = item.value(now);
+                }
+                break;
+            }
+            for (previous, item) in self.previous.iter_mut().zip(&mut self.items) {
+                *previous = item.value(self.time);
+                item.step(self.time, self.timestep);
+            }
+            self.time = self.time.advance(self.timestep);
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Get the progress from the last step to the next one at `now`, in `[0, 1]`.
+    pub fn alpha(&self, now: X) -> f32 {
+        let elapsed = now.saturating_since(self.time).as_f32() / self.timestep.as_f32();
+        elapsed.clamp(0.0, 1.0)
+    }
+
+    /// Get the value of the animation interpolated between the last two steps at `now`.
+    pub fn get(&self, index: usize, now: X) -> S::Value {
+        self.previous[index].mix_ref(&self.items[index].value(self.time), self.alpha(now))
+    }
+
+    /// Get interpolated values of all animations.
+    pub fn get_all(&self, now: X) -> Vec<S::Value> {
+        let alpha = self.alpha(now);
+        self.previous
+            .iter()
+            .zip(&self.items)
+            .map(|(previous, item)| previous.mix_ref(&item.value(self.time), alpha))
+            .collect()
+    }
+}
+
+impl<S: Step<X> + Debug, X: Time + Debug> Debug for Stepper<S, X>
+where
+    X::Duration: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Stepper")
+            .field("timestep", &self.timestep)
+            .field("max_steps", &self.max_steps)
+            .field("time", &self.time)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl<S: Step<X> + Clone, X: Time> Clone for Stepper<S, X> {
+    fn clone(&self) -> Self {
+        Self {
+            timestep: self.timestep,
+            max_steps: self.max_steps,
+            time: self.time,
+            items: self.items.clone(),
+            previous: self.previous.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Moves by one unit per step.
+    #[derive(Clone, Debug)]
+    struct Counter(f32);
+
+    impl Step<f32> for Counter {
+        type Value = f32;
+
+        fn step(&mut self, _time: f32, _timestep: f32) {
+            self.0 += 1.0;
+        }
+
+        fn value(&self, _time: f32) -> f32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn fixed_steps() {
+        let mut stepper = Stepper::new(0.5, 0.0);
+        stepper.push(Counter(0.0));
+        stepper.push(Counter(10.0));
+
+        assert_eq!(stepper.update(0.25), 0);
+        assert_eq!(stepper.get_all(0.25), vec![0.0, 10.0]);
+
+        assert_eq!(stepper.update(1.25), 2);
+        assert_eq!(stepper.time(), 1.0);
+        assert_eq!(stepper.alpha(1.25), 0.5);
+        assert_eq!(stepper.get_all(1.25), vec![1.5, 11.5]);
+        assert_eq!(stepper.get(0, 2.0), 2.0, "Alpha is clamped");
+
+        assert_eq!(stepper.remove(0).0, 2.0);
+        assert_eq!(stepper.len(), 1);
+    }
+
+    #[test]
+    fn max_steps() {
+        let mut stepper = Stepper::new(1.0, 0.0).with_max_steps(3);
+        stepper.push(Counter(0.0));
+        assert_eq!(stepper.update(10.5), 3);
+        assert_eq!(stepper.time(), 10.5, "The lag is skipped");
+        assert_eq!(stepper.get(0, 10.5), 3.0, "No interpolation after the skip");
+    }
+
+    #[test]
+    fn springs() {
+        let mut stepper = Stepper::new(0.5, 0.0);
+        stepper.push(Spring::new(0.0));
+        stepper.update(0.5);
+        stepper.items_mut()[0].set_target(1.0, 0.5);
+        assert_eq!(stepper.get(0, 0.75), 0.0);
+
+        stepper.update(10.0);
+        assert_eq!(stepper.get(0, 10.0), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Stepper timestep must be positive")]
+    fn zero_timestep() {
+        Stepper::<Counter, f32>::new(0.0, 0.0);
+    }
+}
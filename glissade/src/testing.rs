@@ -0,0 +1,123 @@
+//! Assertions for tests of animations, see `assert_animates!`.
+//! Values are compared approximately, with a tolerance on their `Distance`.
+
+use crate::{Animated, Distance, Time};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Debug;
+
+/// Check if two values are within `tolerance` of each other.
+/// NaN distances are never within the tolerance.
+pub fn approx_eq<T: Distance>(a: T, b: T, tolerance: f32) -> bool {
+    a.distance(b) <= tolerance
+}
+
+/// Assert that two values are within `tolerance` of each other.
+///
+/// # Example
+///
+/// ```
+/// use glissade::testing::assert_approx_eq;
+///
+/// assert_approx_eq((0.1 + 0.2, 1.0), (0.3, 1.0), 1e-6);
+/// ```
+#[track_caller]
+pub fn assert_approx_eq<T: Distance + Clone + Debug>(actual: T, expected: T, tolerance: f32) {
+    let distance = actual.clone().distance(expected.clone());
+    assert!(
+        distance <= tolerance,
+        "Values are not approximately equal: expected {:?}, got {:?}, distance {} > {}",
+        expected,
+        actual,
+        distance,
+        tolerance
+    );
+}
+
+/// Assert that the animation has the expected values at the given times,
+/// each value is compared with its own tolerance. All mismatches are reported at once.
+/// It's the function behind `assert_animates!`.
+#[track_caller]
+pub fn assert_animates<T: Distance + Clone + Debug, X: Time + Debug>(
+    animated: &impl Animated<T, X>,
+    expectations: &[(X, T, f32)],
+) {
+    let mut mismatches = String::new();
+    for (time, expected, tolerance) in expectations {
+        let actual = animated.get(*time);
+        if !approx_eq(actual.clone(), expected.clone(), *tolerance) {
+            let distance = actual.clone().distance(expected.clone());
+            mismatches += &format!(
+                "\n  at {:?}: expected {:?}, got {:?}, distance {} > {}",
+                time, expected, actual, distance, tolerance
+            );
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "Animation doesn't have the expected values:{}",
+        mismatches
+    );
+}
+
+/// Assert that an animation has the expected values at the given times,
+/// as a list of `(time, expected, tolerance)` tuples. Values are compared with `Distance`.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{assert_animates, keyframes, Keyframes};
+///
+/// let animation = keyframes::line(0.0, 10.0, 1.0).run(0.0);
+/// assert_animates!(animation, [
+///     (0.0, 0.0, 1e-6),
+///     (0.5, 5.0, 1e-6),
+///     (2.0, 10.0, 1e-6),
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! assert_animates {
+    ($animated:expr, [$(($time:expr, $expected:expr, $tolerance:expr)),* $(,)?]) => {
+        $crate::testing::assert_animates(
+            &$animated,
+            &[$(($time, $expected, $tolerance)),*],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+
+    #[test]
+    fn approximate_values() {
+        assert!(approx_eq(1.0f32, 1.05, 0.1));
+        assert!(!approx_eq(1.0f32, 1.2, 0.1));
+        assert!(!approx_eq(f32::NAN, f32::NAN, 1.0), "NaN is never equal");
+        assert_approx_eq([1.0f32, 2.0], [1.0, 2.001], 0.01);
+    }
+
+    #[test]
+    fn animates() {
+        let animation = keyframes::line::<(f32, f32), f32>((0.0, 0.0), (4.0, 2.0), 2.0).run(1.0);
+        crate::assert_animates!(
+            animation,
+            [
+                (1.0, (0.0, 0.0), 0.0),
+                (2.0, (2.0, 1.0), 1e-6),
+                (5.0, (4.0, 2.0), 1e-6),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at 0.5: expected 3.0, got 5.0, distance 2 > 0.1\n  at 1.0")]
+    fn reports_all_mismatches() {
+        let animation = keyframes::line::<f32, f32>(0.0, 10.0, 1.0).run(0.0);
+        crate::assert_animates!(
+            animation,
+            [(0.0, 0.0, 0.1), (0.5, 3.0, 0.1), (1.0, 0.0, 0.1)]
+        );
+    }
+}
@@ -1,89 +1,373 @@
+use crate::math::round_f64;
+use core::cmp::Ordering;
+use core::ops::{Add, Sub};
+
 /// Time trait should be implemented for types that represent animation time.
-/// It's implemented for `f32`, `f64`, `std::time::Instant`, and `std::time::SystemTime` by default.
+/// It's implemented for `f32`, `f64`, `u32`, `u64`, `std::time::Instant`, and `std::time::SystemTime` by default.
 /// You can implement it for your own types.
 pub trait Time: PartialEq + PartialOrd + Clone + Copy {
     /// Positive time difference
-    type Duration: Default + PartialEq + PartialOrd + Clone + Copy;
+    type Duration: TimeDiff;
 
     /// Panics if `self < earlier`
     fn since(self, earlier: Self) -> Self::Duration;
+
+    /// Same as `since`, but returns zero duration if `self < earlier` instead of panicking.
+    fn saturating_since(self, earlier: Self) -> Self::Duration {
+        if self < earlier {
+            Default::default()
+        } else {
+            self.since(earlier)
+        }
+    }
+
+    /// Same as `since`, but returns `None` if `self < earlier`.
+    fn checked_since(self, earlier: Self) -> Option<Self::Duration> {
+        if self < earlier {
+            None
+        } else {
+            Some(self.since(earlier))
+        }
+    }
+
     fn advance(self, duration: Self::Duration) -> Self;
 
-    fn duration_as_f32(duration: Self::Duration) -> f32;
-    fn duration_sum(duration: Self::Duration, other: Self::Duration) -> Self::Duration;
-    fn duration_diff(duration: Self::Duration, other: Self::Duration) -> Self::Duration;
-    fn duration_scale(duration: Self::Duration, scale: f32) -> Self::Duration;
+    /// Same as `advance`, but returns `None` if the result can't be represented.
+    /// The default implementation can't detect overflow, implementations should override it.
+    fn checked_advance(self, duration: Self::Duration) -> Option<Self> {
+        Some(self.advance(duration))
+    }
 }
 
-impl Time for f32 {
-    type Duration = f32;
-    fn since(self, earlier: f32) -> f32 {
-        if self < earlier {
-            panic!("Time::since: self < earlier");
+/// TimeDiff trait should be implemented for durations, the difference between two `Time` values.
+/// Durations are added and subtracted with the usual operators,
+/// the subtraction is only used when the result is non-negative.
+/// It's implemented for `f32`, `f64`, `u32`, `u64`, and `core::time::Duration` by default.
+pub trait TimeDiff:
+    Default + PartialEq + PartialOrd + Clone + Copy + Add<Output = Self> + Sub<Output = Self>
+{
+    /// Convert the duration to a number. It's used to compute the ratio between two durations,
+    /// so units don't matter, but they should be the same for all durations of a type.
+    fn as_f32(self) -> f32;
+
+    /// Same as `as_f32`, but keeps more precision, it's used by the `f64` feature.
+    /// The default implementation converts `as_f32`, implementations should override it.
+    fn as_f64(self) -> f64 {
+        self.as_f32() as f64
+    }
+
+    /// Convert a number in the units of `as_f64` back to a duration, e.g. seconds for `Duration`.
+    /// Returns `None` if `value` is negative or not finite, or if the result can't be represented.
+    /// The default implementation always returns `None`, implementations should override it.
+    fn from_f64(_value: f64) -> Option<Self> {
+        None
+    }
+
+    /// Multiply the duration by a non-negative factor, it panics if the factor is negative or NaN.
+    /// Implementations saturate to the largest duration on overflow instead of panicking,
+    /// and should compute in `f64` to keep precision for long durations.
+    fn scale(self, scale: f32) -> Self;
+
+    /// Same as `scale`, but returns `None` if `scale` is negative or not finite,
+    /// or if the result can't be represented.
+    /// The default implementation can't detect overflow, implementations should override it.
+    fn checked_scale(self, scale: f32) -> Option<Self> {
+        if scale >= 0.0 && scale.is_finite() {
+            Some(self.scale(scale))
+        } else {
+            None
+        }
+    }
+
+    /// Get the remainder of dividing the duration by `period`, it's always non-negative.
+    /// Unlike going through `as_f32`, it doesn't lose precision for long durations.
+    /// Returns zero if `period` isn't positive.
+    /// The default implementation subtracts doubled periods, implementations may override it.
+    fn wrap(self, period: Self) -> Self {
+        let zero = Self::default();
+        if period.partial_cmp(&zero) != Some(Ordering::Greater) {
+            return zero;
+        }
+        if self < zero {
+            let rest = (zero - self).wrap(period);
+            return if rest > zero { period - rest } else { zero };
         }
 
-        self - earlier
+        let mut rest = self;
+        while rest >= period {
+            let mut chunk = period;
+            while rest - chunk >= chunk {
+                chunk = chunk + chunk;
+            }
+            rest = rest - chunk;
+        }
+        rest
     }
+}
+
+macro_rules! impl_time_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl Time for $t {
+                type Duration = $t;
+                fn since(self, earlier: $t) -> $t {
+                    if self < earlier {
+                        panic!("Time::since: self < earlier");
+                    }
+                    self - earlier
+                }
+
+                fn advance(self, duration: $t) -> $t {
+                    self + duration
+                }
+
+                fn checked_advance(self, duration: $t) -> Option<$t> {
+                    Some(self + duration).filter(|time| time.is_finite())
+                }
+            }
+
+            impl TimeDiff for $t {
+                fn as_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Option<$t> {
+                    Some(value as $t).filter(|duration| *duration >= 0.0 && duration.is_finite())
+                }
+
+                fn scale(self, scale: f32) -> $t {
+                    if scale < 0.0 || scale.is_nan() {
+                        panic!("TimeDiff::scale: scale is negative or NaN");
+                    }
+                    self * scale as $t
+                }
+
+                fn checked_scale(self, scale: f32) -> Option<$t> {
+                    if scale >= 0.0 {
+                        Some(self * scale as $t).filter(|duration| duration.is_finite())
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_time_for_float!(f32, f64);
+
+/// Integer time is treated as a tick counter, for example, milliseconds or frame numbers.
+/// Time arithmetic is saturating, and duration scaling saturates on overflow,
+/// but a negative or NaN scale panics, like for other durations.
+macro_rules! impl_time_for_ticks {
+    ($($t:ty),*) => {
+        $(
+            impl Time for $t {
+                type Duration = $t;
+                fn since(self, earlier: $t) -> $t {
+                    self.saturating_sub(earlier)
+                }
+
+                fn checked_since(self, earlier: $t) -> Option<$t> {
+                    self.checked_sub(earlier)
+                }
+
+                fn advance(self, duration: $t) -> $t {
+                    self.saturating_add(duration)
+                }
+
+                fn checked_advance(self, duration: $t) -> Option<$t> {
+                    self.checked_add(duration)
+                }
+            }
+
+            impl TimeDiff for $t {
+                fn as_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
 
-    fn advance(self, duration: f32) -> f32 {
-        self + duration
+                fn from_f64(value: f64) -> Option<$t> {
+                    let ticks = round_f64(value);
+                    if ticks >= 0.0 && ticks <= <$t>::MAX as f64 {
+                        Some(ticks as $t)
+                    } else {
+                        None
+                    }
+                }
+
+                fn scale(self, scale: f32) -> $t {
+                    if scale < 0.0 || scale.is_nan() {
+                        panic!("TimeDiff::scale: scale is negative or NaN");
+                    }
+                    round_f64(self as f64 * scale as f64) as $t
+                }
+
+                fn wrap(self, period: $t) -> $t {
+                    self.checked_rem(period).unwrap_or(0)
+                }
+
+                fn checked_scale(self, scale: f32) -> Option<$t> {
+                    let result = round_f64(self as f64 * scale as f64);
+                    if scale >= 0.0 && result <= <$t>::MAX as f64 {
+                        Some(result as $t)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_time_for_ticks!(u32, u64);
+
+/// It's used by `std::time::Instant`, `std::time::SystemTime`, and their `web_time` counterparts.
+impl TimeDiff for core::time::Duration {
+    fn as_f32(self) -> f32 {
+        self.as_secs_f32()
     }
 
-    fn duration_as_f32(duration: f32) -> f32 {
-        duration
+    fn as_f64(self) -> f64 {
+        self.as_secs_f64()
     }
 
-    fn duration_sum(duration: f32, other: f32) -> f32 {
-        duration + other
+    fn from_f64(value: f64) -> Option<Self> {
+        Self::try_from_secs_f64(value).ok()
     }
 
-    fn duration_diff(duration: f32, other: f32) -> f32 {
-        if duration < other {
-            panic!("Time::sub_duration: duration < other");
+    fn scale(self, scale: f32) -> Self {
+        if scale < 0.0 || scale.is_nan() {
+            panic!("TimeDiff::scale: scale is negative or NaN");
         }
-        duration - other
+        self.checked_scale(scale).unwrap_or(Self::MAX)
+    }
+
+    fn checked_scale(self, scale: f32) -> Option<Self> {
+        Self::try_from_secs_f64(self.as_secs_f64() * scale as f64).ok()
     }
 
-    fn duration_scale(duration: f32, scale: f32) -> f32 {
-        if scale < 0.0 {
-            panic!("Time::scale_duration: scale < 0.0");
+    fn wrap(self, period: Self) -> Self {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        match self.as_nanos().checked_rem(period.as_nanos()) {
+            Some(rest) => Self::new((rest / NANOS_PER_SEC) as u64, (rest % NANOS_PER_SEC) as u32),
+            None => Self::ZERO,
         }
-        duration * scale
     }
 }
 
-impl Time for f64 {
-    type Duration = f64;
-    fn since(self, earlier: f64) -> f64 {
-        if self < earlier {
-            panic!("Time::since: self < earlier");
-        }
-        self - earlier
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Animated, Keyframes};
+    use core::time::Duration;
+
+    #[test]
+    fn ticks_are_saturating() {
+        assert_eq!(5u32.since(10), 0);
+        assert_eq!(5u32.saturating_since(10), 0);
+        assert_eq!(u32::MAX.advance(10), u32::MAX);
+        assert_eq!(u32::MAX.scale(2.0), u32::MAX);
+        assert_eq!(10u32.scale(0.25), 3);
+        assert_eq!(Duration::MAX.scale(2.0), Duration::MAX);
     }
 
-    fn advance(self, duration: f64) -> f64 {
-        self + duration
+    #[test]
+    fn from_f64() {
+        assert_eq!(f32::from_f64(0.5), Some(0.5));
+        assert_eq!(f32::from_f64(-0.5), None);
+        assert_eq!(u32::from_f64(2.6), Some(3));
+        assert_eq!(u32::from_f64(1e10), None);
+        assert_eq!(Duration::from_f64(1.5), Some(Duration::from_millis(1500)));
+        assert_eq!(Duration::from_f64(f64::NAN), None);
     }
 
-    fn duration_as_f32(duration: f64) -> f32 {
-        duration as f32
+    #[test]
+    fn long_duration_scale() {
+        let week = Duration::from_secs(7 * 86_400) + Duration::from_nanos(1);
+        assert_eq!(week.scale(2.0), week * 2);
+        assert_eq!(week.checked_scale(2.0), Some(week * 2));
+        assert_eq!(86_400_000_001u64.scale(1.0), 86_400_000_001);
     }
 
-    fn duration_sum(duration: f64, other: f64) -> f64 {
-        duration + other
+    #[test]
+    #[should_panic(expected = "TimeDiff::scale: scale is negative or NaN")]
+    fn nan_scale() {
+        Duration::from_secs(1).scale(f32::NAN);
     }
 
-    fn duration_diff(duration: f64, other: f64) -> f64 {
-        if duration < other {
-            panic!("Time::sub_duration: duration < other");
-        }
-        duration - other
+    #[test]
+    fn wrap() {
+        assert_eq!(7.5f32.wrap(2.0), 1.5);
+        assert_eq!((-0.5f64).wrap(2.0), 1.5);
+        assert_eq!(1.0f32.wrap(0.0), 0.0);
+        assert_eq!(7u32.wrap(3), 1);
+        assert_eq!(7u64.wrap(0), 0);
+
+        let long = Duration::from_secs(1_000_000) + Duration::from_nanos(250_000_001);
+        assert_eq!(
+            long.wrap(Duration::from_millis(500)),
+            Duration::from_nanos(250_000_001)
+        );
     }
 
-    fn duration_scale(duration: f64, scale: f32) -> f64 {
-        if scale < 0.0 {
-            panic!("Time::scale_duration: scale < 0.0");
-        }
-        duration * scale as f64
+    #[test]
+    fn as_f64() {
+        assert_eq!(0.1f32.as_f64(), 0.1f32 as f64);
+        assert_eq!(16_777_217u32.as_f64(), 16_777_217.0);
+        assert_eq!(Duration::from_nanos(1_000_000_001).as_f64(), 1.000000001);
+    }
+
+    #[cfg(feature = "f64")]
+    #[test]
+    fn f64_precision() {
+        // A day in milliseconds
+        let keyframes = keyframes::line::<f64, u64>(0.0, 86_400_000.0, 86_400_000);
+        assert!((keyframes.get(86_399_999) - 86_399_999.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn saturating_since() {
+        assert_eq!(1.0f32.saturating_since(3.0), 0.0);
+        assert_eq!(3.0f64.saturating_since(1.0), 2.0);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(1.0f32.checked_since(3.0), None);
+        assert_eq!(3.0f32.checked_since(1.0), Some(2.0));
+        assert_eq!(f32::MAX.checked_advance(f32::MAX), None);
+        assert_eq!(2.0f64.checked_scale(-1.0), None);
+        assert_eq!(2.0f64.checked_scale(f32::NAN), None);
+        assert_eq!(2.0f64.checked_scale(1.5), Some(3.0));
+
+        assert_eq!(1u32.checked_since(2), None);
+        assert_eq!(u64::MAX.checked_advance(1), None);
+        assert_eq!(u32::MAX.checked_scale(2.0), None);
+        assert_eq!(10u32.checked_scale(0.5), Some(5));
+
+        assert_eq!(Duration::MAX.checked_scale(2.0), None);
+        assert_eq!(Duration::from_secs(2).checked_scale(-1.0), None);
+        assert_eq!(
+            Duration::from_secs(2).checked_scale(0.5),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn ticks_animation() {
+        let animation = keyframes::from::<f32, u64>(0.0).go_to(10.0, 100).run(1000);
+        assert_eq!(animation.get(1000), 0.0);
+        assert_eq!(animation.get(1050), 5.0);
+        assert_eq!(animation.get(1100), 10.0);
+        assert!(!animation.is_finished(1099));
+        assert!(animation.is_finished(1100));
     }
 }
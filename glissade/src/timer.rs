@@ -0,0 +1,259 @@
+use crate::{Animated, Time};
+use alloc::boxed::Box;
+use core::fmt::Debug;
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A source of the current time that can wait for the next tick, e.g. the next frame,
+/// it drives `Animated::finished` futures.
+///
+/// There are adapters for async runtimes: `driver::tokio::TokioTimer` with the `"tokio"` feature,
+/// and `driver::web::FrameTimer` with the `"web"` feature.
+pub trait Timer<X: Time> {
+    type Tick: Future<Output = ()>;
+
+    /// Get the current time.
+    fn now(&self) -> X;
+
+    /// Wait for the next tick, the animation is checked after each tick.
+    fn tick(&self) -> Self::Tick;
+}
+
+impl<X: Time, C: Timer<X>> Timer<X> for &C {
+    type Tick = C::Tick;
+
+    fn now(&self) -> X {
+        (*self).now()
+    }
+
+    fn tick(&self) -> Self::Tick {
+        (*self).tick()
+    }
+}
+
+/// A future that resolves when the animation is finished, see `Animated::finished`.
+pub struct Finished<'a, T, X: Time, A: ?Sized, C: Timer<X>> {
+    animated: &'a A,
+    timer: C,
+    tick: Option<Pin<Box<C::Tick>>>,
+    phantom: PhantomData<fn() -> (T, X)>,
+}
+
+impl<'a, T, X: Time, A: Animated<T, X> + ?Sized, C: Timer<X>> Finished<'a, T, X, A, C> {
+    pub(crate) fn new(animated: &'a A, timer: C) -> Self {
+        Self {
+            animated,
+            timer,
+            tick: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// Ticks are boxed, so nothing is pinned in place.
+impl<T, X: Time, A: ?Sized, C: Timer<X>> Unpin for Finished<'_, T, X, A, C> {}
+
+impl<T, X: Time, A: Animated<T, X> + ?Sized, C: Timer<X>> Future for Finished<'_, T, X, A, C> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+
+        if let Some(tick) = &mut this.tick {
+            if tick.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            this.tick = None;
+        }
+
+        if this.animated.is_finished(this.timer.now()) {
+            return Poll::Ready(());
+        }
+
+        let mut tick = Box::pin(this.timer.tick());
+        if tick.as_mut().poll(cx).is_ready() {
+            // Yield to the executor anyway, so a timer with ready ticks doesn't block it.
+            cx.waker().wake_by_ref();
+        } else {
+            this.tick = Some(tick);
+        }
+        Poll::Pending
+    }
+}
+
+impl<T, X: Time, A: ?Sized, C: Timer<X> + Debug> Debug for Finished<'_, T, X, A, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Finished")
+            .field("timer", &self.timer)
+            .field("waiting", &self.tick.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A stream of animation values sampled on every timer tick, see `Animated::stream`.
+#[cfg(feature = "futures")]
+pub struct AnimatedStream<T, X: Time, A, C: Timer<X>> {
+    animated: A,
+    timer: C,
+    tick: Option<Pin<Box<C::Tick>>>,
+    finished: bool,
+    phantom: PhantomData<fn() -> (T, X)>,
+}
+
+#[cfg(feature = "futures")]
+impl<T, X: Time, A: Animated<T, X>, C: Timer<X>> AnimatedStream<T, X, A, C> {
+    pub(crate) fn new(animated: A, timer: C) -> Self {
+        Self {
+            animated,
+            timer,
+            tick: None,
+            finished: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// Ticks are boxed, and the animation is never pinned.
+#[cfg(feature = "futures")]
+impl<T, X: Time, A, C: Timer<X>> Unpin for AnimatedStream<T, X, A, C> {}
+
+#[cfg(feature = "futures")]
+impl<T, X: Time, A: Animated<T, X>, C: Timer<X>> futures_core::Stream
+    for AnimatedStream<T, X, A, C>
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        if this.finished {
+            return Poll::Ready(None);
+        }
+
+        if let Some(tick) = &mut this.tick {
+            if tick.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            this.tick = None;
+        }
+
+        let time = this.timer.now();
+        this.finished = this.animated.is_finished(time);
+        if !this.finished {
+            this.tick = Some(Box::pin(this.timer.tick()));
+        }
+        Poll::Ready(Some(this.animated.get(time)))
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T, X: Time, A, C: Timer<X>> futures_core::FusedStream for AnimatedStream<T, X, A, C>
+where
+    Self: futures_core::Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.finished
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T, X: Time, A: Debug, C: Timer<X> + Debug> Debug for AnimatedStream<T, X, A, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimatedStream")
+            .field("animated", &self.animated)
+            .field("timer", &self.timer)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+    use core::cell::Cell;
+    use core::future::Ready;
+    use std::task::Waker;
+
+    /// A timer that moves its time forward on every tick.
+    struct StepTimer {
+        time: Cell<f32>,
+        step: f32,
+    }
+
+    impl Timer<f32> for StepTimer {
+        type Tick = Ready<()>;
+
+        fn now(&self) -> f32 {
+            self.time.get()
+        }
+
+        fn tick(&self) -> Ready<()> {
+            self.time.set(self.time.get() + self.step);
+            core::future::ready(())
+        }
+    }
+
+    fn block_on(future: impl Future<Output = ()>) -> usize {
+        let mut future = Box::pin(future);
+        let mut context = Context::from_waker(Waker::noop());
+        let mut polls = 1;
+        while future.as_mut().poll(&mut context).is_pending() {
+            polls += 1;
+        }
+        polls
+    }
+
+    #[test]
+    fn waits_for_the_end() {
+        let timer = StepTimer {
+            time: Cell::new(0.0),
+            step: 0.25,
+        };
+        let animation = keyframes::line(0.0, 1.0, 1.0).run(0.0);
+
+        let polls = block_on(animation.finished(&timer));
+        assert_eq!(timer.now(), 1.0, "Resolves at the first tick after the end");
+        assert_eq!(polls, 5, "Yields after each ready tick");
+        assert!(animation.is_finished(timer.now()));
+    }
+
+    #[test]
+    #[cfg(feature = "futures")]
+    fn stream() {
+        use futures_core::{FusedStream, Stream};
+
+        let timer = StepTimer {
+            time: Cell::new(0.0),
+            step: 0.25,
+        };
+        let mut stream = keyframes::line(0.0, 4.0, 1.0).run(0.0).stream(&timer);
+        let mut context = Context::from_waker(Waker::noop());
+        let mut values = Vec::new();
+        while let Poll::Ready(Some(value)) = Pin::new(&mut stream).poll_next(&mut context) {
+            values.push(value);
+        }
+        assert_eq!(
+            values,
+            [0.0, 1.0, 2.0, 3.0, 4.0],
+            "Ends with the final value"
+        );
+        assert!(stream.is_terminated());
+        assert_eq!(
+            Pin::new(&mut stream).poll_next(&mut context),
+            Poll::Ready(None)
+        );
+    }
+
+    #[test]
+    fn finished_animation() {
+        let timer = StepTimer {
+            time: Cell::new(2.0),
+            step: 0.25,
+        };
+        let animation = keyframes::line(0.0, 1.0, 1.0).run(0.0);
+        assert_eq!(block_on(animation.finished(&timer)), 1);
+        assert_eq!(timer.now(), 2.0, "Doesn't wait for a tick");
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{Animated, Keyframes, Time};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A timeline of named tracks, each one is keyframes of its own value type.
+/// All tracks run from the same start time, and `sample` gets values of all active tracks at once,
+/// e.g. to apply a data-driven animation to several properties of an object.
+///
+/// Tracks can be disabled, or soloed: if any track is soloed, only soloed tracks are active.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Keyframes, Tracks};
+///
+/// let mut tracks = Tracks::new(0.0);
+/// tracks.insert("opacity", keyframes::line::<f32, f32>(0.0, 1.0, 1.0));
+/// tracks.insert("label", keyframes::stay::<&str, f32>("hello", 2.0));
+///
+/// let pose = tracks.sample(0.5);
+/// assert_eq!(pose.get::<f32>("opacity"), Some(0.5));
+/// assert_eq!(pose.get::<&str>("label"), Some("hello"));
+/// assert_eq!(pose.get::<u32>("opacity"), None);
+///
+/// tracks.set_solo("label", true);
+/// assert_eq!(tracks.sample(0.5).get::<f32>("opacity"), None);
+/// ```
+pub struct Tracks<X: Time> {
+    start_time: X,
+    tracks: Vec<Track<X>>,
+}
+
+struct Track<X: Time> {
+    name: String,
+    keyframes: Box<dyn ErasedKeyframes<X>>,
+    enabled: bool,
+    solo: bool,
+}
+
+impl<X: Time> Tracks<X> {
+    /// Create an empty timeline that starts at `start_time`.
+    pub fn new(start_time: X) -> Self {
+        Self {
+            start_time,
+            tracks: Vec::new(),
+        }
+    }
+
+    /// Add a track, a track with the same name is replaced.
+    /// The track is enabled and not soloed.
+    pub fn insert<T: 'static>(
+        &mut self,
+        name: impl Into<String>,
+        keyframes: impl Keyframes<T, X> + 'static,
+    ) {
+        let track = Track {
+            name: name.into(),
+            keyframes: Box::new(Erased(keyframes, PhantomData)),
+            enabled: true,
+            solo: false,
+        };
+        match self.tracks.iter_mut().find(|t| t.name == track.name) {
+            Some(existing) => *existing = track,
+            None => self.tracks.push(track),
+        }
+    }
+
+    /// Remove a track, returns `false` if there is no such track.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.tracks.len();
+        self.tracks.retain(|track| track.name != name);
+        self.tracks.len() != len
+    }
+
+    /// Get track names in the order they were added.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tracks.iter().map(|track| track.name.as_str())
+    }
+
+    /// Enable or disable a track, returns `false` if there is no such track.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        self.track_mut(name)
+            .map(|track| track.enabled = enabled)
+            .is_some()
+    }
+
+    /// Solo or unsolo a track, returns `false` if there is no such track.
+    pub fn set_solo(&mut self, name: &str, solo: bool) -> bool {
+        self.track_mut(name)
+            .map(|track| track.solo = solo)
+            .is_some()
+    }
+
+    /// Check if a track is sampled: it's enabled, and it's soloed if any track is soloed.
+    pub fn is_active(&self, name: &str) -> bool {
+        self.tracks
+            .iter()
+            .find(|track| track.name == name)
+            .is_some_and(|track| self.is_track_active(track))
+    }
+
+    /// Get the start time of all tracks.
+    pub fn start_time(&self) -> X {
+        self.start_time
+    }
+
+    /// Run all tracks from the beginning at `start_time`.
+    pub fn restart(&mut self, start_time: X) {
+        self.start_time = start_time;
+    }
+
+    /// Get values of all active tracks at `time`.
+    pub fn sample(&self, time: X) -> TrackPose {
+        let offset = time.saturating_since(self.start_time);
+        TrackPose {
+            values: self
+                .active_tracks()
+                .map(|track| (track.name.clone(), track.keyframes.sample(offset)))
+                .collect(),
+        }
+    }
+
+    fn track_mut(&mut self, name: &str) -> Option<&mut Track<X>> {
+        self.tracks.iter_mut().find(|track| track.name == name)
+    }
+
+    fn is_track_active(&self, track: &Track<X>) -> bool {
+        let any_solo = self.tracks.iter().any(|track| track.solo);
+        track.enabled && (track.solo || !any_solo)
+    }
+
+    fn active_tracks(&self) -> impl Iterator<Item = &Track<X>> {
+        self.tracks
+            .iter()
+            .filter(|track| self.is_track_active(track))
+    }
+}
+
+impl<X: Time> Animated<TrackPose, X> for Tracks<X> {
+    fn get(&self, time: X) -> TrackPose {
+        self.sample(time)
+    }
+
+    /// All active tracks are finished.
+    fn is_finished(&self, time: X) -> bool {
+        let offset = time.saturating_since(self.start_time);
+        self.active_tracks()
+            .all(|track| track.keyframes.is_finished(offset))
+    }
+}
+
+impl<X: Time + Debug> Debug for Tracks<X> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Tracks")
+            .field("start_time", &self.start_time)
+            .field("tracks", &self.names().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Values of the tracks sampled at the same time, see `Tracks::sample`.
+#[derive(Debug, Default)]
+pub struct TrackPose {
+    values: BTreeMap<String, Box<dyn Any>>,
+}
+
+impl TrackPose {
+    /// Get the value of a track, or `None` if there is no such track in the pose,
+    /// or its value type is different.
+    pub fn get<T: Clone + 'static>(&self, name: &str) -> Option<T> {
+        self.get_ref(name).cloned()
+    }
+
+    /// Same as `get`, but the value is borrowed.
+    pub fn get_ref<T: 'static>(&self, name: &str) -> Option<&T> {
+        self.values.get(name)?.downcast_ref()
+    }
+
+    /// Check if the track is in the pose.
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Get the names of sampled tracks, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    /// Get the number of sampled tracks.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if no tracks were sampled.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Keyframes with the value type erased.
+trait ErasedKeyframes<X: Time> {
+    fn sample(&self, offset: X::Duration) -> Box<dyn Any>;
+    fn is_finished(&self, offset: X::Duration) -> bool;
+}
+
+struct Erased<T, K>(K, PhantomData<fn() -> T>);
+
+impl<T: 'static, X: Time, K: Keyframes<T, X>> ErasedKeyframes<X> for Erased<T, K> {
+    fn sample(&self, offset: X::Duration) -> Box<dyn Any> {
+        Box::new(self.0.get(offset))
+    }
+
+    fn is_finished(&self, offset: X::Duration) -> bool {
+        self.0.is_finished(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyframes;
+
+    fn tracks() -> Tracks<f32> {
+        let mut tracks = Tracks::new(1.0);
+        tracks.insert("x", keyframes::line::<f32, f32>(0.0, 10.0, 1.0));
+        tracks.insert("y", keyframes::line::<f64, f32>(0.0, 1.0, 2.0));
+        tracks.insert("visible", keyframes::stay::<bool, f32>(true, 0.0));
+        tracks
+    }
+
+    #[test]
+    fn sample_all_tracks() {
+        let tracks = tracks();
+        let pose = tracks.sample(1.5);
+        assert_eq!(pose.len(), 3);
+        assert_eq!(pose.names().collect::<Vec<_>>(), vec!["visible", "x", "y"]);
+        assert_eq!(pose.get::<f32>("x"), Some(5.0));
+        assert_eq!(pose.get::<f64>("y"), Some(0.25));
+        assert_eq!(pose.get_ref::<bool>("visible"), Some(&true));
+        assert_eq!(pose.get::<f32>("z"), None);
+
+        assert_eq!(tracks.sample(0.0).get::<f32>("x"), Some(0.0));
+        assert!(!tracks.is_finished(2.5));
+        assert!(tracks.is_finished(3.0));
+    }
+
+    #[test]
+    fn enable_and_solo() {
+        let mut tracks = tracks();
+        assert!(tracks.set_enabled("y", false));
+        assert!(!tracks.set_enabled("z", false));
+        assert!(!tracks.is_active("y"));
+        assert!(!tracks.sample(1.5).contains("y"));
+        assert!(
+            tracks.is_finished(2.0),
+            "Disabled tracks don't keep it running"
+        );
+
+        assert!(tracks.set_solo("x", true));
+        let pose = tracks.sample(1.5);
+        assert_eq!(pose.names().collect::<Vec<_>>(), vec!["x"]);
+
+        tracks.set_solo("x", false);
+        tracks.set_enabled("y", true);
+        assert_eq!(tracks.sample(1.5).len(), 3);
+    }
+
+    #[test]
+    fn replace_and_remove() {
+        let mut tracks = tracks();
+        tracks.insert("x", keyframes::stay::<u8, f32>(7, 0.0));
+        assert_eq!(
+            tracks.names().collect::<Vec<_>>(),
+            vec!["x", "y", "visible"]
+        );
+        assert_eq!(tracks.sample(1.0).get::<u8>("x"), Some(7));
+
+        assert!(tracks.remove("x"));
+        assert!(!tracks.remove("x"));
+        tracks.restart(5.0);
+        assert_eq!(tracks.sample(6.0).get::<f64>("y"), Some(0.5));
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{Animated, Mix, Time, TimeDiff};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::Debug;
+use core::mem::replace;
+
+/// A set of running animations, each one is registered with a key and a callback.
+/// On every frame `update` samples the animations and passes their values to the callbacks.
+/// Finished and cancelled animations are removed.
+///
+/// Registering an animation with a key that is already in use cancels the previous animation,
+/// so the same property is never animated twice.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{keyframes, Keyframes, Tweens};
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// let opacity = Rc::new(Cell::new(0.0));
+/// let mut tweens = Tweens::new();
+///
+/// let handle = tweens.insert("opacity", keyframes::line(0.0, 1.0, 1.0).run(0.0), {
+///     let opacity = opacity.clone();
+///     move |value| opacity.set(value)
+/// });
+///
+/// tweens.update(0.5);
+/// assert_eq!(opacity.get(), 0.5);
+///
+/// // Go back to 0.0 in 1.0, blending from the current animation during the first 0.5
+/// handle.overwrite(keyframes::line(1.0, 0.0, 1.0).run(0.5), 0.5, 0.5);
+/// tweens.update(1.5);
+/// assert_eq!(opacity.get(), 0.0);
+/// assert!(tweens.is_empty());
+/// ```
+pub struct Tweens<K, X: Time> {
+    entries: Vec<(K, Rc<dyn Entry<X>>)>,
+}
+
+impl<K, X: Time> Default for Tweens<K, X> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<K: Debug, X: Time> Debug for Tweens<K, X> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.entries.iter().map(|(key, _)| key))
+            .finish()
+    }
+}
+
+impl<K: PartialEq, X: Time + 'static> Tweens<K, X> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Register an animation, previous animation with the same key is cancelled.
+    /// * `callback` - Receives the animation value on every `update`, including the one when it's finished.
+    pub fn insert<T: 'static>(
+        &mut self,
+        key: K,
+        animated: impl Animated<T, X> + 'static,
+        callback: impl FnMut(T) + 'static,
+    ) -> TweenHandle<T, X> {
+        self.cancel(&key);
+
+        let tween = Rc::new(RefCell::new(Tween {
+            animated: Some(Box::new(animated)),
+            callback: Box::new(callback),
+            finished: false,
+        }));
+        self.entries.push((key, tween.clone()));
+        TweenHandle { tween }
+    }
+
+    /// Cancel the animation with the key. Returns `false` if there is no such animation.
+    pub fn cancel(&mut self, key: &K) -> bool {
+        match self.entries.iter().position(|(k, _)| k == key) {
+            Some(index) => {
+                self.entries.remove(index).1.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check if there is a running animation with the key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries
+            .iter()
+            .any(|(k, entry)| k == key && entry.is_active())
+    }
+
+    /// Get the number of running animations.
+    pub fn len(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| entry.is_active())
+            .count()
+    }
+
+    /// Check if there are no running animations.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pass the current values to the callbacks, and remove finished animations.
+    /// * `time` - The current time, usually `Instant::now()`.
+    pub fn update(&mut self, time: X) {
+        self.entries.retain(|(_, entry)| entry.is_active());
+        for (_, entry) in &self.entries {
+            entry.update(time);
+        }
+        self.entries.retain(|(_, entry)| entry.is_active());
+    }
+}
+
+/// A handle of an animation registered in `Tweens`.
+pub struct TweenHandle<T, X: Time> {
+    tween: Rc<RefCell<Tween<T, X>>>,
+}
+
+impl<T, X: Time> Clone for TweenHandle<T, X> {
+    fn clone(&self) -> Self {
+        Self {
+            tween: self.tween.clone(),
+        }
+    }
+}
+
+impl<T, X: Time> Debug for TweenHandle<T, X> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TweenHandle")
+            .field("is_active", &self.is_active())
+            .finish()
+    }
+}
+
+impl<T, X: Time> TweenHandle<T, X> {
+    /// Stop the animation, its callback isn't called anymore.
+    pub fn cancel(&self) {
+        self.tween.borrow_mut().cancel();
+    }
+
+    /// Check if the animation is neither finished nor cancelled.
+    pub fn is_active(&self) -> bool {
+        self.tween.borrow().is_active()
+    }
+}
+
+impl<T: Mix + 'static, X: Time + 'static> TweenHandle<T, X> {
+    /// Replace the animation, blending from the current one to the new one during `blend_duration`.
+    /// It has no effect if the animation is already finished or cancelled.
+    /// * `time` - The time to start blending, usually `Instant::now()`.
+    pub fn overwrite(
+        &self,
+        animated: impl Animated<T, X> + 'static,
+        time: X,
+        blend_duration: X::Duration,
+    ) {
+        let mut tween = self.tween.borrow_mut();
+        if let (Some(from), false) = (tween.animated.take(), tween.finished) {
+            tween.animated = Some(Box::new(Blend {
+                from,
+                to: Box::new(animated),
+                start_time: time,
+                duration: blend_duration,
+            }));
+        }
+    }
+}
+
+struct Tween<T, X: Time> {
+    /// `None` if the tween is cancelled.
+    animated: Option<Box<dyn Animated<T, X>>>,
+    callback: Box<dyn FnMut(T)>,
+    finished: bool,
+}
+
+impl<T, X: Time> Tween<T, X> {
+    fn is_active(&self) -> bool {
+        self.animated.is_some() && !self.finished
+    }
+
+    fn cancel(&mut self) {
+        self.animated = None;
+    }
+}
+
+/// Type-erased tween, so tweens of different value types can be stored together.
+trait Entry<X> {
+    fn update(&self, time: X);
+    fn cancel(&self);
+    fn is_active(&self) -> bool;
+}
+
+impl<T, X: Time> Entry<X> for RefCell<Tween<T, X>> {
+    fn update(&self, time: X) {
+        let (value, mut callback) = {
+            let mut tween = self.borrow_mut();
+            let Some(animated) = &tween.animated else {
+                return;
+            };
+            let value = animated.get(time);
+            tween.finished = animated.is_finished(time);
+            // The callback is taken out, so it can use the tween handle.
+            (value, replace(&mut tween.callback, Box::new(|_| {})))
+        };
+
+        callback(value);
+        self.borrow_mut().callback = callback;
+    }
+
+    fn cancel(&self) {
+        self.borrow_mut().cancel();
+    }
+
+    fn is_active(&self) -> bool {
+        self.borrow().is_active()
+    }
+}
+
+/// Cross-fade between two animations.
+struct Blend<T, X: Time> {
+    from: Box<dyn Animated<T, X>>,
+    to: Box<dyn Animated<T, X>>,
+    start_time: X,
+    duration: X::Duration,
+}
+
+impl<T: Mix, X: Time> Animated<T, X> for Blend<T, X> {
+    fn get(&self, time: X) -> T {
+        let elapsed = time.saturating_since(self.start_time);
+        if elapsed >= self.duration {
+            self.to.get(time)
+        } else {
+            let t = elapsed.as_f32() / self.duration.as_f32();
+            self.from.get(time).mix(self.to.get(time), t)
+        }
+    }
+
+    fn is_finished(&self, time: X) -> bool {
+        time.saturating_since(self.start_time) >= self.duration && self.to.is_finished(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+    use alloc::vec;
+    use core::cell::Cell;
+
+    fn recorder() -> (Rc<RefCell<Vec<f32>>>, impl FnMut(f32) + 'static) {
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let callback = {
+            let values = values.clone();
+            move |value| values.borrow_mut().push(value)
+        };
+        (values, callback)
+    }
+
+    #[test]
+    fn update_until_finished() {
+        let (values, callback) = recorder();
+        let mut tweens = Tweens::new();
+        let handle = tweens.insert(1, keyframes::line(0.0, 1.0, 1.0).run(0.0), callback);
+
+        tweens.update(0.5);
+        assert!(handle.is_active());
+        assert!(tweens.contains_key(&1));
+        tweens.update(1.0);
+        assert!(!handle.is_active());
+        assert!(tweens.is_empty());
+        tweens.update(1.5);
+
+        assert_eq!(*values.borrow(), vec![0.5, 1.0]);
+    }
+
+    #[test]
+    fn insert_cancels_same_key() {
+        let (first, first_callback) = recorder();
+        let (second, second_callback) = recorder();
+        let mut tweens = Tweens::new();
+
+        let first_handle =
+            tweens.insert("x", keyframes::line(0.0, 1.0, 1.0).run(0.0), first_callback);
+        tweens.insert(
+            "x",
+            keyframes::line(4.0, 8.0, 1.0).run(0.0),
+            second_callback,
+        );
+        assert!(!first_handle.is_active());
+        assert_eq!(tweens.len(), 1);
+
+        tweens.update(0.5);
+        assert!(first.borrow().is_empty());
+        assert_eq!(*second.borrow(), vec![6.0]);
+    }
+
+    #[test]
+    fn cancel() {
+        let (values, callback) = recorder();
+        let mut tweens = Tweens::new();
+        let handle = tweens.insert((), keyframes::line(0.0, 1.0, 1.0).run(0.0), callback);
+
+        tweens.update(0.25);
+        handle.cancel();
+        tweens.update(0.5);
+        assert!(tweens.is_empty());
+        assert!(!tweens.cancel(&()));
+        assert_eq!(*values.borrow(), vec![0.25]);
+    }
+
+    #[test]
+    fn cancel_from_callback() {
+        let calls = Rc::new(Cell::new(0));
+        let handle: Rc<RefCell<Option<TweenHandle<f32, f32>>>> = Default::default();
+        let mut tweens = Tweens::new();
+
+        *handle.borrow_mut() = Some(tweens.insert((), keyframes::line(0.0, 1.0, 1.0).run(0.0), {
+            let calls = calls.clone();
+            let handle = handle.clone();
+            move |_| {
+                calls.set(calls.get() + 1);
+                if let Some(handle) = handle.borrow().as_ref() {
+                    handle.cancel();
+                }
+            }
+        }));
+
+        tweens.update(0.25);
+        tweens.update(0.5);
+        assert_eq!(calls.get(), 1);
+        assert!(tweens.is_empty());
+        handle.borrow_mut().take();
+    }
+
+    #[test]
+    fn overwrite_with_blending() {
+        let (values, callback) = recorder();
+        let mut tweens = Tweens::new();
+        let handle = tweens.insert((), keyframes::stay(1.0, 10.0).run(0.0), callback);
+
+        handle.overwrite(keyframes::stay(3.0, 1.0).run(1.0), 1.0, 2.0);
+        tweens.update(1.0);
+        tweens.update(2.0);
+        tweens.update(3.0);
+        assert!(tweens.is_empty());
+
+        assert_eq!(*values.borrow(), vec![1.0, 2.0, 3.0]);
+    }
+}
@@ -0,0 +1,148 @@
+/// A value that can be added and scaled, like a number or a vector.
+/// It's necessary for physics-based motion like `Spring`, which tracks the velocity of a value.
+/// The lib provides implementations for floats, tuples, and arrays.
+pub trait VectorSpace: Clone {
+    /// The zero value, e.g. no velocity.
+    fn zero() -> Self;
+
+    /// Add two values.
+    fn add(self, other: Self) -> Self;
+
+    /// Multiply the value by a factor.
+    fn scale(self, factor: f32) -> Self;
+
+    /// Subtract `other` from the value.
+    fn sub(self, other: Self) -> Self {
+        self.add(other.scale(-1.0))
+    }
+}
+
+impl VectorSpace for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        self * factor
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+}
+
+impl VectorSpace for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        self * factor as f64
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+}
+
+impl<T1: VectorSpace, T2: VectorSpace> VectorSpace for (T1, T2) {
+    fn zero() -> Self {
+        (T1::zero(), T2::zero())
+    }
+
+    fn add(self, other: Self) -> Self {
+        (self.0.add(other.0), self.1.add(other.1))
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        (self.0.scale(factor), self.1.scale(factor))
+    }
+}
+
+impl<T1: VectorSpace, T2: VectorSpace, T3: VectorSpace> VectorSpace for (T1, T2, T3) {
+    fn zero() -> Self {
+        (T1::zero(), T2::zero(), T3::zero())
+    }
+
+    fn add(self, other: Self) -> Self {
+        (
+            self.0.add(other.0),
+            self.1.add(other.1),
+            self.2.add(other.2),
+        )
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        (
+            self.0.scale(factor),
+            self.1.scale(factor),
+            self.2.scale(factor),
+        )
+    }
+}
+
+impl<T1: VectorSpace, T2: VectorSpace, T3: VectorSpace, T4: VectorSpace> VectorSpace
+    for (T1, T2, T3, T4)
+{
+    fn zero() -> Self {
+        (T1::zero(), T2::zero(), T3::zero(), T4::zero())
+    }
+
+    fn add(self, other: Self) -> Self {
+        (
+            self.0.add(other.0),
+            self.1.add(other.1),
+            self.2.add(other.2),
+            self.3.add(other.3),
+        )
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        (
+            self.0.scale(factor),
+            self.1.scale(factor),
+            self.2.scale(factor),
+            self.3.scale(factor),
+        )
+    }
+}
+
+impl<T: VectorSpace, const N: usize> VectorSpace for [T; N] {
+    fn zero() -> Self {
+        core::array::from_fn(|_| T::zero())
+    }
+
+    fn add(self, other: Self) -> Self {
+        let mut result = self;
+        for (value, other) in result.iter_mut().zip(other) {
+            *value = value.clone().add(other);
+        }
+        result
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        self.map(|value| value.scale(factor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_operations() {
+        assert_eq!(VectorSpace::add(1.0f32, 2.0), 3.0);
+        assert_eq!(VectorSpace::scale(2.0f64, 1.5), 3.0);
+        assert_eq!(VectorSpace::sub((3.0f32, 1.0f32), (1.0, 2.0)), (2.0, -1.0));
+        assert_eq!([1.0f32, 2.0].add([3.0, 4.0]).scale(0.5), [2.0, 3.0]);
+        assert_eq!(<(f32, f32, f32)>::zero(), (0.0, 0.0, 0.0));
+    }
+}
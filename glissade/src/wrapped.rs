@@ -0,0 +1,169 @@
+use crate::math::{abs_f64, floor_f64};
+use crate::{Distance, Mix};
+use core::f32::consts::TAU;
+
+/// Interpolate between two values of a cyclic domain `[min, max)`, e.g. hue degrees or angles,
+/// by the shortest route, the result is wrapped into the range.
+/// When both routes are equally long, the value goes up.
+///
+/// # Example
+///
+/// ```
+/// use glissade::mix_wrapped;
+///
+/// assert_eq!(mix_wrapped(350.0, 10.0, 0.25, 0.0, 360.0), 355.0);
+/// assert_eq!(mix_wrapped(350.0, 10.0, 0.75, 0.0, 360.0), 5.0);
+/// // 23:00 to 01:00 goes through midnight.
+/// assert_eq!(mix_wrapped(23.0, 1.0, 0.5, 0.0, 24.0), 0.0);
+/// ```
+pub fn mix_wrapped(a: f32, b: f32, t: f32, min: f32, max: f32) -> f32 {
+    mix_wrapped_f64(a as f64, b as f64, t as f64, min as f64, max as f64) as f32
+}
+
+fn mix_wrapped_f64(a: f64, b: f64, t: f64, min: f64, max: f64) -> f64 {
+    debug_assert!(!t.is_nan(), "mix_wrapped: t is NaN");
+    let a = wrap(a, min, max);
+    wrap(a + shortest_delta(a, b, min, max) * t, min, max)
+}
+
+/// Get the signed difference from `a` to `b` by the shortest route, in `(-range / 2, range / 2]`.
+fn shortest_delta(a: f64, b: f64, min: f64, max: f64) -> f64 {
+    let range = max - min;
+    let delta = wrap(b - a, 0.0, range);
+    if delta > range / 2.0 {
+        delta - range
+    } else {
+        delta
+    }
+}
+
+fn wrap(value: f64, min: f64, max: f64) -> f64 {
+    let range = max - min;
+    let wrapped = value - range * floor_f64((value - min) / range);
+    // Rounding can push the value to the end of the range.
+    if wrapped >= max {
+        min
+    } else {
+        wrapped
+    }
+}
+
+/// A value of a cyclic domain `[min, max)` that is mixed by the shortest route,
+/// e.g. a hue going from 350° to 10° passes 0° instead of the whole color wheel.
+/// It's a general-purpose counterpart of the `palette` hue types, for angles, times of day, etc.
+/// The range of the first value is used when two values are mixed.
+///
+/// # Example
+///
+/// ```
+/// use glissade::{Mix, Wrapped};
+///
+/// let hue = Wrapped::degrees(350.0f32).mix(Wrapped::degrees(30.0), 0.5);
+/// assert_eq!(hue.value, 10.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wrapped<T> {
+    pub value: T,
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: PartialOrd> Wrapped<T> {
+    pub fn new(value: T, min: T, max: T) -> Self {
+        assert!(min < max, "Wrapped range must not be empty");
+        Self { value, min, max }
+    }
+}
+
+impl<T: PartialOrd + From<f32>> Wrapped<T> {
+    /// A value in the `[0, 360)` range.
+    pub fn degrees(value: T) -> Self {
+        Self::new(value, 0.0.into(), 360.0.into())
+    }
+
+    /// A value in the `[0, 2π)` range.
+    pub fn radians(value: T) -> Self {
+        Self::new(value, 0.0.into(), TAU.into())
+    }
+}
+
+macro_rules! impl_wrapped {
+    ($($t:ty),*) => {
+        $(
+            impl Wrapped<$t> {
+                /// Get the value wrapped into the range.
+                pub fn wrapped(&self) -> $t {
+                    wrap(self.value as f64, self.min as f64, self.max as f64) as $t
+                }
+            }
+
+            impl Mix for Wrapped<$t> {
+                fn mix(self, other: Self, t: f32) -> Self {
+                    self.mix_ref_f64(&other, t as f64)
+                }
+
+                fn mix_ref_f64(&self, other: &Self, t: f64) -> Self {
+                    let value = mix_wrapped_f64(
+                        self.value as f64,
+                        other.value as f64,
+                        t,
+                        self.min as f64,
+                        self.max as f64,
+                    );
+                    Self { value: value as $t, ..*self }
+                }
+            }
+
+            /// The length of the shortest route, using the range of the first value.
+            impl Distance for Wrapped<$t> {
+                fn distance(self, other: Self) -> f32 {
+                    let delta = shortest_delta(
+                        self.value as f64,
+                        other.value as f64,
+                        self.min as f64,
+                        self.max as f64,
+                    );
+                    abs_f64(delta) as f32
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapped!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keyframes, Keyframes};
+
+    #[test]
+    fn shortest_route() {
+        assert_eq!(mix_wrapped(10.0, 350.0, 0.25, 0.0, 360.0), 5.0);
+        assert_eq!(mix_wrapped(10.0, 50.0, 0.5, 0.0, 360.0), 30.0);
+        assert_eq!(mix_wrapped(0.0, 180.0, 0.5, 0.0, 360.0), 90.0, "Ties go up");
+        assert_eq!(mix_wrapped(370.0, -10.0, 0.0, 0.0, 360.0), 10.0, "Wrapped");
+        assert_eq!(mix_wrapped(350.0, 10.0, 1.0, 0.0, 360.0), 10.0);
+        assert_eq!(mix_wrapped(-170.0, 170.0, 0.5, -180.0, 180.0), -180.0);
+    }
+
+    #[test]
+    fn wrapped_values() {
+        let a = Wrapped::new(22.0f64, 0.0, 24.0);
+        let b = Wrapped::new(2.0f64, 0.0, 24.0);
+        assert_eq!(a.mix(b, 0.75).value, 1.0);
+        assert_eq!(a.distance(b), 4.0);
+        assert_eq!(Wrapped::degrees(-90.0f32).wrapped(), 270.0);
+
+        let turn =
+            keyframes::line::<_, f32>(Wrapped::degrees(300.0f32), Wrapped::degrees(60.0), 4.0);
+        assert_eq!(turn.get(1.0).value, 330.0);
+        assert_eq!(turn.get(2.0).value, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Wrapped range must not be empty")]
+    fn empty_range() {
+        Wrapped::new(0.0f32, 1.0, 1.0);
+    }
+}
@@ -0,0 +1,101 @@
+//! [Yew](https://yew.rs/) hooks. Components using them are re-rendered on every animation frame,
+//! until the animated value is finished.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use glissade::yew::use_animation;
+//! use glissade::{keyframes, Keyframes};
+//! use web_time::Duration;
+//! use yew::prelude::*;
+//!
+//! #[function_component]
+//! fn FadeIn() -> Html {
+//!     let opacity: f32 = use_animation(keyframes::line(0.0, 1.0, Duration::from_millis(300)));
+//!     html! { <div style={format!("opacity: {}", opacity)}>{"Hello"}</div> }
+//! }
+//! ```
+
+use crate::driver::web::AnimationLoop;
+use crate::{Animated, Inertial, Keyframes, Mix};
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_time::{Duration, Instant};
+use yew::prelude::*;
+
+/// Compares `Rc` by address, so hook dependencies don't require `PartialEq` for animations.
+struct ByAddress<A>(Rc<A>);
+
+impl<A> PartialEq for ByAddress<A> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Get the current value, and re-render the component on every animation frame until it's finished.
+/// Frames are requested again when a different `Rc` is passed.
+#[hook]
+pub fn use_animated<T, A>(animated: Rc<A>) -> T
+where
+    T: 'static,
+    A: Animated<T, Instant> + 'static,
+{
+    let update = use_force_update();
+
+    use_effect_with(ByAddress(animated.clone()), move |ByAddress(animated)| {
+        let animated = animated.clone();
+        let animation_loop: Rc<RefCell<Option<AnimationLoop>>> = Default::default();
+        *animation_loop.borrow_mut() = Some(AnimationLoop::new({
+            let animation_loop = animation_loop.clone();
+            move |_| {
+                update.force_update();
+                if animated.is_finished(Instant::now()) {
+                    if let Some(animation_loop) = animation_loop.borrow().as_ref() {
+                        animation_loop.stop();
+                    }
+                }
+            }
+        }));
+
+        move || drop(animation_loop.take())
+    });
+
+    animated.get(Instant::now())
+}
+
+/// Run keyframes when the component is mounted, and get the current value.
+/// Keyframes passed on later renders are ignored.
+#[hook]
+pub fn use_animation<T, K>(keyframes: K) -> T
+where
+    T: 'static,
+    K: Keyframes<T, Instant> + 'static,
+{
+    let animation = use_state(move || Rc::new(keyframes.run(Instant::now())));
+    use_animated((*animation).clone())
+}
+
+/// Smoothly follow the value, when it changes the transition to the new value starts.
+#[hook]
+pub fn use_inertial<T>(value: &T, duration: Duration) -> T
+where
+    T: Mix + Clone + PartialEq + 'static,
+{
+    let inertial = use_state_eq({
+        let value = value.clone();
+        move || Rc::new(Inertial::new(value))
+    });
+
+    use_effect_with(value.clone(), {
+        let inertial = inertial.clone();
+        move |value: &T| {
+            inertial.set(Rc::new(inertial.as_ref().clone().go_to(
+                value.clone(),
+                Instant::now(),
+                duration,
+            )));
+        }
+    });
+
+    use_animated((*inertial).clone())
+}